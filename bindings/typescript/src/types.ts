@@ -14,6 +14,8 @@ export enum AttestationStatus {
   Expired = "Expired",
   Revoked = "Revoked",
   Pending = "Pending",
+  Superseded = "Superseded",
+  NotFound = "NotFound",
 }
 
 export enum AuditAction {
@@ -29,6 +31,15 @@ export enum AttestationOrigin {
   Bridged = "Bridged",
 }
 
+/** Coarse classification chosen by the issuer at creation. */
+export enum Category {
+  Identity = "Identity",
+  Financial = "Financial",
+  Compliance = "Compliance",
+  Reputation = "Reputation",
+  Other = "Other",
+}
+
 /** Trust tier assigned to a registered issuer. */
 export enum IssuerTier {
   Basic = 0,
@@ -54,12 +65,34 @@ export interface Attestation {
   expiration: bigint | null;
   revoked: boolean;
   metadata: string | null;
+  jurisdiction: string | null;
   valid_from: bigint | null;
   origin: AttestationOrigin;
   source_chain: string | null;
   source_tx: string | null;
   tags: string[] | null;
   revocation_reason: string | null;
+  /** URI of the justification documents attached at revocation, if any. */
+  revocation_evidence: string | null;
+  /** Id of a parent attestation (same subject) this credential derives from. */
+  depends_on: string | null;
+  /** Parent issuer on whose behalf a sub-issuer created this attestation. */
+  parent_issuer: string | null;
+  /** True when the issuer deliberately minted a non-expiring claim. */
+  permanent: boolean;
+  category: Category;
+  /** Optional numeric value (KYC score, risk rating, reputation points). */
+  score: bigint | null;
+  /** False for credentials the issuer can never revoke. */
+  revocable: boolean;
+  /** Collateral the issuer reports backing this claim (accounting only). */
+  stake: bigint | null;
+  /** Id of the attestation that replaced this one via `supersede_attestation`. */
+  superseded_by: string | null;
+  /** True when the expiration was fixed at issuance and can never change. */
+  expiration_locked: boolean;
+  /** Set by the subject to keep the record from being pruned. */
+  pinned: boolean;
   /** True when the subject has requested GDPR deletion. */
   deleted: boolean;
 }
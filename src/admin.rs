@@ -1,11 +1,12 @@
-use soroban_sdk::{token::TokenClient, Address, Env, String, Vec};
+use soroban_sdk::{token::TokenClient, Address, BytesN, Env, String, Vec};
 
+use crate::constants::MAX_REVOCATION_SUBSCRIBERS;
 use crate::events::Events;
 use crate::storage::Storage;
 use crate::types::{
-    AdminCouncil, ClaimTypeInfo, ContractConfig, CouncilOperation, CouncilProposal, DecayConfig,
-    Delegation, DisputeRecord, Error, ExpirationHook, FeeConfig, IssuerMetadata, IssuerStats,
-    IssuerTier, PendingAdminTransfer, RateLimitConfig, StorageLimits, TtlConfig,
+    AdminCouncil, AutoExtendConfig, BackupAdmin, ClaimTypeInfo, ContractConfig, ContractMetadata, CouncilOperation, CouncilProposal, DecayConfig,
+    Delegation, DisputeRecord, Error, ExpirationHook, FeeConfig, GlobalStats, IssuerMetadata, IssuerStats,
+    IssuerTier, PauseFlags, PendingAdminTransfer, RateLimitConfig, StorageLimits, TimelockOperation, TtlConfig,
 };
 use crate::validation::Validation;
 
@@ -29,18 +30,223 @@ pub fn initialize(env: &Env, admin: Address, ttl_days: Option<u32>) -> Result<()
     Ok(())
 }
 
+/// Initialize the contract and register an initial set of issuers in one call.
+///
+/// Duplicate addresses in `issuers` are registered once.
+///
+/// # Errors
+/// - [`Error::LimitExceeded`] — more than 50 issuers supplied.
+/// - [`Error::AlreadyInitialized`] — the contract already has an admin.
+pub fn initialize_with_issuers(env: &Env, admin: Address, issuers: Vec<Address>) -> Result<(), Error> {
+    const MAX_INITIAL_ISSUERS: u32 = 50;
+
+    if issuers.len() > MAX_INITIAL_ISSUERS {
+        return Err(Error::LimitExceeded);
+    }
+    initialize(env, admin.clone(), None)?;
+    let timestamp = env.ledger().timestamp();
+    for issuer in issuers.iter() {
+        if Storage::is_issuer(env, &issuer) {
+            continue;
+        }
+        Storage::add_issuer(env, &issuer);
+        Storage::increment_total_issuers(env);
+        Events::issuer_registered(env, &issuer, &admin, timestamp);
+    }
+    Ok(())
+}
+
+/// Initialize a development deployment. Identical to [`initialize`] except
+/// that [`reset`] is enabled; dev mode cannot be turned on or off afterwards.
+///
+/// # Errors
+/// - [`Error::AlreadyInitialized`] — the contract already has an admin.
+pub fn initialize_dev(env: &Env, admin: Address, ttl_days: Option<u32>) -> Result<(), Error> {
+    initialize(env, admin, ttl_days)?;
+    Storage::set_dev_mode(env);
+    Ok(())
+}
+
+/// Initialize a deployment on which claim-type matching is case-insensitive:
+/// every public entrypoint that takes a claim type (creation, import, bridge,
+/// queries, and per-claim-type admin settings) passes it through
+/// [`Validation::normalize_claim_type`] once on entry. Like dev mode, the flag
+/// is fixed at initialization so stored claim types stay consistent.
+///
+/// # Errors
+/// - [`Error::AlreadyInitialized`] — the contract already has an admin.
+pub fn initialize_normalized(env: &Env, admin: Address, ttl_days: Option<u32>) -> Result<(), Error> {
+    initialize(env, admin, ttl_days)?;
+    Storage::set_normalize_claim_types(env);
+    Ok(())
+}
+
+/// Initialize a deployment on which `admin_revoke` may revoke attestations
+/// created as non-revocable. The override is fixed at initialization so a
+/// later admin cannot retroactively weaken issuers' irrevocability promises.
+///
+/// # Errors
+/// - [`Error::AlreadyInitialized`] — the contract already has an admin.
+pub fn initialize_admin_override(env: &Env, admin: Address, ttl_days: Option<u32>) -> Result<(), Error> {
+    initialize(env, admin.clone(), ttl_days)?;
+    let mut config = load_contract_config(env, &admin);
+    config.admin_overrides_revocable = true;
+    Storage::set_contract_config(env, &config);
+    Ok(())
+}
+
+pub fn is_normalize_claim_types(env: &Env) -> bool {
+    Storage::is_normalize_claim_types(env)
+}
+
+pub fn is_dev_mode(env: &Env) -> bool {
+    Storage::is_dev_mode(env)
+}
+
+/// Wipe every attestation and every registered issuer, keeping the admin
+/// council and configuration. Dev-mode deployments only.
+///
+/// Live attestations are found through every issuer index: registered
+/// issuers, bridges, and removed issuers and sub-issuers, so bridged and
+/// multisig records go too. Revoked records leave the issuer index, so they
+/// are found through the revoked list of each subject reached that way and
+/// through the sweep's revoked list; a permanent revoked record of a subject
+/// that holds nothing else is not reachable and is kept.
+/// Claim-type counts, issuers' owed fees and weights, and the sweep cursor
+/// are cleared as well. Bridge registrations and claim-type settings are
+/// configuration and survive.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::NotPermitted`] — the contract was not initialized in dev mode.
+pub fn reset(env: &Env, admin: Address) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    if !Storage::is_dev_mode(env) {
+        return Err(Error::NotPermitted);
+    }
+    let empty: Vec<String> = Vec::new(env);
+    let mut subjects: Vec<Address> = Vec::new(env);
+    for issuer in crate::attestation::sweep_sources(env).iter() {
+        for attestation_id in Storage::get_issuer_attestations(env, &issuer).iter() {
+            if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+                Storage::remove_subject_attestation(env, &attestation.subject, &attestation_id);
+                Storage::remove_valid_attestation(env, &attestation.subject, &attestation_id);
+                Storage::remove_subject_claim_type_id(env, &attestation.subject, &attestation.claim_type, &attestation_id);
+                crate::storage::ChunkedIndex::remove_subject(env, &attestation.subject, &attestation_id);
+                if !subjects.contains(&attestation.subject) {
+                    subjects.push_back(attestation.subject.clone());
+                }
+                if !attestation.revoked && !attestation.deleted {
+                    Storage::decrement_claim_type_count(env, &attestation.claim_type);
+                }
+                Storage::remove_attestation(env, &attestation_id);
+            }
+        }
+        Storage::set_issuer_attestations(env, &issuer, &empty);
+        crate::storage::ChunkedIndex::set_issuer_all(env, &issuer, &empty);
+        Storage::set_issuer_owed(env, &issuer, 0);
+        Storage::set_issuer_weight(env, &issuer, 0);
+        if Storage::is_issuer(env, &issuer) {
+            Storage::remove_issuer(env, &issuer);
+        }
+    }
+    for subject in subjects.iter() {
+        for attestation_id in Storage::get_subject_revoked(env, &subject).iter() {
+            Storage::remove_attestation(env, &attestation_id);
+        }
+        Storage::clear_subject_revoked(env, &subject);
+    }
+    for attestation_id in Storage::get_revoked_sweep_ids(env).iter() {
+        Storage::remove_attestation(env, &attestation_id);
+    }
+    Storage::set_revoked_sweep_ids(env, &Vec::new(env));
+    Storage::set_sweep_cursor(env, (0, 0));
+    Storage::set_global_stats(env, &GlobalStats { total_attestations: 0, total_revocations: 0, total_issuers: 0 });
+    Events::dev_reset(env, &admin, env.ledger().timestamp());
+    Ok(())
+}
+
 pub fn transfer_admin(env: &Env, current_admin: Address, new_admin: Address) -> Result<(), Error> {
     current_admin.require_auth();
     Validation::require_admin(env, &current_admin)?;
+    Storage::record_admin_action(env);
     Storage::add_admin(env, &new_admin);
     Storage::remove_admin(env, &current_admin);
     Events::admin_transferred(env, &current_admin, &new_admin);
     Ok(())
 }
 
+/// Designate a backup admin that may take over via [`claim_admin`] after
+/// `inactivity_window` seconds without any admin action. Replaces any
+/// existing backup and starts the timer now.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::InvalidTimestamp`] — `inactivity_window` is zero.
+pub fn set_backup_admin(env: &Env, admin: Address, backup_admin: Address, inactivity_window: u64) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    if inactivity_window == 0 {
+        return Err(Error::InvalidTimestamp);
+    }
+    Storage::set_backup_admin(
+        env,
+        &BackupAdmin { backup_admin, inactivity_window, last_admin_action: env.ledger().timestamp() },
+    );
+    Ok(())
+}
+
+/// Remove the backup admin.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+pub fn clear_backup_admin(env: &Env, admin: Address) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Storage::remove_backup_admin(env);
+    Ok(())
+}
+
+pub fn get_backup_admin(env: &Env) -> Option<BackupAdmin> {
+    Storage::get_backup_admin(env)
+}
+
+/// Take over as sole admin once the inactivity window has elapsed since the
+/// last admin action. The backup designation is consumed, and any pending
+/// admin transfer from the replaced council is dropped so it cannot be
+/// accepted afterwards.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not the designated backup, or the
+///   inactivity window has not yet elapsed.
+pub fn claim_admin(env: &Env, backup_admin: Address) -> Result<(), Error> {
+    backup_admin.require_auth();
+    let backup = Storage::get_backup_admin(env).ok_or(Error::Unauthorized)?;
+    if backup.backup_admin != backup_admin {
+        return Err(Error::Unauthorized);
+    }
+    let now = env.ledger().timestamp();
+    if now < backup.last_admin_action.saturating_add(backup.inactivity_window) {
+        return Err(Error::Unauthorized);
+    }
+    let old_admin = Storage::get_admin(env)?;
+    let mut council: AdminCouncil = Vec::new(env);
+    council.push_back(backup_admin.clone());
+    Storage::set_admin_council(env, &council);
+    Storage::remove_backup_admin(env);
+    Storage::remove_pending_admin_transfer(env);
+    Events::admin_transferred(env, &old_admin, &backup_admin);
+    Ok(())
+}
+
 pub fn propose_admin_transfer(env: &Env, current_admin: Address, new_admin: Address) -> Result<(), Error> {
     current_admin.require_auth();
     Validation::require_admin(env, &current_admin)?;
+    Storage::record_admin_action(env);
     Storage::set_pending_admin_transfer(
         env,
         &PendingAdminTransfer { proposed_by: current_admin.clone(), new_admin: new_admin.clone() },
@@ -52,6 +258,7 @@ pub fn propose_admin_transfer(env: &Env, current_admin: Address, new_admin: Addr
 pub fn cancel_admin_transfer(env: &Env, current_admin: Address) -> Result<(), Error> {
     current_admin.require_auth();
     Validation::require_admin(env, &current_admin)?;
+    Storage::record_admin_action(env);
     let pending = Storage::get_pending_admin_transfer(env).ok_or(Error::NotFound)?;
     if pending.proposed_by != current_admin {
         return Err(Error::Unauthorized);
@@ -80,6 +287,7 @@ pub fn get_pending_admin_transfer(env: &Env) -> Option<PendingAdminTransfer> {
 pub fn add_admin(env: &Env, existing_admin: Address, new_admin: Address) -> Result<(), Error> {
     existing_admin.require_auth();
     Validation::require_admin(env, &existing_admin)?;
+    Storage::record_admin_action(env);
     if Storage::is_admin(env, &new_admin) {
         return Ok(());
     }
@@ -91,6 +299,7 @@ pub fn add_admin(env: &Env, existing_admin: Address, new_admin: Address) -> Resu
 pub fn remove_admin(env: &Env, existing_admin: Address, admin_to_remove: Address) -> Result<(), Error> {
     existing_admin.require_auth();
     Validation::require_admin(env, &existing_admin)?;
+    Storage::record_admin_action(env);
     let council = Storage::get_admin_council(env)?;
     if council.len() <= 1 {
         return Err(Error::LastAdminCannotBeRemoved);
@@ -107,6 +316,14 @@ pub fn get_admin(env: &Env) -> Result<Address, Error> {
     Storage::get_admin(env)
 }
 
+pub fn get_admin_unchecked(env: &Env) -> Option<Address> {
+    Storage::get_admin(env).ok()
+}
+
+pub fn get_admin_or(env: &Env, fallback: Address) -> Address {
+    Storage::get_admin(env).unwrap_or(fallback)
+}
+
 pub fn get_admin_council(env: &Env) -> Result<AdminCouncil, Error> {
     Storage::get_admin_council(env)
 }
@@ -118,7 +335,9 @@ pub fn get_admin_council(env: &Env) -> Result<AdminCouncil, Error> {
 pub fn register_issuer(env: &Env, admin: Address, issuer: Address) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
-    Validation::require_not_paused(env)?;
+    Storage::record_admin_action(env);
+    Validation::require_register_not_paused(env)?;
+    Validation::require_registry_not_frozen(env)?;
     if Storage::is_bridge(env, &issuer) {
         return Err(Error::Unauthorized);
     }
@@ -128,15 +347,91 @@ pub fn register_issuer(env: &Env, admin: Address, issuer: Address) -> Result<(),
     Ok(())
 }
 
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::TimelockNotReady`] — the operation timelock is set; queue a
+///   [`TimelockOperation::RemoveIssuer`] instead.
+/// - [`Error::RegistryFrozen`] — the issuer registry is frozen.
 pub fn remove_issuer(env: &Env, admin: Address, issuer: Address) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Validation::require_no_timelock(env)?;
+    apply_remove_issuer(env, &admin, &issuer)
+}
+
+/// Let a registered issuer remove its own authorization. Existing
+/// attestations are untouched, and the admin may register the address again
+/// later.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — `issuer` is not a registered issuer.
+/// - [`Error::RegistryFrozen`] — the issuer registry is frozen.
+pub fn renounce_issuer(env: &Env, issuer: Address) -> Result<(), Error> {
+    issuer.require_auth();
+    if !Storage::is_issuer(env, &issuer) {
+        return Err(Error::Unauthorized);
+    }
+    Validation::require_registry_not_frozen(env)?;
     Storage::remove_issuer(env, &issuer);
     Storage::decrement_total_issuers(env);
-    Events::issuer_removed(env, &issuer, &admin, env.ledger().timestamp());
+    Events::issuer_renounced(env, &issuer, env.ledger().timestamp());
     Ok(())
 }
 
+fn apply_remove_issuer(env: &Env, admin: &Address, issuer: &Address) -> Result<(), Error> {
+    Validation::require_registry_not_frozen(env)?;
+    Storage::remove_issuer(env, issuer);
+    Storage::decrement_total_issuers(env);
+    Events::issuer_removed(env, issuer, admin, env.ledger().timestamp());
+    Ok(())
+}
+
+/// Remove up to 50 issuers in one call. Addresses that are not registered
+/// (including repeats within the batch) are skipped; `issuer_removed` is
+/// emitted only for issuers actually removed. Returns the number removed.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::LimitExceeded`] — more than 50 issuers supplied.
+/// - [`Error::RegistryFrozen`] — the issuer registry is frozen.
+/// - [`Error::TimelockNotReady`] — the operation timelock is set; queue a
+///   [`TimelockOperation::RemoveIssuers`] instead.
+pub fn remove_issuers_batch(env: &Env, admin: Address, issuers: Vec<Address>) -> Result<u32, Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Validation::require_no_timelock(env)?;
+    apply_remove_issuers(env, &admin, issuers)
+}
+
+fn apply_remove_issuers(env: &Env, admin: &Address, issuers: Vec<Address>) -> Result<u32, Error> {
+    const MAX_BATCH: u32 = 50;
+    Validation::require_registry_not_frozen(env)?;
+    if issuers.len() > MAX_BATCH {
+        return Err(Error::LimitExceeded);
+    }
+    let timestamp = env.ledger().timestamp();
+    let mut removed: u32 = 0;
+    for issuer in issuers.iter() {
+        if !Storage::is_issuer(env, &issuer) {
+            continue;
+        }
+        Storage::remove_issuer(env, &issuer);
+        Storage::decrement_total_issuers(env);
+        Events::issuer_removed(env, &issuer, admin, timestamp);
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+/// Number of registered issuers, read from the issuer list itself so it
+/// cannot drift from it (unlike the `total_issuers` counter, which
+/// re-registering an existing issuer bumps).
+pub fn get_issuer_total(env: &Env) -> u32 {
+    Storage::get_issuer_list(env).len()
+}
+
 pub fn get_issuer_list(env: &Env, start: u32, limit: u32) -> Vec<Address> {
     crate::storage::paginate_addresses(env, &Storage::get_issuer_list(env), start, limit)
 }
@@ -179,6 +474,7 @@ pub fn is_whitelist_enabled(env: &Env, issuer: Address) -> bool {
 pub fn set_issuer_tier(env: &Env, admin: Address, issuer: Address, tier: IssuerTier) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     Validation::require_issuer(env, &issuer)?;
     Storage::set_issuer_tier(env, &issuer, &tier);
     Events::issuer_tier_updated(env, &issuer, &tier);
@@ -240,6 +536,7 @@ pub fn get_confidence_score(env: &Env, attestation_id: String) -> Option<u32> {
 pub fn set_decay_config(env: &Env, admin: Address, config: DecayConfig) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     Storage::set_decay_config(env, &config);
     Ok(())
 }
@@ -267,10 +564,137 @@ pub fn is_issuer(env: &Env, address: Address) -> bool {
     Storage::is_issuer(env, &address)
 }
 
+/// `is_issuer` for up to 50 addresses; results are positional.
+///
+/// # Errors
+/// - [`Error::LimitExceeded`] — more than 50 addresses supplied.
+pub fn are_issuers(env: &Env, addresses: Vec<Address>) -> Result<Vec<bool>, Error> {
+    const MAX_BATCH: u32 = 50;
+    if addresses.len() > MAX_BATCH {
+        return Err(Error::LimitExceeded);
+    }
+    let mut results = Vec::new(env);
+    for address in addresses.iter() {
+        results.push_back(Storage::is_issuer(env, &address));
+    }
+    Ok(results)
+}
+
+/// Whether `address` was a registered issuer at `as_of`, read from its
+/// bounded transition log. Before the oldest retained transition the state
+/// is the opposite of that transition. Addresses registered before the log
+/// existed have none, so their current status is returned.
+pub fn was_issuer_at(env: &Env, address: Address, as_of: u64) -> bool {
+    let history = Storage::get_issuer_history(env, &address);
+    let Some((_, oldest_authorized)) = history.first() else {
+        return Storage::is_issuer(env, &address);
+    };
+    let mut authorized = !oldest_authorized;
+    for (timestamp, state) in history.iter() {
+        if timestamp > as_of {
+            break;
+        }
+        authorized = state;
+    }
+    authorized
+}
+
 pub fn get_issuer_tier(env: &Env, issuer: Address) -> Option<IssuerTier> {
     Storage::get_issuer_tier(env, &issuer)
 }
 
+/// Replace the contract's human-facing self-description.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not the admin.
+/// - [`Error::MetadataTooLong`] — `name` exceeds 64 or `description` 256 characters.
+/// - [`Error::NotInitialized`] — contract has not been initialized.
+pub fn set_contract_metadata(
+    env: &Env,
+    admin: Address,
+    name: String,
+    description: String,
+    version: u32,
+) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Validation::validate_contract_metadata(&name, &description)?;
+    let code_version = Storage::get_version(env).ok_or(Error::NotInitialized)?;
+    Storage::set_contract_metadata(
+        env,
+        &ContractMetadata {
+            name,
+            version: code_version,
+            description,
+            metadata_version: version,
+        },
+    );
+    Ok(())
+}
+
+/// Return the admin-set self-description, or the built-in defaults when unset.
+///
+/// # Errors
+/// - [`Error::NotInitialized`] — contract has not been initialized.
+pub fn get_contract_metadata(env: &Env) -> Result<ContractMetadata, Error> {
+    let version = Storage::get_version(env).ok_or(Error::NotInitialized)?;
+    Ok(match Storage::get_contract_metadata(env) {
+        Some(stored) => ContractMetadata { version, ..stored },
+        None => ContractMetadata {
+            name: String::from_str(env, "TrustLink"),
+            version,
+            description: String::from_str(
+                env,
+                "On-chain attestation and verification system for the Stellar blockchain.",
+            ),
+            metadata_version: 0,
+        },
+    })
+}
+
+/// Set the metered fee recorded against an issuer for each new attestation
+/// of `claim_type`. Pass `0` to stop metering the type.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::InvalidFee`] — `fee` is negative.
+pub fn set_claim_type_fee(env: &Env, admin: Address, claim_type: String, fee: i128) -> Result<(), Error> {
+    admin.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    if fee < 0 {
+        return Err(Error::InvalidFee);
+    }
+    Storage::set_claim_type_fee(env, &claim_type, fee);
+    Ok(())
+}
+
+pub fn get_claim_type_fee(env: &Env, claim_type: String) -> i128 {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Storage::get_claim_type_fee(env, &claim_type)
+}
+
+pub fn get_issuer_owed(env: &Env, issuer: Address) -> i128 {
+    Storage::get_issuer_owed(env, &issuer)
+}
+
+/// Zero an issuer's owed balance once it has been settled off-chain.
+/// Returns the amount that was cleared.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+pub fn settle_issuer_fees(env: &Env, admin: Address, issuer: Address) -> Result<i128, Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    let owed = Storage::get_issuer_owed(env, &issuer);
+    Storage::set_issuer_owed(env, &issuer, 0);
+    Events::issuer_fees_settled(env, &issuer, &admin, owed);
+    Ok(owed)
+}
+
 // -----------------------------------------------------------------------
 // Bridge management
 // -----------------------------------------------------------------------
@@ -278,6 +702,7 @@ pub fn get_issuer_tier(env: &Env, issuer: Address) -> Option<IssuerTier> {
 pub fn register_bridge(env: &Env, admin: Address, bridge_contract: Address) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     if Storage::is_issuer(env, &bridge_contract) {
         return Err(Error::Unauthorized);
     }
@@ -323,6 +748,7 @@ pub fn get_fee_config(env: &Env) -> Result<FeeConfig, Error> {
 pub fn set_fee(env: &Env, admin: Address, fee: i128, collector: Address, fee_token: Option<Address>) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     validate_fee_config(env, fee, &fee_token)?;
     if admin == collector {
         return Err(Error::Unauthorized);
@@ -334,6 +760,7 @@ pub fn set_fee(env: &Env, admin: Address, fee: i128, collector: Address, fee_tok
 pub fn set_rate_limit(env: &Env, admin: Address, min_issuance_interval: u64) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     Storage::set_rate_limit_config(env, &RateLimitConfig { min_issuance_interval });
     Ok(())
 }
@@ -342,6 +769,119 @@ pub fn get_rate_limit(env: &Env) -> Option<RateLimitConfig> {
     Storage::get_rate_limit_config(env)
 }
 
+/// Cap how many attestations a single issuer may create within one ledger.
+///
+/// Pass `0` to disable the cap.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+pub fn set_max_per_ledger(env: &Env, admin: Address, max_per_ledger: u32) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Storage::set_max_per_ledger(env, max_per_ledger);
+    Ok(())
+}
+
+pub fn get_max_per_ledger(env: &Env) -> Option<u32> {
+    Storage::get_max_per_ledger(env)
+}
+
+/// Enable cohort expiration for `claim_type`: new attestations of that type
+/// expire `window_secs` after the subject's first attestation of the type.
+/// Pass `None` to turn cohort mode off.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::InvalidExpiration`] — `window_secs` is zero.
+pub fn set_cohort_expiration(
+    env: &Env,
+    admin: Address,
+    claim_type: String,
+    window_secs: Option<u64>,
+) -> Result<(), Error> {
+    admin.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Validation::validate_claim_type(&claim_type)?;
+    match window_secs {
+        Some(0) => return Err(Error::InvalidExpiration),
+        Some(window) => Storage::set_cohort_expiration(env, &claim_type, window),
+        None => Storage::remove_cohort_expiration(env, &claim_type),
+    }
+    Ok(())
+}
+
+pub fn get_cohort_expiration(env: &Env, claim_type: String) -> Option<u64> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Storage::get_cohort_expiration(env, &claim_type)
+}
+
+/// Enable auto-extension for `claim_type`: an authenticated verification
+/// within the grace window extends the matching attestation, provided its
+/// issuer has consented and is registered and unpaused, up to
+/// `max_extensions` times per attestation. Pass `None` to turn it off.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::InvalidClaimType`] — `claim_type` fails format validation.
+/// - [`Error::InvalidExpiration`] — `grace_window`, `extension` or
+///   `max_extensions` is zero.
+pub fn set_auto_extend(
+    env: &Env,
+    admin: Address,
+    claim_type: String,
+    config: Option<AutoExtendConfig>,
+) -> Result<(), Error> {
+    admin.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Validation::validate_claim_type(&claim_type)?;
+    if let Some(ref c) = config {
+        if c.grace_window == 0 || c.extension == 0 || c.max_extensions == 0 {
+            return Err(Error::InvalidExpiration);
+        }
+    }
+    Storage::set_auto_extend(env, &claim_type, &config);
+    Ok(())
+}
+
+pub fn get_auto_extend(env: &Env, claim_type: String) -> Option<AutoExtendConfig> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Storage::get_auto_extend(env, &claim_type)
+}
+
+/// Set or replace the ed25519 public key `submit_signed_attestation` checks
+/// the issuer's signatures against. Attestations already created are unaffected.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — `issuer` is not a registered issuer.
+pub fn rotate_issuer_key(env: &Env, issuer: Address, new_pubkey: BytesN<32>) -> Result<(), Error> {
+    issuer.require_auth();
+    Validation::require_issuer(env, &issuer)?;
+    Storage::set_issuer_signing_key(env, &issuer, &new_pubkey);
+    Events::issuer_key_rotated(env, &issuer, &new_pubkey);
+    Ok(())
+}
+
+pub fn get_issuer_key(env: &Env, issuer: Address) -> Option<BytesN<32>> {
+    Storage::get_issuer_signing_key(env, &issuer)
+}
+
+/// Issuer pre-authorization for auto-extending its `claim_type` attestations.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — `issuer` is not a registered issuer.
+pub fn set_auto_extend_consent(env: &Env, issuer: Address, claim_type: String, allowed: bool) -> Result<(), Error> {
+    issuer.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Validation::require_issuer(env, &issuer)?;
+    Storage::set_auto_extend_consent(env, &issuer, &claim_type, allowed);
+    Ok(())
+}
+
 /// Set a per-claim-type rate limit override.
 ///
 /// When set, this overrides the global rate limit for the specified claim type.
@@ -356,7 +896,9 @@ pub fn set_rate_limit_for_claim_type(
     interval_secs: u64,
 ) -> Result<(), Error> {
     admin.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     Validation::validate_claim_type(&claim_type)?;
     Storage::set_claim_type_rate_limit(env, &claim_type, interval_secs);
     Ok(())
@@ -364,6 +906,7 @@ pub fn set_rate_limit_for_claim_type(
 
 /// Get the per-claim-type rate limit override for a claim type, or None if not set.
 pub fn get_rate_limit_for_claim_type(env: &Env, claim_type: String) -> Option<u64> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Storage::get_claim_type_rate_limit(env, &claim_type)
 }
 
@@ -374,6 +917,7 @@ pub fn get_rate_limit_for_claim_type(env: &Env, claim_type: String) -> Option<u6
 pub fn pause(env: &Env, admin: Address) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     Storage::set_paused(env, true);
     Events::contract_paused(env, &admin, env.ledger().timestamp());
     Ok(())
@@ -382,6 +926,7 @@ pub fn pause(env: &Env, admin: Address) -> Result<(), Error> {
 pub fn unpause(env: &Env, admin: Address) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     Storage::set_paused(env, false);
     Events::contract_unpaused(env, &admin, env.ledger().timestamp());
     Ok(())
@@ -391,29 +936,308 @@ pub fn is_paused(env: &Env) -> bool {
     Storage::is_paused(env)
 }
 
+/// Replace the granular pause flags. `pause`/`unpause` set or clear all three.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not the admin.
+pub fn set_pause_flags(env: &Env, admin: Address, flags: PauseFlags) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Storage::set_pause_flags(env, &flags);
+    Ok(())
+}
+
+pub fn get_pause_flags(env: &Env) -> PauseFlags {
+    Storage::get_pause_flags(env)
+}
+
+/// Authorize `sub_issuer` to create attestations on behalf of `parent_issuer`.
+///
+/// The sub-issuer passes `require_issuer` only while the parent stays
+/// registered and unpaused, so removing the parent disables it.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — `parent_issuer` is not a directly registered
+///   issuer, or `sub_issuer` is the parent itself.
+/// - [`Error::IssuerPaused`] — `parent_issuer` is paused.
+/// - [`Error::RegistryFrozen`] — the issuer registry is frozen.
+pub fn add_sub_issuer(env: &Env, parent_issuer: Address, sub_issuer: Address) -> Result<(), Error> {
+    parent_issuer.require_auth();
+    Validation::require_registry_not_frozen(env)?;
+    if !Storage::is_issuer(env, &parent_issuer) || parent_issuer == sub_issuer {
+        return Err(Error::Unauthorized);
+    }
+    Validation::require_issuer(env, &parent_issuer)?;
+    Storage::set_sub_issuer_parent(env, &sub_issuer, &parent_issuer);
+    Events::sub_issuer_added(env, &parent_issuer, &sub_issuer, env.ledger().timestamp());
+    Ok(())
+}
+
+/// Withdraw a sub-issuer's authorization.
+///
+/// # Errors
+/// - [`Error::NotFound`] — `sub_issuer` is not a sub-issuer of `parent_issuer`.
+/// - [`Error::RegistryFrozen`] — the issuer registry is frozen.
+pub fn remove_sub_issuer(env: &Env, parent_issuer: Address, sub_issuer: Address) -> Result<(), Error> {
+    parent_issuer.require_auth();
+    Validation::require_registry_not_frozen(env)?;
+    if Storage::get_sub_issuer_parent(env, &sub_issuer) != Some(parent_issuer.clone()) {
+        return Err(Error::NotFound);
+    }
+    Storage::remove_sub_issuer_parent(env, &sub_issuer);
+    Events::sub_issuer_removed(env, &parent_issuer, &sub_issuer, env.ledger().timestamp());
+    Ok(())
+}
+
+pub fn get_sub_issuer_parent(env: &Env, sub_issuer: Address) -> Option<Address> {
+    Storage::get_sub_issuer_parent(env, &sub_issuer)
+}
+
+/// Freeze the issuer set: `register_issuer`, every issuer-removal path and
+/// sub-issuer changes fail with [`Error::RegistryFrozen`] until
+/// [`unfreeze_issuer_registry`].
+/// Attestation creation and verification are unaffected.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+pub fn freeze_issuer_registry(env: &Env, admin: Address) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Storage::set_issuer_registry_frozen(env, true);
+    Events::issuer_registry_frozen(env, &admin, true);
+    Ok(())
+}
+
+/// Lift a freeze set by [`freeze_issuer_registry`].
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+pub fn unfreeze_issuer_registry(env: &Env, admin: Address) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Storage::set_issuer_registry_frozen(env, false);
+    Events::issuer_registry_frozen(env, &admin, false);
+    Ok(())
+}
+
+pub fn is_issuer_registry_frozen(env: &Env) -> bool {
+    Storage::is_issuer_registry_frozen(env)
+}
+
+/// Set the delay between queuing and executing a destructive admin
+/// operation. While non-zero, `remove_issuer`, `remove_issuers_batch`,
+/// `admin_revoke` and `admin_revoke_subject_claim_type` cannot be called
+/// directly; each has a [`TimelockOperation`] variant to pass through
+/// [`queue_operation`] and [`execute_operation`] instead.
+///
+/// Only increases take effect directly. Lowering the delay, or turning it off
+/// with `0`, must itself be queued as [`TimelockOperation::SetTimelock`], so a
+/// compromised admin key cannot strip the timelock and act at once.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::TimelockNotReady`] — `seconds` is below the current delay.
+pub fn set_operation_timelock(env: &Env, admin: Address, seconds: u64) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    if seconds < Storage::get_operation_timelock(env) {
+        return Err(Error::TimelockNotReady);
+    }
+    Storage::set_operation_timelock(env, seconds);
+    Ok(())
+}
+
+pub fn get_operation_timelock(env: &Env) -> u64 {
+    Storage::get_operation_timelock(env)
+}
+
+pub fn hash_operation(env: &Env, operation: TimelockOperation) -> BytesN<32> {
+    operation.hash(env)
+}
+
+/// Queue the operation whose [`TimelockOperation::hash`] is `op_hash`,
+/// executable once the current timelock delay has elapsed. Re-queuing a hash
+/// restarts its delay. Returns the ready time.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+pub fn queue_operation(env: &Env, admin: Address, op_hash: BytesN<32>) -> Result<u64, Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    let ready_at = env.ledger().timestamp().saturating_add(Storage::get_operation_timelock(env));
+    Storage::set_queued_operation(env, &op_hash, ready_at);
+    Events::operation_queued(env, &admin, &op_hash, ready_at);
+    Ok(ready_at)
+}
+
+/// Ready time of a queued operation, or `None` if it is not queued.
+pub fn get_queued_operation(env: &Env, op_hash: BytesN<32>) -> Option<u64> {
+    Storage::get_queued_operation(env, &op_hash)
+}
+
+/// Run a queued operation whose delay has elapsed, consuming the queue entry.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::NotFound`] — the operation was never queued or already ran.
+/// - [`Error::TimelockNotReady`] — the delay has not yet elapsed.
+/// - Any error of the underlying operation.
+pub fn execute_operation(env: &Env, admin: Address, operation: TimelockOperation) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    let op_hash = operation.hash(env);
+    let ready_at = Storage::get_queued_operation(env, &op_hash).ok_or(Error::NotFound)?;
+    if env.ledger().timestamp() < ready_at {
+        return Err(Error::TimelockNotReady);
+    }
+    Storage::remove_queued_operation(env, &op_hash);
+    match operation {
+        TimelockOperation::RemoveIssuer(issuer) => apply_remove_issuer(env, &admin, &issuer)?,
+        TimelockOperation::AdminRevoke(attestation_id, reason) => {
+            crate::attestation::apply_admin_revoke(env, &admin, attestation_id, reason)?
+        }
+        TimelockOperation::RemoveIssuers(issuers) => {
+            apply_remove_issuers(env, &admin, issuers)?;
+        }
+        TimelockOperation::AdminRevokeSubjectClaimType(subject, claim_type, limit) => {
+            crate::attestation::apply_admin_revoke_subject_claim_type(env, &admin, subject, claim_type, limit)?;
+        }
+        TimelockOperation::SetTimelock(seconds) => Storage::set_operation_timelock(env, seconds),
+    }
+    Events::operation_executed(env, &admin, &op_hash);
+    Ok(())
+}
+
+/// Halt a single issuer without touching the rest of the contract.
+///
+/// The issuer stays registered and its attestations are untouched, but
+/// `require_issuer` rejects it until `unpause_issuer` is called.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::NotFound`] — `issuer` is not registered.
+pub fn pause_issuer(env: &Env, admin: Address, issuer: Address) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    if !Storage::is_issuer(env, &issuer) {
+        return Err(Error::NotFound);
+    }
+    Storage::set_issuer_paused(env, &issuer, true);
+    Events::issuer_paused(env, &issuer, &admin, env.ledger().timestamp());
+    Ok(())
+}
+
+/// Resume a previously paused issuer.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::NotFound`] — `issuer` is not registered.
+pub fn unpause_issuer(env: &Env, admin: Address, issuer: Address) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    if !Storage::is_issuer(env, &issuer) {
+        return Err(Error::NotFound);
+    }
+    Storage::set_issuer_paused(env, &issuer, false);
+    Events::issuer_unpaused(env, &issuer, &admin, env.ledger().timestamp());
+    Ok(())
+}
+
+pub fn is_issuer_paused(env: &Env, issuer: Address) -> bool {
+    Storage::is_issuer_paused(env, &issuer)
+}
+
+/// Limit a registered issuer's authorization to ledger timestamps before
+/// `expires_at`; `None` removes the limit.
+///
+/// Once the limit passes, `require_issuer` rejects the issuer with
+/// [`Error::IssuerExpired`]. The rejection leaves no event, since a failed
+/// invocation's events are rolled back; monitor failed transactions or poll
+/// `get_issuer_expiry` instead.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::NotFound`] — `issuer` is not registered.
+/// - [`Error::InvalidTimestamp`] — `expires_at` is not in the future.
+pub fn set_issuer_expiry(env: &Env, admin: Address, issuer: Address, expires_at: Option<u64>) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    if !Storage::is_issuer(env, &issuer) {
+        return Err(Error::NotFound);
+    }
+    if let Some(ts) = expires_at {
+        if ts <= env.ledger().timestamp() {
+            return Err(Error::InvalidTimestamp);
+        }
+    }
+    Storage::set_issuer_expiry(env, &issuer, expires_at);
+    Ok(())
+}
+
+pub fn get_issuer_expiry(env: &Env, issuer: Address) -> Option<u64> {
+    Storage::get_issuer_expiry(env, &issuer)
+}
+
+/// Assign the trust weight `issuer` contributes to `has_weighted_claim`.
+/// Issuers without an assigned weight contribute `0`.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::NotFound`] — `issuer` is not registered.
+pub fn set_issuer_weight(env: &Env, admin: Address, issuer: Address, weight: u32) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    if !Storage::is_issuer(env, &issuer) {
+        return Err(Error::NotFound);
+    }
+    Storage::set_issuer_weight(env, &issuer, weight);
+    Ok(())
+}
+
+pub fn get_issuer_weight(env: &Env, issuer: Address) -> u32 {
+    Storage::get_issuer_weight(env, &issuer)
+}
+
 // -----------------------------------------------------------------------
 // Contract Config
 // -----------------------------------------------------------------------
 
+/// Load the stored contract config, or a default one with every mode flag off.
+fn load_contract_config(env: &Env, admin: &Address) -> ContractConfig {
+    Storage::get_contract_config(env).unwrap_or_else(|| ContractConfig {
+        contract_name: soroban_sdk::String::from_str(env, "TrustLink"),
+        contract_version: soroban_sdk::String::from_str(env, "0.1.0"),
+        contract_description: soroban_sdk::String::from_str(env, ""),
+        ttl_config: Storage::get_ttl_config(env).unwrap_or(TtlConfig { ttl_days: 30 }),
+        fee_config: Storage::get_fee_config(env).unwrap_or(FeeConfig {
+            attestation_fee: 0,
+            fee_collector: admin.clone(),
+            fee_token: None,
+        }),
+        require_registered_claim_type: false,
+        metadata_hash_only: false,
+        idempotent_revoke: false,
+        enforce_explicit_permanence: false,
+        admin_overrides_revocable: false,
+    })
+}
+
 pub fn set_require_registered_claim_type(env: &Env, admin: Address, require: bool) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     
-    let mut config = Storage::get_contract_config(env).unwrap_or_else(|| {
-        ContractConfig {
-            contract_name: soroban_sdk::String::from_str(env, "TrustLink"),
-            contract_version: soroban_sdk::String::from_str(env, "0.1.0"),
-            contract_description: soroban_sdk::String::from_str(env, ""),
-            ttl_config: Storage::get_ttl_config(env).unwrap_or(TtlConfig { ttl_days: 30 }),
-            fee_config: Storage::get_fee_config(env).unwrap_or(FeeConfig {
-                attestation_fee: 0,
-                fee_collector: admin.clone(),
-                fee_token: None,
-            }),
-            require_registered_claim_type: false,
-        }
-    });
-    
+    let mut config = load_contract_config(env, &admin);
     config.require_registered_claim_type = require;
     Storage::set_contract_config(env, &config);
     Ok(())
@@ -428,6 +1252,7 @@ pub fn get_require_registered_claim_type(env: &Env) -> bool {
 pub fn set_metadata_hash_only(env: &Env, admin: Address, enabled: bool) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
 
     if let Some(mut config) = Storage::get_contract_config(env) {
         config.metadata_hash_only = enabled;
@@ -444,6 +1269,52 @@ pub fn get_metadata_hash_only(env: &Env) -> bool {
         .unwrap_or(false)
 }
 
+/// Enable or disable idempotent revocation. Strict (`false`) is the default.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+pub fn set_idempotent_revoke(env: &Env, admin: Address, enabled: bool) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    let mut config = load_contract_config(env, &admin);
+    config.idempotent_revoke = enabled;
+    Storage::set_contract_config(env, &config);
+    Ok(())
+}
+
+pub fn get_idempotent_revoke(env: &Env) -> bool {
+    Storage::get_contract_config(env)
+        .map(|config| config.idempotent_revoke)
+        .unwrap_or(false)
+}
+
+/// Require non-expiring attestations to be minted explicitly as permanent.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+pub fn set_enforce_explicit_permanence(env: &Env, admin: Address, enabled: bool) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    let mut config = load_contract_config(env, &admin);
+    config.enforce_explicit_permanence = enabled;
+    Storage::set_contract_config(env, &config);
+    Ok(())
+}
+
+pub fn get_enforce_explicit_permanence(env: &Env) -> bool {
+    Storage::get_contract_config(env)
+        .map(|config| config.enforce_explicit_permanence)
+        .unwrap_or(false)
+}
+
+pub fn get_admin_overrides_revocable(env: &Env) -> bool {
+    Storage::get_contract_config(env)
+        .map(|config| config.admin_overrides_revocable)
+        .unwrap_or(false)
+}
+
 // -----------------------------------------------------------------------
 // Limits
 // -----------------------------------------------------------------------
@@ -455,6 +1326,7 @@ pub fn get_limits(env: &Env) -> StorageLimits {
 pub fn set_limits(env: &Env, admin: Address, max_attestations_per_issuer: u32, max_attestations_per_subject: u32) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     Storage::set_limits(env, &StorageLimits { max_attestations_per_issuer, max_attestations_per_subject });
     Ok(())
 }
@@ -463,17 +1335,136 @@ pub fn set_limits(env: &Env, admin: Address, max_attestations_per_issuer: u32, m
 // Claim type registry
 // -----------------------------------------------------------------------
 
+/// Register or re-describe a claim type.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::InvalidClaimType`] — `claim_type` fails format validation.
+/// - [`Error::MetadataTooLong`] — `description` exceeds 256 characters.
+/// - [`Error::RegistryFull`] — `claim_type` is new and the registry is at its maximum size.
 pub fn register_claim_type(env: &Env, admin: Address, claim_type: String, description: String) -> Result<(), Error> {
     admin.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     Validation::validate_claim_type(&claim_type)?;
+    Validation::validate_claim_type_description(&description)?;
+    if Storage::get_claim_type(env, &claim_type).is_none()
+        && Storage::get_claim_type_list(env).len() >= Storage::get_max_claim_types(env)
+    {
+        return Err(Error::RegistryFull);
+    }
     let info = ClaimTypeInfo { claim_type: claim_type.clone(), description: description.clone() };
     Storage::set_claim_type(env, &info);
     Events::claim_type_registered(env, &claim_type, &description);
     Ok(())
 }
 
+/// Replace the human-readable description of a registered claim type.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::NotFound`] — `claim_type` is not registered.
+/// - [`Error::MetadataTooLong`] — `description` exceeds 256 characters.
+pub fn set_claim_type_description(
+    env: &Env,
+    admin: Address,
+    claim_type: String,
+    description: String,
+) -> Result<(), Error> {
+    admin.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Validation::validate_claim_type_description(&description)?;
+    let mut info = Storage::get_claim_type(env, &claim_type).ok_or(Error::NotFound)?;
+    info.description = description.clone();
+    Storage::set_claim_type(env, &info);
+    Events::claim_type_registered(env, &claim_type, &description);
+    Ok(())
+}
+
+/// Remove a claim type from the registry to free a slot.
+///
+/// Existing attestations of the type are untouched; only future creation
+/// under `require_registered_claim_type` is affected.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::NotFound`] — `claim_type` is not registered.
+pub fn unregister_claim_type(env: &Env, admin: Address, claim_type: String) -> Result<(), Error> {
+    admin.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    if !Storage::remove_claim_type(env, &claim_type) {
+        return Err(Error::NotFound);
+    }
+    Events::claim_type_unregistered(env, &claim_type, &admin);
+    Ok(())
+}
+
+/// Mark `claim_type` as a singleton: an issuer may hold at most one valid
+/// attestation of it per subject. Superseding requires revoking the old one.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::InvalidClaimType`] — `claim_type` fails format validation.
+pub fn set_singleton_claim_type(env: &Env, admin: Address, claim_type: String, singleton: bool) -> Result<(), Error> {
+    admin.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Validation::validate_claim_type(&claim_type)?;
+    Storage::set_singleton_claim_type(env, &claim_type, singleton);
+    Ok(())
+}
+
+pub fn is_singleton_claim_type(env: &Env, claim_type: String) -> bool {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Storage::is_singleton_claim_type(env, &claim_type)
+}
+
+/// Block an issuer from re-issuing `claim_type` to a subject for `seconds`
+/// after revoking that subject's previous one. `0` disables the cooldown.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::InvalidClaimType`] — `claim_type` fails format validation.
+pub fn set_revocation_cooldown(env: &Env, admin: Address, claim_type: String, seconds: u64) -> Result<(), Error> {
+    admin.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Validation::validate_claim_type(&claim_type)?;
+    Storage::set_revocation_cooldown(env, &claim_type, seconds);
+    Ok(())
+}
+
+pub fn get_revocation_cooldown(env: &Env, claim_type: String) -> u64 {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Storage::get_revocation_cooldown(env, &claim_type)
+}
+
+/// Set the maximum claim-type registry size. Lowering it below the current
+/// size blocks new registrations until enough types are unregistered.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+pub fn set_max_claim_types(env: &Env, admin: Address, max: u32) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Storage::set_max_claim_types(env, max);
+    Ok(())
+}
+
+pub fn get_max_claim_types(env: &Env) -> u32 {
+    Storage::get_max_claim_types(env)
+}
+
 pub fn get_claim_type_description(env: &Env, claim_type: String) -> Option<String> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Storage::get_claim_type(env, &claim_type).map(|info| info.description)
 }
 
@@ -483,13 +1474,16 @@ pub fn list_claim_types(env: &Env, start: u32, limit: u32) -> Vec<String> {
 
 pub fn set_claim_type_constraints(env: &Env, admin: Address, claim_type: String, constraints: crate::types::ClaimTypeConstraints) -> Result<(), Error> {
     admin.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     Validation::validate_claim_type(&claim_type)?;
     Storage::set_claim_type_constraints(env, &claim_type, &constraints);
     Ok(())
 }
 
 pub fn get_claim_type_constraints(env: &Env, claim_type: String) -> Option<crate::types::ClaimTypeConstraints> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Storage::get_claim_type_constraints(env, &claim_type)
 }
 
@@ -505,6 +1499,7 @@ pub fn delegate_claim_type(
     expiration: Option<u64>,
 ) -> Result<(), Error> {
     issuer.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Validation::require_issuer(env, &issuer)?;
     if issuer == delegate {
         return Err(Error::CannotDelegateToSelf);
@@ -528,6 +1523,7 @@ pub fn revoke_delegation(
     claim_type: String,
 ) -> Result<(), Error> {
     issuer.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Validation::require_issuer(env, &issuer)?;
     if Storage::get_delegation(env, &issuer, &delegate, &claim_type).is_none() {
         return Err(Error::NotFound);
@@ -539,7 +1535,7 @@ pub fn revoke_delegation(
 
 pub fn revoke_delegation_all(env: &Env, delegator: Address) -> Result<(), Error> {
     delegator.require_auth();
-    Validation::require_not_paused(env)?;
+    Validation::require_create_not_paused(env)?;
     let index = Storage::get_delegator_index(env, &delegator);
     for (delegate, claim_type) in index.iter() {
         if Storage::get_delegation(env, &delegator, &delegate, &claim_type).is_some() {
@@ -603,6 +1599,53 @@ pub fn remove_expiration_hook(env: &Env, subject: Address) -> Result<(), Error>
     Ok(())
 }
 
+// -----------------------------------------------------------------------
+// Revocation subscriptions
+// -----------------------------------------------------------------------
+
+/// Ask to be notified whenever `subject`'s `claim_type` attestation is
+/// revoked: each revocation, including the one performed by supersession,
+/// emits a `rev_note` event topic'd on every subscriber. Expiry and pruning
+/// by `sweep_expired` are not revocations and send no notice. Subscribing
+/// twice is a no-op.
+///
+/// Both `subscriber` and `subject` must sign, so a third party cannot fill the
+/// subject's bounded subscriber list without the subject's consent.
+///
+/// # Errors
+/// - [`Error::LimitExceeded`] — the pair already has
+///   `MAX_REVOCATION_SUBSCRIBERS` subscribers.
+pub fn subscribe_revocation(env: &Env, subscriber: Address, subject: Address, claim_type: String) -> Result<(), Error> {
+    subscriber.require_auth();
+    subject.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let mut subscribers = Storage::get_revocation_subscribers(env, &subject, &claim_type);
+    if subscribers.contains(&subscriber) {
+        return Ok(());
+    }
+    if subscribers.len() >= MAX_REVOCATION_SUBSCRIBERS {
+        return Err(Error::LimitExceeded);
+    }
+    subscribers.push_back(subscriber);
+    Storage::set_revocation_subscribers(env, &subject, &claim_type, &subscribers);
+    Ok(())
+}
+
+pub fn unsubscribe_revocation(env: &Env, subscriber: Address, subject: Address, claim_type: String) {
+    subscriber.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let mut subscribers = Storage::get_revocation_subscribers(env, &subject, &claim_type);
+    if let Some(index) = subscribers.first_index_of(&subscriber) {
+        subscribers.remove(index);
+        Storage::set_revocation_subscribers(env, &subject, &claim_type, &subscribers);
+    }
+}
+
+pub fn get_revocation_subscribers(env: &Env, subject: Address, claim_type: String) -> Vec<Address> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Storage::get_revocation_subscribers(env, &subject, &claim_type)
+}
+
 // -----------------------------------------------------------------------
 // Misc
 // -----------------------------------------------------------------------
@@ -633,6 +1676,7 @@ pub fn create_council_proposal(
 ) -> Result<u32, Error> {
     proposer.require_auth();
     Validation::require_admin(env, &proposer)?;
+    Storage::record_admin_action(env);
 
     let id = Storage::next_proposal_id(env);
     let mut approvals: soroban_sdk::Vec<Address> = soroban_sdk::Vec::new(env);
@@ -658,6 +1702,7 @@ pub fn approve_council_proposal(
 ) -> Result<(), Error> {
     approver.require_auth();
     Validation::require_admin(env, &approver)?;
+    Storage::record_admin_action(env);
 
     let mut proposal = Storage::get_proposal(env, proposal_id).ok_or(Error::NotFound)?;
     if proposal.executed {
@@ -693,6 +1738,7 @@ pub fn execute_council_action(
 ) -> Result<(), Error> {
     executor.require_auth();
     Validation::require_admin(env, &executor)?;
+    Storage::record_admin_action(env);
 
     let mut proposal = Storage::get_proposal(env, proposal_id).ok_or(Error::NotFound)?;
     if proposal.executed {
@@ -700,7 +1746,12 @@ pub fn execute_council_action(
     }
     let quorum_at = proposal.quorum_reached_at.ok_or(Error::Unauthorized)?;
 
-    let delay = Storage::get_council_timelock_delay(env);
+    // Issuer removal is a timelocked operation; the council may not bypass
+    // the operation timelock by running it with a shorter council delay.
+    let mut delay = Storage::get_council_timelock_delay(env);
+    if let CouncilOperation::RemoveIssuer(_) = proposal.operation {
+        delay = delay.max(Storage::get_operation_timelock(env));
+    }
     let now = env.ledger().timestamp();
     if now < quorum_at.saturating_add(delay) {
         return Err(Error::TimelockNotReady);
@@ -708,6 +1759,7 @@ pub fn execute_council_action(
 
     match proposal.operation.clone() {
         CouncilOperation::RemoveIssuer(issuer) => {
+            Validation::require_registry_not_frozen(env)?;
             Storage::remove_issuer(env, &issuer);
             Storage::decrement_total_issuers(env);
             Events::issuer_removed(env, &issuer, &executor, now);
@@ -731,6 +1783,7 @@ pub fn get_council_proposal(env: &Env, proposal_id: u32) -> Option<CouncilPropos
 pub fn set_council_timelock_delay(env: &Env, admin: Address, delay_seconds: u64) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     Storage::set_council_timelock_delay(env, delay_seconds);
     Ok(())
 }
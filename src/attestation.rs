@@ -1,11 +1,11 @@
-use soroban_sdk::{token::TokenClient, Address, Env, String, Vec};
+use soroban_sdk::{token::TokenClient, xdr::ToXdr, Address, Bytes, BytesN, Env, String, TryFromVal, Vec};
 
-use crate::constants::SECS_PER_DAY;
+use crate::constants::{MAX_SWEEP_SCAN, SECS_PER_DAY, SUPERSEDED_REASON, SWEEP_BUFFER_SECS};
 use crate::events::Events;
 use crate::storage::Storage;
 use crate::types::{
-    Attestation, AttestationOrigin, AttestationVersionSnapshot, AuditAction, AuditEntry,
-    Endorsement, Error, FeeConfig,
+    Attestation, AttestationOrigin, AttestationStatus, AttestationVersionSnapshot, Category, AuditAction, AuditEntry,
+    Endorsement, Error, FeeConfig, LegacyAttestation,
 };
 use crate::validation::Validation;
 
@@ -32,15 +32,31 @@ pub fn u64_to_string(env: &Env, n: u64) -> String {
 // Shared helpers (pub so admin.rs / multisig.rs / request.rs can reuse)
 // -----------------------------------------------------------------------
 
+/// Reject expirations that are not strictly in the future.
+///
+/// `Some(0)` is always rejected, even on a ledger whose timestamp is 0, so a
+/// zero expiration can never be confused with "no expiration".
 pub fn validate_native_expiration(env: &Env, expiration: Option<u64>) -> Result<(), Error> {
     if let Some(v) = expiration {
-        if v <= env.ledger().timestamp() {
+        if v == 0 || v <= env.ledger().timestamp() {
             return Err(Error::InvalidExpiration);
         }
     }
     Ok(())
 }
 
+/// When `enforce_explicit_permanence` is on, reject a missing expiration
+/// unless the caller explicitly asked for a permanent attestation.
+pub fn validate_permanence(env: &Env, expiration: Option<u64>, permanent: bool) -> Result<(), Error> {
+    if expiration.is_none()
+        && !permanent
+        && Storage::get_contract_config(env).is_some_and(|config| config.enforce_explicit_permanence)
+    {
+        return Err(Error::ExpirationRequired);
+    }
+    Ok(())
+}
+
 pub fn validate_valid_from(env: &Env, valid_from: Option<u64>) -> Result<(), Error> {
     if let Some(vf) = valid_from {
         if vf <= env.ledger().timestamp() {
@@ -55,7 +71,7 @@ pub fn validate_import_timestamps(env: &Env, timestamp: u64, expiration: Option<
         return Err(Error::InvalidTimestamp);
     }
     if let Some(v) = expiration {
-        if v <= timestamp {
+        if v == 0 || v <= timestamp {
             return Err(Error::InvalidExpiration);
         }
     }
@@ -172,6 +188,72 @@ pub fn check_rate_limit(env: &Env, issuer: &Address, claim_type: &String) -> Res
     Ok(())
 }
 
+/// Reject a new attestation of a singleton claim type while `issuer` still
+/// has a valid one of that type for `subject`.
+pub fn check_singleton(env: &Env, issuer: &Address, subject: &Address, claim_type: &String) -> Result<(), Error> {
+    if !Storage::is_singleton_claim_type(env, claim_type) {
+        return Ok(());
+    }
+    let current_time = env.ledger().timestamp();
+    for attestation_id in Storage::get_valid_attestations(env, subject).iter() {
+        if let Ok(existing) = Storage::get_attestation(env, &attestation_id) {
+            if &existing.issuer == issuer
+                && &existing.claim_type == claim_type
+                && crate::query::effective_status(env, &existing, current_time) == AttestationStatus::Valid
+            {
+                return Err(Error::SingletonViolation);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reject re-issuing a claim type to a subject while the issuer's last
+/// revocation of that tuple is still inside the claim type's cooldown.
+pub fn check_revocation_cooldown(env: &Env, issuer: &Address, subject: &Address, claim_type: &String) -> Result<(), Error> {
+    let cooldown = Storage::get_revocation_cooldown(env, claim_type);
+    if cooldown == 0 {
+        return Ok(());
+    }
+    if let Some(revoked_at) = Storage::get_last_revocation(env, issuer, subject, claim_type) {
+        if env.ledger().timestamp() < revoked_at.saturating_add(cooldown) {
+            return Err(Error::RevocationCooldown);
+        }
+    }
+    Ok(())
+}
+
+/// Remember when a tuple was revoked, but only for claim types with a
+/// cooldown so unconfigured types pay no extra write. Supersession does not
+/// record: the replacement is issued in the same call.
+fn record_revocation_time(env: &Env, attestation: &Attestation) {
+    if Storage::get_revocation_cooldown(env, &attestation.claim_type) > 0 {
+        Storage::set_last_revocation(
+            env,
+            &attestation.issuer,
+            &attestation.subject,
+            &attestation.claim_type,
+            env.ledger().timestamp(),
+        );
+    }
+}
+
+/// Enforce the admin-configured cap on attestations per issuer per ledger.
+///
+/// `count` is the number of attestations about to be created. A cap of 0 or
+/// no configured cap disables the check.
+pub fn check_ledger_rate_limit(env: &Env, issuer: &Address, count: u32) -> Result<(), Error> {
+    let max_per_ledger = match Storage::get_max_per_ledger(env) {
+        Some(max) if max > 0 => max,
+        _ => return Ok(()),
+    };
+    let created = Storage::get_issuer_ledger_count(env, issuer);
+    if created.saturating_add(count) > max_per_ledger {
+        return Err(Error::IssuerRateLimited);
+    }
+    Ok(())
+}
+
 pub fn load_fee_config(env: &Env) -> Result<FeeConfig, Error> {
     Storage::get_fee_config(env).ok_or(Error::NotInitialized)
 }
@@ -186,6 +268,17 @@ pub fn charge_attestation_fee(env: &Env, issuer: &Address) -> Result<(), Error>
     Ok(())
 }
 
+/// Add the metered creation fee for `count` attestations of `claim_type` to
+/// the issuer's owed balance. Accounting only; no tokens move.
+pub fn accrue_issuer_fee(env: &Env, issuer: &Address, claim_type: &String, count: u32) {
+    let fee = Storage::get_claim_type_fee(env, claim_type);
+    if fee == 0 {
+        return;
+    }
+    let owed = Storage::get_issuer_owed(env, issuer).saturating_add(fee.saturating_mul(i128::from(count)));
+    Storage::set_issuer_owed(env, issuer, owed);
+}
+
 pub fn store_attestation(env: &Env, attestation: &Attestation) {
     Storage::set_attestation(env, attestation);
     // Write to both the legacy flat index (for backwards-compatible reads) and
@@ -193,12 +286,14 @@ pub fn store_attestation(env: &Env, attestation: &Attestation) {
     Storage::add_subject_attestation(env, &attestation.subject, &attestation.id);
     Storage::add_issuer_attestation(env, &attestation.issuer, &attestation.id);
     Storage::add_valid_attestation(env, &attestation.subject, &attestation.id);
+    Storage::add_subject_claim_type_id(env, &attestation.subject, &attestation.claim_type, &attestation.id);
     crate::storage::ChunkedIndex::add_subject(env, &attestation.subject, &attestation.id);
     crate::storage::ChunkedIndex::add_issuer(env, &attestation.issuer, &attestation.id);
     let mut stats = Storage::get_issuer_stats(env, &attestation.issuer);
     stats.total_issued += 1;
     Storage::set_issuer_stats(env, &attestation.issuer, &stats);
     Storage::increment_total_attestations(env, 1);
+    Storage::increment_claim_type_count(env, &attestation.claim_type, 1);
 }
 
 pub fn maybe_trigger_expiration_hook(
@@ -221,60 +316,230 @@ pub fn maybe_trigger_expiration_hook(
     }
 }
 
+/// Extend a valid attestation that was just verified inside its claim type's
+/// auto-extend grace window, if the issuer consented and is still active.
+/// Revoked, expired, expiration-locked and non-expiring attestations are
+/// never touched, and each attestation is extended at most
+/// `max_extensions` times, however many verifiers query it.
+pub fn maybe_auto_extend(env: &Env, attestation: &Attestation, current_time: u64) {
+    let expiration = match attestation.expiration {
+        Some(ts) if ts > current_time && !attestation.revoked && !attestation.expiration_locked => ts,
+        _ => return,
+    };
+    let config = match Storage::get_auto_extend(env, &attestation.claim_type) {
+        Some(c) => c,
+        None => return,
+    };
+    if expiration.saturating_sub(current_time) > config.grace_window
+        || !Storage::has_auto_extend_consent(env, &attestation.issuer, &attestation.claim_type)
+        || !Validation::is_issuer_active(env, &attestation.issuer)
+    {
+        return;
+    }
+    let extensions = Storage::get_auto_extend_count(env, &attestation.id);
+    if extensions >= config.max_extensions {
+        return;
+    }
+    Storage::set_auto_extend_count(env, &attestation.id, extensions + 1);
+    let new_expiration = Some(expiration.saturating_add(config.extension));
+    let mut updated = attestation.clone();
+    updated.expiration = new_expiration;
+    Storage::set_attestation(env, &updated);
+    Storage::extend_attestation_ttl_for_expiry(env, &updated.id, new_expiration);
+    Events::attestation_renewed(env, &updated.id, &updated.issuer, new_expiration);
+    Storage::append_audit_entry(env, &updated.id, &AuditEntry {
+        action: AuditAction::Renewed,
+        actor: updated.issuer.clone(),
+        timestamp: current_time,
+        details: new_expiration.map(|ts| u64_to_string(env, ts)),
+    });
+}
+
+/// When cohort mode is configured for `claim_type`, replace the requested
+/// expiration with one anchored to the subject's first attestation of that
+/// type, so all of the subject's claims of that type expire together.
+fn apply_cohort_expiration(
+    env: &Env,
+    subject: &Address,
+    claim_type: &String,
+    expiration: Option<u64>,
+) -> Option<u64> {
+    match Storage::get_cohort_expiration(env, claim_type) {
+        Some(window) => {
+            let anchor = Storage::get_subject_first_seen(env, subject, claim_type)
+                .unwrap_or_else(|| env.ledger().timestamp());
+            Some(anchor.saturating_add(window))
+        }
+        None => expiration,
+    }
+}
+
 // -----------------------------------------------------------------------
 // Attestation creation
 // -----------------------------------------------------------------------
 
-pub fn create_attestation_internal(
+/// Claim-type policy every path that builds a native attestation must pass:
+/// cohort expiration, explicit permanence, singleton and revocation
+/// cooldown. Returns the effective expiration, validated after the cohort
+/// anchor has replaced the caller's value, so an old anchor cannot store an
+/// already-expired attestation.
+pub(crate) fn check_creation_policy(
     env: &Env,
-    issuer: Address,
-    subject: Address,
-    claim_type: String,
+    issuer: &Address,
+    subject: &Address,
+    claim_type: &String,
     expiration: Option<u64>,
-    metadata: Option<String>,
-    jurisdiction: Option<String>,
-    tags: Option<Vec<String>>,
-    valid_from: Option<u64>,
-) -> Result<String, Error> {
-    issuer.require_auth();
-    Validation::require_not_paused(env)?;
-    Validation::require_issuer(env, &issuer)?;
-    Validation::validate_claim_type(&claim_type)?;
-    Validation::require_registered_claim_type(env, &claim_type)?;
-    Validation::validate_metadata(env, &metadata)?;
-    Validation::validate_claim_constraints(env, &claim_type, &metadata)?;
-    Validation::validate_metadata_hash_only(env, &metadata)?;
-    validate_jurisdiction(env, &jurisdiction)?;
-    validate_tags(&tags)?;
+    permanent: bool,
+) -> Result<Option<u64>, Error> {
+    let expiration = apply_cohort_expiration(env, subject, claim_type, expiration);
     validate_native_expiration(env, expiration)?;
-    validate_valid_from(env, valid_from)?;
+    validate_permanence(env, expiration, permanent)?;
+    check_singleton(env, issuer, subject, claim_type)?;
+    check_revocation_cooldown(env, issuer, subject, claim_type)?;
+    Ok(expiration)
+}
+
+/// Bookkeeping every path that stores a single native attestation must run:
+/// anchors the subject's cohort and accrues the metered claim-type fee.
+pub(crate) fn record_creation(env: &Env, attestation: &Attestation) {
+    Storage::set_subject_first_seen_if_absent(env, &attestation.subject, &attestation.claim_type, attestation.timestamp);
+    accrue_issuer_fee(env, &attestation.issuer, &attestation.claim_type, 1);
+}
+
+/// Optional fields the `create_*` variants set on a new native attestation.
+/// Threaded through [`create_attestation_internal`] so the record, its
+/// `created` event and its audit entry are written once, with final values.
+#[derive(Clone)]
+pub struct CreationOptions {
+    pub jurisdiction: Option<String>,
+    pub valid_from: Option<u64>,
+    pub permanent: bool,
+    pub category: Category,
+    pub revocable: bool,
+    pub stake: Option<i128>,
+    pub score: Option<i128>,
+    pub expiration_locked: bool,
+    pub depends_on: Option<String>,
+}
+
+impl Default for CreationOptions {
+    fn default() -> Self {
+        Self {
+            jurisdiction: None,
+            valid_from: None,
+            permanent: false,
+            category: Category::Other,
+            revocable: true,
+            stake: None,
+            score: None,
+            expiration_locked: false,
+            depends_on: None,
+        }
+    }
+}
+
+/// Run every check `create_attestation_internal` performs before writing,
+/// except authorization. Returns the derived attestation id and the
+/// effective expiration (after cohort adjustment).
+fn check_native_creation(
+    env: &Env,
+    issuer: &Address,
+    subject: &Address,
+    claim_type: &String,
+    expiration: Option<u64>,
+    metadata: &Option<String>,
+    tags: &Option<Vec<String>>,
+    options: &CreationOptions,
+) -> Result<(String, Option<u64>), Error> {
+    Validation::require_create_not_paused(env)?;
+    Validation::require_issuer(env, issuer)?;
+    Validation::validate_claim_type(claim_type)?;
+    Validation::require_registered_claim_type(env, claim_type)?;
+    Validation::validate_metadata(env, metadata)?;
+    Validation::validate_claim_constraints(env, claim_type, metadata)?;
+    Validation::validate_metadata_hash_only(env, metadata)?;
+    validate_jurisdiction(env, &options.jurisdiction)?;
+    validate_tags(tags)?;
+    if options.stake.is_some_and(|stake| stake < 0) {
+        return Err(Error::InvalidStake);
+    }
+    let expiration = check_creation_policy(env, issuer, subject, claim_type, expiration, options.permanent)?;
+    validate_valid_from(env, options.valid_from)?;
 
     if issuer == subject {
         return Err(Error::Unauthorized);
     }
 
-    if Storage::is_whitelist_mode(env, &issuer) && !Storage::is_whitelisted(env, &issuer, &subject) {
+    if Storage::is_whitelist_mode(env, issuer) && !Storage::is_whitelisted(env, issuer, subject) {
         return Err(Error::SubjectNotWhitelisted);
     }
 
-    check_rate_limit(env, &issuer, &claim_type)?;
+    check_rate_limit(env, issuer, claim_type)?;
+    check_ledger_rate_limit(env, issuer, 1)?;
 
     let limits = Storage::get_limits(env);
-    let issuer_count = Storage::get_issuer_attestations(env, &issuer).len();
+    let issuer_count = Storage::get_issuer_attestations(env, issuer).len();
     if issuer_count >= limits.max_attestations_per_issuer {
         return Err(Error::LimitExceeded);
     }
-    let subject_count = Storage::get_subject_attestations(env, &subject).len();
+    let subject_count = Storage::get_subject_attestations(env, subject).len();
     if subject_count >= limits.max_attestations_per_subject {
         return Err(Error::LimitExceeded);
     }
 
     let timestamp = env.ledger().timestamp();
-    let attestation_id = Attestation::generate_id(env, &issuer, &subject, &claim_type, timestamp);
+    let attestation_id = Attestation::generate_id(env, issuer, subject, claim_type, timestamp);
 
     if Storage::has_attestation(env, &attestation_id) {
         return Err(Error::DuplicateAttestation);
     }
+    Ok((attestation_id, expiration))
+}
+
+/// Dry-run of `create_attestation`: returns the error creation would fail
+/// with, or `Ok(())`. Writes nothing and does not require auth.
+pub fn can_create_attestation(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    expiration: Option<u64>,
+) -> Result<(), Error> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    check_native_creation(env, &issuer, &subject, &claim_type, expiration, &None, &None, &CreationOptions::default())?;
+    Ok(())
+}
+
+pub fn create_attestation_internal(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    expiration: Option<u64>,
+    metadata: Option<String>,
+    tags: Option<Vec<String>>,
+    options: CreationOptions,
+) -> Result<String, Error> {
+    issuer.require_auth();
+    create_attestation_unauthed(env, issuer, subject, claim_type, expiration, metadata, tags, options)
+}
+
+/// Body of [`create_attestation_internal`] for callers that have already
+/// authenticated the issuer some other way (e.g. an ed25519 signature).
+fn create_attestation_unauthed(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    expiration: Option<u64>,
+    metadata: Option<String>,
+    tags: Option<Vec<String>>,
+    options: CreationOptions,
+) -> Result<String, Error> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let (attestation_id, expiration) =
+        check_native_creation(env, &issuer, &subject, &claim_type, expiration, &metadata, &tags, &options)?;
+    let timestamp = env.ledger().timestamp();
 
     let attestation = Attestation {
         id: attestation_id.clone(),
@@ -286,13 +551,24 @@ pub fn create_attestation_internal(
         revoked: false,
         deleted: false,
         metadata,
-        jurisdiction,
-        valid_from,
+        jurisdiction: options.jurisdiction,
+        valid_from: options.valid_from,
         origin: AttestationOrigin::Native,
         source_chain: None,
         source_tx: None,
         tags,
         revocation_reason: None,
+        pinned: false,
+        expiration_locked: options.expiration_locked,
+        superseded_by: None,
+        stake: options.stake,
+        revocable: options.revocable,
+        score: options.score,
+        category: options.category,
+        permanent: options.permanent && expiration.is_none(),
+        parent_issuer: Storage::get_effective_parent(env, &issuer),
+        depends_on: options.depends_on,
+        revocation_evidence: None,
     };
 
     store_attestation(env, &attestation);
@@ -307,11 +583,13 @@ pub fn create_attestation_internal(
         },
     );
     Storage::set_last_issuance_time(env, &issuer, timestamp);
+    Storage::increment_issuer_ledger_count(env, &issuer, 1);
     if Storage::get_claim_type_rate_limit(env, &attestation.claim_type).is_some() {
         Storage::set_last_issuance_time_by_claim_type(env, &issuer, &attestation.claim_type, timestamp);
     }
 
     charge_attestation_fee(env, &issuer)?;
+    record_creation(env, &attestation);
 
     Events::attestation_created(env, &attestation);
     Ok(attestation_id)
@@ -326,7 +604,190 @@ pub fn create_attestation(
     metadata: Option<String>,
     tags: Option<Vec<String>>,
 ) -> Result<String, Error> {
-    create_attestation_internal(env, issuer, subject, claim_type, expiration, metadata, None, tags, None)
+    create_attestation_internal(env, issuer, subject, claim_type, expiration, metadata, tags, CreationOptions::default())
+}
+
+/// Create a deliberately non-expiring attestation. Required for claims without
+/// an expiration when `enforce_explicit_permanence` is enabled.
+pub fn create_permanent_attestation(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    metadata: Option<String>,
+    tags: Option<Vec<String>>,
+) -> Result<String, Error> {
+    let options = CreationOptions { permanent: true, ..CreationOptions::default() };
+    create_attestation_internal(env, issuer, subject, claim_type, None, metadata, tags, options)
+}
+
+/// Create an attestation tagged with a coarse [`Category`].
+pub fn create_attestation_with_category(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    category: Category,
+    expiration: Option<u64>,
+    metadata: Option<String>,
+    tags: Option<Vec<String>>,
+) -> Result<String, Error> {
+    let options = CreationOptions { category, ..CreationOptions::default() };
+    create_attestation_internal(env, issuer, subject, claim_type, expiration, metadata, tags, options)
+}
+
+/// The bytes an issuer signs for [`submit_signed_attestation`]: the XDR of
+/// the contract address, issuer, subject, claim type, expiration and the
+/// issuer's current signing nonce, concatenated.
+pub fn signed_attestation_payload(
+    env: &Env,
+    issuer: &Address,
+    subject: &Address,
+    claim_type: &String,
+    expiration: Option<u64>,
+) -> Bytes {
+    let mut payload = Bytes::new(env);
+    payload.append(&env.current_contract_address().to_xdr(env));
+    payload.append(&issuer.clone().to_xdr(env));
+    payload.append(&subject.clone().to_xdr(env));
+    payload.append(&claim_type.clone().to_xdr(env));
+    payload.append(&expiration.to_xdr(env));
+    payload.append(&Storage::get_signing_nonce(env, issuer).to_xdr(env));
+    payload
+}
+
+/// Create an attestation from an issuer's off-chain ed25519 signature over
+/// [`signed_attestation_payload`], so a relayer can submit it without the
+/// issuer's account authorizing the call. Each accepted signature bumps the
+/// issuer's nonce, so it cannot be replayed.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — `issuer` is not registered or has no signing key.
+/// - Any error [`create_attestation`] can return. A configured attestation
+///   fee still requires the issuer's authorization to transfer.
+///
+/// Traps if `signature` does not verify against the issuer's current key.
+pub fn submit_signed_attestation(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    expiration: Option<u64>,
+    signature: BytesN<64>,
+) -> Result<String, Error> {
+    Validation::require_issuer(env, &issuer)?;
+    let public_key = Storage::get_issuer_signing_key(env, &issuer).ok_or(Error::Unauthorized)?;
+    let payload = signed_attestation_payload(env, &issuer, &subject, &claim_type, expiration);
+    env.crypto().ed25519_verify(&public_key, &payload, &signature);
+    Storage::set_signing_nonce(env, &issuer, Storage::get_signing_nonce(env, &issuer).saturating_add(1));
+    create_attestation_unauthed(env, issuer, subject, claim_type, expiration, None, None, CreationOptions::default())
+}
+
+/// Create an attestation its issuer can never revoke (e.g. a diploma).
+pub fn create_irrevocable_attestation(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    expiration: Option<u64>,
+    metadata: Option<String>,
+    tags: Option<Vec<String>>,
+) -> Result<String, Error> {
+    let options = CreationOptions { revocable: false, ..CreationOptions::default() };
+    create_attestation_internal(env, issuer, subject, claim_type, expiration, metadata, tags, options)
+}
+
+/// Create an attestation that expires `valid_for_seconds` after the current
+/// ledger timestamp, sparing clients their own clock arithmetic. `None`
+/// creates a non-expiring attestation.
+///
+/// # Errors
+/// - [`Error::InvalidExpiration`] — the resulting expiration overflows or is
+///   not in the future.
+pub fn create_attestation_relative(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    valid_for_seconds: Option<u64>,
+) -> Result<String, Error> {
+    let expiration = match valid_for_seconds {
+        Some(secs) => Some(env.ledger().timestamp().checked_add(secs).ok_or(Error::InvalidExpiration)?),
+        None => None,
+    };
+    create_attestation_internal(env, issuer, subject, claim_type, expiration, None, None, CreationOptions::default())
+}
+
+/// Create an attestation backed by a declared `stake`. The amount is
+/// recorded for relying parties only; no tokens move.
+///
+/// # Errors
+/// - [`Error::InvalidStake`] — `stake` is negative.
+pub fn create_staked_attestation(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    stake: i128,
+    expiration: Option<u64>,
+    metadata: Option<String>,
+    tags: Option<Vec<String>>,
+) -> Result<String, Error> {
+    let options = CreationOptions { stake: Some(stake), ..CreationOptions::default() };
+    create_attestation_internal(env, issuer, subject, claim_type, expiration, metadata, tags, options)
+}
+
+/// Create an attestation whose expiration can never be changed afterwards:
+/// `renew_attestation`, `update_expiration` and auto-extension all refuse it.
+pub fn create_locked_attestation(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    expiration: Option<u64>,
+    metadata: Option<String>,
+    tags: Option<Vec<String>>,
+) -> Result<String, Error> {
+    let options = CreationOptions { expiration_locked: true, ..CreationOptions::default() };
+    create_attestation_internal(env, issuer, subject, claim_type, expiration, metadata, tags, options)
+}
+
+/// Create an attestation carrying a numeric `score` for range gating.
+pub fn create_attestation_with_score(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    score: i128,
+    expiration: Option<u64>,
+    metadata: Option<String>,
+    tags: Option<Vec<String>>,
+) -> Result<String, Error> {
+    let options = CreationOptions { score: Some(score), ..CreationOptions::default() };
+    create_attestation_internal(env, issuer, subject, claim_type, expiration, metadata, tags, options)
+}
+
+/// Create an attestation that derives from `depends_on`, an existing
+/// attestation of the same subject (e.g. ACCREDITED from KYC_PASSED).
+pub fn create_dependent_attestation(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    depends_on: String,
+    expiration: Option<u64>,
+    metadata: Option<String>,
+) -> Result<String, Error> {
+    let parent = Storage::get_attestation(env, &depends_on)?;
+    if parent.deleted {
+        return Err(Error::NotFound);
+    }
+    if parent.subject != subject {
+        return Err(Error::InvalidDependency);
+    }
+
+    let options = CreationOptions { depends_on: Some(depends_on), ..CreationOptions::default() };
+    create_attestation_internal(env, issuer, subject, claim_type, expiration, metadata, None, options)
 }
 
 pub fn create_attestation_valid_from(
@@ -339,7 +800,8 @@ pub fn create_attestation_valid_from(
     tags: Option<Vec<String>>,
     valid_from: u64,
 ) -> Result<String, Error> {
-    create_attestation_internal(env, issuer, subject, claim_type, expiration, metadata, None, tags, Some(valid_from))
+    let options = CreationOptions { valid_from: Some(valid_from), ..CreationOptions::default() };
+    create_attestation_internal(env, issuer, subject, claim_type, expiration, metadata, tags, options)
 }
 
 pub fn create_attestation_jurisdiction(
@@ -352,7 +814,8 @@ pub fn create_attestation_jurisdiction(
     jurisdiction: Option<String>,
     tags: Option<Vec<String>>,
 ) -> Result<String, Error> {
-    create_attestation_internal(env, issuer, subject, claim_type, expiration, metadata, jurisdiction, tags, None)
+    let options = CreationOptions { jurisdiction, ..CreationOptions::default() };
+    create_attestation_internal(env, issuer, subject, claim_type, expiration, metadata, tags, options)
 }
 
 pub fn import_attestation(
@@ -365,9 +828,13 @@ pub fn import_attestation(
     expiration: Option<u64>,
 ) -> Result<String, Error> {
     admin.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Validation::require_admin(env, &admin)?;
-    Validation::require_not_paused(env)?;
+    Storage::record_admin_action(env);
+    Validation::require_create_not_paused(env)?;
     Validation::require_issuer(env, &issuer)?;
+    Validation::validate_claim_type(&claim_type)?;
+    Validation::require_registered_claim_type(env, &claim_type)?;
     validate_import_timestamps(env, timestamp, expiration)?;
 
     let attestation_id = Attestation::generate_id(env, &issuer, &subject, &claim_type, timestamp);
@@ -392,6 +859,17 @@ pub fn import_attestation(
         source_tx: None,
         tags: None,
         revocation_reason: None,
+        pinned: false,
+        expiration_locked: false,
+        superseded_by: None,
+        stake: None,
+        revocable: true,
+        score: None,
+        category: Category::Other,
+        permanent: false,
+        parent_issuer: Storage::get_effective_parent(env, &issuer),
+        depends_on: None,
+        revocation_evidence: None,
     };
 
     store_attestation(env, &attestation);
@@ -418,8 +896,11 @@ pub fn bridge_attestation(
     source_tx: String,
 ) -> Result<String, Error> {
     bridge.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Validation::require_bridge(env, &bridge)?;
-    Validation::require_not_paused(env)?;
+    Validation::require_create_not_paused(env)?;
+    Validation::validate_claim_type(&claim_type)?;
+    Validation::require_registered_claim_type(env, &claim_type)?;
     validate_source_reference(&source_chain, &source_tx)?;
 
     let timestamp = env.ledger().timestamp();
@@ -447,6 +928,17 @@ pub fn bridge_attestation(
         source_tx: Some(source_tx),
         tags: None,
         revocation_reason: None,
+        pinned: false,
+        expiration_locked: false,
+        superseded_by: None,
+        stake: None,
+        revocable: true,
+        score: None,
+        category: Category::Other,
+        permanent: false,
+        parent_issuer: None,
+        depends_on: None,
+        revocation_evidence: None,
     };
 
     store_attestation(env, &attestation);
@@ -468,12 +960,13 @@ pub fn create_attestations_batch(
     expiration: Option<u64>,
 ) -> Result<Vec<String>, Error> {
     issuer.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Validation::require_issuer(env, &issuer)?;
-    Validation::require_not_paused(env)?;
+    Validation::require_create_not_paused(env)?;
     Validation::validate_claim_type(&claim_type)?;
     Validation::require_registered_claim_type(env, &claim_type)?;
-    validate_native_expiration(env, expiration)?;
     check_rate_limit(env, &issuer, &claim_type)?;
+    check_ledger_rate_limit(env, &issuer, subjects.len())?;
 
     let timestamp = env.ledger().timestamp();
     let limits = Storage::get_limits(env);
@@ -496,6 +989,7 @@ pub fn create_attestations_batch(
         if subject_count >= limits.max_attestations_per_subject {
             return Err(Error::LimitExceeded);
         }
+        let expiration = check_creation_policy(env, &issuer, &subject, &claim_type, expiration, false)?;
         let attestation = Attestation {
             id: attestation_id.clone(),
             issuer: issuer.clone(),
@@ -513,13 +1007,26 @@ pub fn create_attestations_batch(
             source_tx: None,
             tags: None,
             revocation_reason: None,
+            pinned: false,
+            expiration_locked: false,
+            superseded_by: None,
+            stake: None,
+            revocable: true,
+            score: None,
+            category: Category::Other,
+            permanent: false,
+            parent_issuer: Storage::get_effective_parent(env, &issuer),
+            depends_on: None,
+            revocation_evidence: None,
         };
 
         // Write attestation record and per-subject index — issuer index deferred.
         Storage::set_attestation(env, &attestation);
         Storage::add_subject_attestation(env, &subject, &attestation_id);
         Storage::add_valid_attestation(env, &subject, &attestation_id);
+        Storage::add_subject_claim_type_id(env, &subject, &claim_type, &attestation_id);
         crate::storage::ChunkedIndex::add_subject(env, &subject, &attestation_id);
+        Storage::set_subject_first_seen_if_absent(env, &subject, &claim_type, timestamp);
 
         Storage::append_audit_entry(
             env,
@@ -548,11 +1055,14 @@ pub fn create_attestations_batch(
 
     // Single write: global stats (replaces N increment_total_attestations calls).
     Storage::increment_total_attestations(env, batch_len);
+    Storage::increment_claim_type_count(env, &claim_type, new_issuer_ids.len());
 
     Storage::set_last_issuance_time(env, &issuer, timestamp);
+    Storage::increment_issuer_ledger_count(env, &issuer, new_issuer_ids.len());
     if Storage::get_claim_type_rate_limit(env, &claim_type).is_some() {
         Storage::set_last_issuance_time_by_claim_type(env, &issuer, &claim_type, timestamp);
     }
+    accrue_issuer_fee(env, &issuer, &claim_type, new_issuer_ids.len());
     Ok(ids)
 }
 
@@ -562,61 +1072,391 @@ pub fn revoke_attestation(
     attestation_id: String,
     reason: Option<String>,
 ) -> Result<(), Error> {
-    issuer.require_auth();
-    Validation::require_not_paused(env)?;
-    Validation::require_issuer(env, &issuer)?;
-    validate_reason(&reason)?;
-
-    let mut attestation = Storage::get_attestation(env, &attestation_id)?;
-    if attestation.issuer != issuer {
-        return Err(Error::Unauthorized);
-    }
-    if attestation.revoked {
-        return Err(Error::AlreadyRevoked);
-    }
-
-    attestation.revoked = true;
-    attestation.revocation_reason = reason.clone();
-    Storage::set_attestation(env, &attestation);
-    Storage::remove_subject_attestation(env, &attestation.subject, &attestation_id);
-    Storage::remove_valid_attestation(env, &attestation.subject, &attestation_id);
-    Storage::remove_issuer_attestation(env, &issuer, &attestation_id);
-    crate::storage::ChunkedIndex::remove_subject(env, &attestation.subject, &attestation_id);
-    crate::storage::ChunkedIndex::remove_issuer(env, &issuer, &attestation_id);
-
-    Events::attestation_revoked(env, &attestation_id, &issuer, &reason);
-    Storage::append_audit_entry(env, &attestation_id, &AuditEntry {
-        action: AuditAction::Revoked,
-        actor: issuer.clone(),
-        timestamp: env.ledger().timestamp(),
-        details: reason.clone(),
-    });
-    Storage::increment_total_revocations(env, 1);
-    Storage::increment_issuer_revocations(env, &issuer, 1);
-    Ok(())
+    revoke_attestation_internal(env, issuer, attestation_id, reason, None)
 }
 
-pub fn renew_attestation(
+/// Revoke an attestation and attach a URI referencing the justification documents.
+pub fn revoke_attestation_with_evidence(
     env: &Env,
     issuer: Address,
     attestation_id: String,
-    new_expiration: Option<u64>,
+    reason: Option<String>,
+    evidence_uri: Option<String>,
 ) -> Result<(), Error> {
-    issuer.require_auth();
-    Validation::require_issuer(env, &issuer)?;
-    Validation::require_not_paused(env)?;
-    validate_native_expiration(env, new_expiration)?;
+    revoke_attestation_internal(env, issuer, attestation_id, reason, evidence_uri)
+}
 
-    let mut attestation = Storage::get_attestation(env, &attestation_id)?;
-    if attestation.issuer != issuer {
-        return Err(Error::Unauthorized);
-    }
-    if attestation.revoked {
+/// Revoke the attestation identified by its content rather than its id.
+///
+/// Recomputes the deterministic id from `(issuer, subject, claim_type, timestamp)`
+/// and revokes it through the normal path.
+///
+/// # Errors
+/// - [`Error::NotFound`] — no attestation matches the supplied content.
+pub fn revoke_by_content(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+    timestamp: u64,
+) -> Result<(), Error> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let attestation_id = Attestation::generate_id(env, &issuer, &subject, &claim_type, timestamp);
+    if !Storage::has_attestation(env, &attestation_id) {
+        return Err(Error::NotFound);
+    }
+    revoke_attestation_internal(env, issuer, attestation_id, None, None)
+}
+
+/// Replace `old_id` with a fresh attestation of the same subject and claim
+/// type. The original is revoked with reason `"Superseded"` and linked to its
+/// replacement through `superseded_by`, so it reports
+/// [`AttestationStatus::Superseded`] rather than `Revoked`. Jurisdiction and
+/// tags carry over.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — `issuer` did not issue `old_id`.
+/// - [`Error::AlreadyRevoked`] — `old_id` is already revoked or superseded.
+/// - [`Error::NotRevocable`] — `old_id` was created as non-revocable.
+/// - [`Error::DuplicateAttestation`] — superseding within the ledger second
+///   the original was created derives the same id.
+pub fn supersede_attestation(
+    env: &Env,
+    issuer: Address,
+    old_id: String,
+    expiration: Option<u64>,
+    metadata: Option<String>,
+) -> Result<String, Error> {
+    issuer.require_auth();
+    Validation::require_revoke_not_paused(env)?;
+    let mut old = Storage::get_attestation(env, &old_id)?;
+    if old.issuer != issuer {
+        return Err(Error::Unauthorized);
+    }
+    if old.revoked {
+        return Err(Error::AlreadyRevoked);
+    }
+    if !old.revocable {
+        return Err(Error::NotRevocable);
+    }
+
+    apply_revocation(env, &mut old, &issuer, Some(String::from_str(env, SUPERSEDED_REASON)), None);
+    let new_id = create_attestation_internal(
+        env,
+        issuer,
+        old.subject.clone(),
+        old.claim_type.clone(),
+        expiration,
+        metadata,
+        old.tags.clone(),
+        CreationOptions { jurisdiction: old.jurisdiction.clone(), ..CreationOptions::default() },
+    )?;
+    old.superseded_by = Some(new_id.clone());
+    Storage::set_attestation(env, &old);
+    Events::attestation_superseded(env, &old, &new_id);
+    Ok(new_id)
+}
+
+fn revoke_attestation_internal(
+    env: &Env,
+    issuer: Address,
+    attestation_id: String,
+    reason: Option<String>,
+    evidence_uri: Option<String>,
+) -> Result<(), Error> {
+    issuer.require_auth();
+    Validation::require_revoke_not_paused(env)?;
+    Validation::require_issuer(env, &issuer)?;
+    validate_reason(&reason)?;
+    Validation::validate_evidence_uri(&evidence_uri)?;
+
+    let mut attestation = Storage::get_attestation(env, &attestation_id)?;
+    if attestation.issuer != issuer {
+        return Err(Error::Unauthorized);
+    }
+    if attestation.revoked {
+        // Retries of an already-applied revocation succeed without side effects.
+        if Storage::get_contract_config(env).is_some_and(|config| config.idempotent_revoke) {
+            return Ok(());
+        }
+        return Err(Error::AlreadyRevoked);
+    }
+    if !attestation.revocable {
+        return Err(Error::NotRevocable);
+    }
+
+    apply_revocation(env, &mut attestation, &issuer, reason, evidence_uri);
+    record_revocation_time(env, &attestation);
+    Ok(())
+}
+
+/// Emit a `rev_note` event for each subscriber to the attestation's
+/// (subject, claim type) pair.
+fn notify_revocation_subscribers(env: &Env, attestation: &Attestation) {
+    for subscriber in Storage::get_revocation_subscribers(env, &attestation.subject, &attestation.claim_type).iter() {
+        Events::revocation_notice(env, &subscriber, attestation);
+    }
+}
+
+/// Mark `attestation` revoked, drop it from the live indexes, and record the
+/// event, audit entry and counters. `actor` is whoever authorized the revocation.
+fn apply_revocation(
+    env: &Env,
+    attestation: &mut Attestation,
+    actor: &Address,
+    reason: Option<String>,
+    evidence_uri: Option<String>,
+) {
+    let attestation_id = attestation.id.clone();
+    let issuer = attestation.issuer.clone();
+    attestation.revoked = true;
+    attestation.revocation_reason = reason.clone();
+    attestation.revocation_evidence = evidence_uri.clone();
+    Storage::set_attestation(env, attestation);
+    Storage::remove_subject_attestation(env, &attestation.subject, &attestation_id);
+    Storage::remove_valid_attestation(env, &attestation.subject, &attestation_id);
+    Storage::remove_subject_claim_type_id(env, &attestation.subject, &attestation.claim_type, &attestation_id);
+    Storage::add_subject_revoked(env, &attestation.subject, &attestation_id);
+    if attestation.expiration.is_some() {
+        Storage::add_revoked_sweep_id(env, &attestation_id);
+    }
+    Storage::remove_issuer_attestation(env, &issuer, &attestation_id);
+    crate::storage::ChunkedIndex::remove_subject(env, &attestation.subject, &attestation_id);
+    crate::storage::ChunkedIndex::remove_issuer(env, &issuer, &attestation_id);
+
+    if evidence_uri.is_some() {
+        Events::attestation_revoked_with_evidence(env, attestation, &reason, &evidence_uri);
+    } else {
+        Events::attestation_revoked(env, attestation, &reason);
+    }
+    notify_revocation_subscribers(env, attestation);
+    Storage::append_audit_entry(env, &attestation_id, &AuditEntry {
+        action: AuditAction::Revoked,
+        actor: actor.clone(),
+        timestamp: env.ledger().timestamp(),
+        details: reason,
+    });
+    Storage::increment_total_revocations(env, 1);
+    Storage::increment_issuer_revocations(env, &issuer, 1);
+    if !attestation.deleted {
+        Storage::decrement_claim_type_count(env, &attestation.claim_type);
+    }
+}
+
+/// Revoke any attestation as admin, e.g. when its issuer is unreachable.
+///
+/// Unlike issuer revocation this never honours `idempotent_revoke`: a second
+/// call fails rather than emitting a duplicate event. Use
+/// [`amend_revocation_reason`] to change the reason afterwards.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::NotFound`] — attestation does not exist or was deleted.
+/// - [`Error::AlreadyRevoked`] — attestation is already revoked.
+/// - [`Error::NotRevocable`] — attestation is non-revocable and
+///   `admin_overrides_revocable` is off.
+/// - [`Error::ReasonTooLong`] — reason exceeds 128 characters.
+/// - [`Error::TimelockNotReady`] — the operation timelock is set; queue a
+///   [`crate::types::TimelockOperation::AdminRevoke`] instead.
+pub fn admin_revoke(env: &Env, admin: Address, attestation_id: String, reason: Option<String>) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Validation::require_no_timelock(env)?;
+    apply_admin_revoke(env, &admin, attestation_id, reason)
+}
+
+/// Body of [`admin_revoke`] after authorization, shared with the timelocked
+/// `execute_operation` path.
+pub(crate) fn apply_admin_revoke(
+    env: &Env,
+    admin: &Address,
+    attestation_id: String,
+    reason: Option<String>,
+) -> Result<(), Error> {
+    Validation::require_revoke_not_paused(env)?;
+    validate_reason(&reason)?;
+
+    let mut attestation = Storage::get_attestation(env, &attestation_id)?;
+    if attestation.deleted {
+        return Err(Error::NotFound);
+    }
+    if attestation.revoked {
+        return Err(Error::AlreadyRevoked);
+    }
+    if !attestation.revocable
+        && !Storage::get_contract_config(env).is_some_and(|config| config.admin_overrides_revocable)
+    {
+        return Err(Error::NotRevocable);
+    }
+    apply_revocation(env, &mut attestation, admin, reason, None);
+    record_revocation_time(env, &attestation);
+    Ok(())
+}
+
+/// Revoke up to `limit` of `subject`'s unrevoked `claim_type` attestations,
+/// whichever issuer granted them, with reason `"AdminAction"`. Non-revocable
+/// ones are skipped unless `admin_overrides_revocable` is set. Returns the
+/// number revoked.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::TimelockNotReady`] — the operation timelock is set; queue a
+///   [`crate::types::TimelockOperation::AdminRevokeSubjectClaimType`] instead.
+pub fn admin_revoke_subject_claim_type(
+    env: &Env,
+    admin: Address,
+    subject: Address,
+    claim_type: String,
+    limit: u32,
+) -> Result<u32, Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Validation::require_no_timelock(env)?;
+    apply_admin_revoke_subject_claim_type(env, &admin, subject, claim_type, limit)
+}
+
+/// Body of [`admin_revoke_subject_claim_type`] after authorization, shared
+/// with the timelocked `execute_operation` path.
+pub(crate) fn apply_admin_revoke_subject_claim_type(
+    env: &Env,
+    admin: &Address,
+    subject: Address,
+    claim_type: String,
+    limit: u32,
+) -> Result<u32, Error> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Validation::require_revoke_not_paused(env)?;
+
+    let overrides = Storage::get_contract_config(env).is_some_and(|config| config.admin_overrides_revocable);
+    let reason = Some(String::from_str(env, "AdminAction"));
+    let mut count: u32 = 0;
+    for attestation_id in Storage::get_valid_attestations(env, &subject).iter() {
+        if count >= limit {
+            break;
+        }
+        let Ok(mut attestation) = Storage::get_attestation(env, &attestation_id) else {
+            continue;
+        };
+        if attestation.claim_type != claim_type
+            || attestation.revoked
+            || attestation.deleted
+            || (!attestation.revocable && !overrides)
+        {
+            continue;
+        }
+        apply_revocation(env, &mut attestation, admin, reason.clone(), None);
+        record_revocation_time(env, &attestation);
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Replace the recorded reason on an already-revoked attestation.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::NotFound`] — attestation does not exist or was deleted.
+/// - [`Error::NotRevoked`] — attestation has not been revoked.
+/// - [`Error::ReasonTooLong`] — reason exceeds 128 characters.
+pub fn amend_revocation_reason(
+    env: &Env,
+    admin: Address,
+    attestation_id: String,
+    reason: Option<String>,
+) -> Result<(), Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    validate_reason(&reason)?;
+
+    let mut attestation = Storage::get_attestation(env, &attestation_id)?;
+    if attestation.deleted {
+        return Err(Error::NotFound);
+    }
+    if !attestation.revoked {
+        return Err(Error::NotRevoked);
+    }
+    attestation.revocation_reason = reason.clone();
+    Storage::set_attestation(env, &attestation);
+    Storage::append_audit_entry(env, &attestation_id, &AuditEntry {
+        action: AuditAction::Amended,
+        actor: admin.clone(),
+        timestamp: env.ledger().timestamp(),
+        details: reason.clone(),
+    });
+    Events::revocation_reason_amended(env, &attestation_id, &admin, &reason);
+    Ok(())
+}
+
+/// Record that the subject is aware its attestation was revoked.
+/// Acknowledging twice is a no-op.
+///
+/// # Errors
+/// - [`Error::NotFound`] — attestation does not exist or was deleted.
+/// - [`Error::Unauthorized`] — `subject` is not the attestation's subject.
+/// - [`Error::NotRevoked`] — the attestation has not been revoked.
+pub fn acknowledge_revocation(env: &Env, subject: Address, attestation_id: String) -> Result<(), Error> {
+    subject.require_auth();
+    let attestation = Storage::get_attestation(env, &attestation_id)?;
+    if attestation.deleted {
+        return Err(Error::NotFound);
+    }
+    if attestation.subject != subject {
+        return Err(Error::Unauthorized);
+    }
+    if !attestation.revoked {
+        return Err(Error::NotRevoked);
+    }
+    if Storage::is_revocation_acknowledged(env, &attestation_id) {
+        return Ok(());
+    }
+    Storage::set_revocation_acknowledged(env, &attestation_id);
+    Events::revocation_acknowledged(env, &attestation_id, &subject);
+    Ok(())
+}
+
+pub fn is_revocation_acknowledged(env: &Env, attestation_id: String) -> bool {
+    Storage::is_revocation_acknowledged(env, &attestation_id)
+}
+
+/// Extend an attestation's expiration by `extend_seconds`.
+///
+/// The extension is applied on top of the current expiration; a non-expiring
+/// attestation is treated as expiring now. The storage TTL is re-bumped to
+/// cover the new expiration.
+pub fn renew_attestation(
+    env: &Env,
+    issuer: Address,
+    attestation_id: String,
+    extend_seconds: u64,
+) -> Result<(), Error> {
+    issuer.require_auth();
+    Validation::require_issuer(env, &issuer)?;
+    Validation::require_create_not_paused(env)?;
+    if extend_seconds == 0 {
+        return Err(Error::InvalidExpiration);
+    }
+
+    let mut attestation = Storage::get_attestation(env, &attestation_id)?;
+    if attestation.issuer != issuer {
+        return Err(Error::Unauthorized);
+    }
+    if attestation.revoked {
         return Err(Error::AlreadyRevoked);
     }
+    if attestation.expiration_locked {
+        return Err(Error::ExpirationLocked);
+    }
+
+    let base = attestation.expiration.unwrap_or_else(|| env.ledger().timestamp());
+    let new_expiration = Some(base.saturating_add(extend_seconds));
+    validate_native_expiration(env, new_expiration)?;
 
     attestation.expiration = new_expiration;
     Storage::set_attestation(env, &attestation);
+    Storage::extend_attestation_ttl_for_expiry(env, &attestation_id, new_expiration);
     Events::attestation_renewed(env, &attestation_id, &issuer, new_expiration);
     let details = new_expiration.map(|ts| u64_to_string(env, ts));
     Storage::append_audit_entry(env, &attestation_id, &AuditEntry {
@@ -637,7 +1477,7 @@ pub fn revoke_attestations_batch(
     const MAX_BATCH: u32 = 50;
 
     issuer.require_auth();
-    Validation::require_not_paused(env)?;
+    Validation::require_revoke_not_paused(env)?;
     Validation::require_issuer(env, &issuer)?;
     validate_reason(&reason)?;
 
@@ -663,6 +1503,9 @@ pub fn revoke_attestations_batch(
         if attestation.revoked {
             return Err(Error::AlreadyRevoked);
         }
+        if !attestation.revocable {
+            return Err(Error::NotRevocable);
+        }
         attestations.push_back(attestation);
     }
 
@@ -674,10 +1517,20 @@ pub fn revoke_attestations_batch(
         Storage::set_attestation(env, &attestation);
         Storage::remove_subject_attestation(env, &attestation.subject, &attestation.id);
         Storage::remove_valid_attestation(env, &attestation.subject, &attestation.id);
+        Storage::remove_subject_claim_type_id(env, &attestation.subject, &attestation.claim_type, &attestation.id);
+        Storage::add_subject_revoked(env, &attestation.subject, &attestation.id);
+        if attestation.expiration.is_some() {
+            Storage::add_revoked_sweep_id(env, &attestation.id);
+        }
+        record_revocation_time(env, &attestation);
         Storage::remove_issuer_attestation(env, &issuer, &attestation.id);
         crate::storage::ChunkedIndex::remove_subject(env, &attestation.subject, &attestation.id);
         crate::storage::ChunkedIndex::remove_issuer(env, &issuer, &attestation.id);
-        Events::attestation_revoked_with_reason(env, &attestation.id, &issuer, &reason);
+        if !attestation.deleted {
+            Storage::decrement_claim_type_count(env, &attestation.claim_type);
+        }
+        Events::attestation_revoked(env, &attestation, &reason);
+        notify_revocation_subscribers(env, &attestation);
         Storage::append_audit_entry(
             env,
             &attestation.id,
@@ -706,7 +1559,7 @@ pub fn update_expiration(
 ) -> Result<(), Error> {
     issuer.require_auth();
     Validation::require_issuer(env, &issuer)?;
-    Validation::require_not_paused(env)?;
+    Validation::require_create_not_paused(env)?;
     validate_native_expiration(env, new_expiration)?;
 
     let mut attestation = Storage::get_attestation(env, &attestation_id)?;
@@ -716,6 +1569,9 @@ pub fn update_expiration(
     if attestation.revoked {
         return Err(Error::AlreadyRevoked);
     }
+    if attestation.expiration_locked {
+        return Err(Error::ExpirationLocked);
+    }
 
     attestation.expiration = new_expiration;
     Storage::set_attestation(env, &attestation);
@@ -738,6 +1594,7 @@ pub fn transfer_attestation(
 ) -> Result<(), Error> {
     admin.require_auth();
     Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
     Validation::require_issuer(env, &new_issuer)?;
 
     let mut attestation = Storage::get_attestation(env, &attestation_id)?;
@@ -774,6 +1631,502 @@ pub fn transfer_attestation(
     Ok(())
 }
 
+/// Drop ids whose attestation record no longer exists from `subject`'s
+/// indexes (flat, valid, chunked, per-claim-type and revoked) and return how
+/// many were removed from the subject index. Callable by the subject or an
+/// admin.
+///
+/// Per-claim-type lists are compacted for every claim type that still has a
+/// record in the subject index; a list whose records are all gone cannot be
+/// located without them, but never produces a match either.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — `caller` is neither `subject` nor an admin.
+pub fn compact_subject_index(env: &Env, caller: Address, subject: Address) -> Result<u32, Error> {
+    caller.require_auth();
+    if caller != subject && !Storage::is_admin(env, &caller) {
+        return Err(Error::Unauthorized);
+    }
+
+    let retain = |ids: Vec<String>| {
+        let mut kept = Vec::new(env);
+        for id in ids.iter() {
+            if Storage::has_attestation(env, &id) {
+                kept.push_back(id);
+            }
+        }
+        kept
+    };
+
+    let ids = Storage::get_subject_attestations(env, &subject);
+    let kept = retain(ids.clone());
+    let removed = ids.len() - kept.len();
+    if removed > 0 {
+        Storage::set_subject_attestations(env, &subject, &kept);
+    }
+
+    let valid = Storage::get_valid_attestations(env, &subject);
+    let kept_valid = retain(valid.clone());
+    if kept_valid.len() < valid.len() {
+        Storage::set_valid_attestations(env, &subject, &kept_valid);
+    }
+
+    let chunked = crate::storage::ChunkedIndex::get_subject_all(env, &subject);
+    let kept_chunked = retain(chunked.clone());
+    if kept_chunked.len() < chunked.len() {
+        crate::storage::ChunkedIndex::set_subject_all(env, &subject, &kept_chunked);
+    }
+
+    let revoked = Storage::get_subject_revoked(env, &subject);
+    let kept_revoked = retain(revoked.clone());
+    if kept_revoked.len() < revoked.len() {
+        Storage::set_subject_revoked(env, &subject, &kept_revoked);
+    }
+
+    let mut claim_types: Vec<String> = Vec::new(env);
+    for id in kept.iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &id) {
+            if !claim_types.contains(&attestation.claim_type) {
+                claim_types.push_back(attestation.claim_type);
+            }
+        }
+    }
+    for claim_type in claim_types.iter() {
+        let by_type = Storage::get_subject_claim_type_ids(env, &subject, &claim_type);
+        let kept_by_type = retain(by_type.clone());
+        if kept_by_type.len() < by_type.len() {
+            Storage::set_subject_claim_type_ids(env, &subject, &claim_type, &kept_by_type);
+        }
+    }
+    Ok(removed)
+}
+
+/// Disaster recovery: replace `subject`'s live indexes (flat, valid, chunked
+/// and per-claim-type) with the subset of `candidate_ids` whose record exists,
+/// belongs to `subject`, and is neither revoked nor deleted. Duplicates are
+/// dropped and candidate order is kept. Per-claim-type lists are rewritten
+/// for every claim type seen in the old or new index. Revoked candidates
+/// are added to the subject's revoked list, which backfills revocations made
+/// before that list existed. Returns the number of ids in the rebuilt index.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::LimitExceeded`] — more candidates than
+///   `max_attestations_per_subject`.
+pub fn rebuild_subject_index(env: &Env, admin: Address, subject: Address, candidate_ids: Vec<String>) -> Result<u32, Error> {
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    if candidate_ids.len() > Storage::get_limits(env).max_attestations_per_subject {
+        return Err(Error::LimitExceeded);
+    }
+
+    let mut verified: Vec<String> = Vec::new(env);
+    let mut claim_types: Vec<String> = Vec::new(env);
+    for id in candidate_ids.iter() {
+        if verified.contains(&id) {
+            continue;
+        }
+        if let Ok(attestation) = Storage::get_attestation(env, &id) {
+            if attestation.subject == subject && attestation.revoked && !attestation.deleted {
+                Storage::add_subject_revoked(env, &subject, &id);
+                continue;
+            }
+            if attestation.subject == subject && !attestation.revoked && !attestation.deleted {
+                if !claim_types.contains(&attestation.claim_type) {
+                    claim_types.push_back(attestation.claim_type);
+                }
+                verified.push_back(id);
+            }
+        }
+    }
+    for id in Storage::get_subject_attestations(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &id) {
+            if !claim_types.contains(&attestation.claim_type) {
+                claim_types.push_back(attestation.claim_type);
+            }
+        }
+    }
+
+    Storage::set_subject_attestations(env, &subject, &verified);
+    Storage::set_valid_attestations(env, &subject, &verified);
+    crate::storage::ChunkedIndex::set_subject_all(env, &subject, &verified);
+    for claim_type in claim_types.iter() {
+        let mut ids = Vec::new(env);
+        for id in verified.iter() {
+            if let Ok(attestation) = Storage::get_attestation(env, &id) {
+                if attestation.claim_type == claim_type {
+                    ids.push_back(id);
+                }
+            }
+        }
+        Storage::set_subject_claim_type_ids(env, &subject, &claim_type, &ids);
+    }
+    Ok(verified.len())
+}
+
+/// Rewrite attestations stored in the [`LegacyAttestation`] layout into the
+/// current one, backfilling new fields with their defaults. Run once after
+/// `upgrade`, in batches of at most 50 ids. Idempotent: ids that are missing
+/// or already in the current layout are skipped. Returns the number rewritten.
+///
+/// # Errors
+/// - [`Error::Unauthorized`] — caller is not an admin.
+/// - [`Error::LimitExceeded`] — more than 50 ids.
+pub fn migrate_attestations(env: &Env, admin: Address, attestation_ids: Vec<String>) -> Result<u32, Error> {
+    const MAX_BATCH: u32 = 50;
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    if attestation_ids.len() > MAX_BATCH {
+        return Err(Error::LimitExceeded);
+    }
+
+    let mut migrated: u32 = 0;
+    for id in attestation_ids.iter() {
+        let Some(raw) = Storage::get_attestation_val(env, &id) else {
+            continue;
+        };
+        if Attestation::try_from_val(env, &raw).is_ok() {
+            continue;
+        }
+        if let Ok(legacy) = LegacyAttestation::try_from_val(env, &raw) {
+            Storage::set_attestation(env, &Attestation::from(legacy));
+            migrated += 1;
+        }
+    }
+    Ok(migrated)
+}
+
+/// Prune up to `limit` attestations that expired more than
+/// [`SWEEP_BUFFER_SECS`] ago. Callable by anyone; attestations without an
+/// expiration, still inside the buffer, or pinned by their subject are never
+/// touched. Pruned records are deleted outright, so later lookups by id
+/// return [`Error::NotFound`]. Returns the number pruned.
+///
+/// Walks the issuer indexes of registered issuers, bridges, and removed
+/// issuers and sub-issuers, then the list of revoked attestations (which
+/// revocation drops from issuer indexes). Each call reads at most
+/// [`MAX_SWEEP_SCAN`] records and resumes where the previous call stopped,
+/// wrapping to the start once every index has been walked.
+pub fn sweep_expired(env: &Env, limit: u32) -> u32 {
+    if limit == 0 {
+        return 0;
+    }
+    let now = env.ledger().timestamp();
+    let sources = sweep_sources(env);
+    let (mut source, mut position) = Storage::get_sweep_cursor(env);
+    let mut pruned: u32 = 0;
+    let mut scanned: u32 = 0;
+
+    'sweep: while let Some(issuer) = sources.get(source) {
+        // `ids` is a snapshot; `position` tracks the live index, which loses
+        // an entry every time one is pruned.
+        let ids = Storage::get_issuer_attestations(env, &issuer);
+        let mut index = position;
+        while let Some(attestation_id) = ids.get(index) {
+            if pruned >= limit || scanned >= MAX_SWEEP_SCAN {
+                break 'sweep;
+            }
+            scanned += 1;
+            index += 1;
+            let prunable = Storage::get_attestation(env, &attestation_id)
+                .ok()
+                .filter(|attestation| is_prunable(attestation, now));
+            match prunable {
+                Some(attestation) => {
+                    prune_attestation(env, &issuer, &attestation);
+                    pruned += 1;
+                    if pruned >= limit {
+                        break 'sweep;
+                    }
+                }
+                None => position += 1,
+            }
+        }
+        source += 1;
+        position = 0;
+    }
+
+    // The revoked list is walked as one more source after the issuer indexes.
+    if source == sources.len() && pruned < limit && scanned < MAX_SWEEP_SCAN {
+        let ids = Storage::get_revoked_sweep_ids(env);
+        let mut index = position;
+        let mut finished = true;
+        while let Some(attestation_id) = ids.get(index) {
+            if pruned >= limit || scanned >= MAX_SWEEP_SCAN {
+                finished = false;
+                break;
+            }
+            scanned += 1;
+            index += 1;
+            match Storage::get_attestation(env, &attestation_id) {
+                Ok(attestation) if is_prunable(&attestation, now) => {
+                    prune_attestation(env, &attestation.issuer, &attestation);
+                    pruned += 1;
+                }
+                Ok(attestation) if attestation.revoked => position += 1,
+                // Already gone or no longer revoked: nothing left to sweep.
+                _ => Storage::remove_revoked_sweep_id(env, &attestation_id),
+            }
+        }
+        if finished {
+            source += 1;
+            position = 0;
+        }
+    }
+
+    let cursor = if source > sources.len() { (0, 0) } else { (source, position) };
+    Storage::set_sweep_cursor(env, cursor);
+    if pruned > 0 {
+        Events::expired_swept(env, pruned, now);
+    }
+    pruned
+}
+
+/// Every issuer index that can hold attestations, in order: registered
+/// issuers, bridges, then removed issuers and sub-issuers not already listed.
+pub(crate) fn sweep_sources(env: &Env) -> Vec<Address> {
+    let mut sources = Storage::get_issuer_list(env);
+    for bridge in Storage::get_bridge_list(env).iter() {
+        if !sources.contains(&bridge) {
+            sources.push_back(bridge);
+        }
+    }
+    for extra in Storage::get_sweep_sources(env).iter() {
+        if !sources.contains(&extra) {
+            sources.push_back(extra);
+        }
+    }
+    sources
+}
+
+/// Unpinned and expired for longer than [`SWEEP_BUFFER_SECS`].
+fn is_prunable(attestation: &Attestation, now: u64) -> bool {
+    !attestation.pinned
+        && attestation
+            .expiration
+            .is_some_and(|expiration| now >= expiration.saturating_add(SWEEP_BUFFER_SECS))
+}
+
+/// Delete an expired attestation record and drop it from every index.
+fn prune_attestation(env: &Env, issuer: &Address, attestation: &Attestation) {
+    let attestation_id = &attestation.id;
+    Storage::remove_subject_attestation(env, &attestation.subject, attestation_id);
+    Storage::remove_valid_attestation(env, &attestation.subject, attestation_id);
+    Storage::remove_subject_claim_type_id(env, &attestation.subject, &attestation.claim_type, attestation_id);
+    Storage::remove_issuer_attestation(env, issuer, attestation_id);
+    crate::storage::ChunkedIndex::remove_subject(env, &attestation.subject, attestation_id);
+    crate::storage::ChunkedIndex::remove_issuer(env, issuer, attestation_id);
+    if attestation.revoked {
+        Storage::remove_subject_revoked(env, &attestation.subject, attestation_id);
+        Storage::remove_revoked_sweep_id(env, attestation_id);
+    }
+    if !attestation.deleted && !attestation.revoked {
+        Storage::decrement_claim_type_count(env, &attestation.claim_type);
+    }
+    Storage::remove_attestation(env, attestation_id);
+}
+
+/// Pin one of the subject's own attestations so `sweep_expired` never prunes
+/// it, and extend its storage TTL to the network maximum.
+///
+/// # Errors
+/// - [`Error::NotFound`] — attestation does not exist or was deleted.
+/// - [`Error::Unauthorized`] — `subject` is not the attestation's subject.
+pub fn pin_attestation(env: &Env, subject: Address, attestation_id: String) -> Result<(), Error> {
+    set_pinned(env, subject, attestation_id, true)
+}
+
+/// Undo [`pin_attestation`]; the attestation becomes prunable again.
+///
+/// # Errors
+/// - [`Error::NotFound`] — attestation does not exist or was deleted.
+/// - [`Error::Unauthorized`] — `subject` is not the attestation's subject.
+pub fn unpin_attestation(env: &Env, subject: Address, attestation_id: String) -> Result<(), Error> {
+    set_pinned(env, subject, attestation_id, false)
+}
+
+fn set_pinned(env: &Env, subject: Address, attestation_id: String, pinned: bool) -> Result<(), Error> {
+    subject.require_auth();
+    let mut attestation = Storage::get_attestation(env, &attestation_id)?;
+    if attestation.deleted {
+        return Err(Error::NotFound);
+    }
+    if attestation.subject != subject {
+        return Err(Error::Unauthorized);
+    }
+    attestation.pinned = pinned;
+    Storage::set_attestation(env, &attestation);
+    if pinned {
+        Storage::extend_attestation_ttl_to_max(env, &attestation_id);
+    }
+    Ok(())
+}
+
+/// Move an attestation to `new_subject`, e.g. after the subject migrates to a
+/// new account. The issuer, which must still be active, re-vouches for the
+/// new address; when `require_consent` is set the new subject must also
+/// authorize the move. Only live attestations move: revoked and expired ones
+/// (including dependents of an expired parent) stay with the old subject.
+/// The new subject must pass the issuer's whitelist and the claim type's
+/// singleton and revocation-cooldown rules, as for a fresh attestation.
+///
+/// # Errors
+/// - [`Error::NotFound`] — attestation does not exist or was deleted.
+/// - [`Error::Unauthorized`] — `issuer` is not a registered issuer, did not
+///   create the attestation, or `new_subject` is the issuer.
+/// - [`Error::IssuerPaused`] / [`Error::IssuerExpired`] — `issuer` is paused
+///   or its registration has lapsed.
+/// - [`Error::AlreadyRevoked`] — the attestation has been revoked.
+/// - [`Error::Expired`] — the attestation or one of its parents has expired.
+/// - [`Error::SubjectNotWhitelisted`] — the issuer is in whitelist mode and
+///   `new_subject` is not whitelisted.
+/// - [`Error::SingletonViolation`] — `new_subject` already holds a valid
+///   attestation of this singleton claim type from the issuer.
+/// - [`Error::RevocationCooldown`] — the issuer revoked this claim type for
+///   `new_subject` too recently.
+/// - [`Error::LimitExceeded`] — `new_subject` is at its attestation limit.
+pub fn transfer_to_subject(
+    env: &Env,
+    issuer: Address,
+    attestation_id: String,
+    new_subject: Address,
+    require_consent: bool,
+) -> Result<(), Error> {
+    issuer.require_auth();
+    if require_consent {
+        new_subject.require_auth();
+    }
+    Validation::require_create_not_paused(env)?;
+    Validation::require_issuer(env, &issuer)?;
+
+    let mut attestation = Storage::get_attestation(env, &attestation_id)?;
+    if attestation.deleted {
+        return Err(Error::NotFound);
+    }
+    if attestation.issuer != issuer || new_subject == issuer {
+        return Err(Error::Unauthorized);
+    }
+    if attestation.revoked {
+        return Err(Error::AlreadyRevoked);
+    }
+    if crate::query::effective_status(env, &attestation, env.ledger().timestamp()) == AttestationStatus::Expired {
+        return Err(Error::Expired);
+    }
+    let old_subject = attestation.subject.clone();
+    if old_subject == new_subject {
+        return Ok(());
+    }
+    if Storage::is_whitelist_mode(env, &issuer) && !Storage::is_whitelisted(env, &issuer, &new_subject) {
+        return Err(Error::SubjectNotWhitelisted);
+    }
+    check_singleton(env, &issuer, &new_subject, &attestation.claim_type)?;
+    check_revocation_cooldown(env, &issuer, &new_subject, &attestation.claim_type)?;
+    let limits = Storage::get_limits(env);
+    if Storage::get_subject_attestations(env, &new_subject).len() >= limits.max_attestations_per_subject {
+        return Err(Error::LimitExceeded);
+    }
+
+    Storage::remove_subject_attestation(env, &old_subject, &attestation_id);
+    Storage::remove_valid_attestation(env, &old_subject, &attestation_id);
+    Storage::remove_subject_claim_type_id(env, &old_subject, &attestation.claim_type, &attestation_id);
+    crate::storage::ChunkedIndex::remove_subject(env, &old_subject, &attestation_id);
+    Storage::add_subject_attestation(env, &new_subject, &attestation_id);
+    Storage::add_valid_attestation(env, &new_subject, &attestation_id);
+    Storage::add_subject_claim_type_id(env, &new_subject, &attestation.claim_type, &attestation_id);
+    crate::storage::ChunkedIndex::add_subject(env, &new_subject, &attestation_id);
+
+    attestation.subject = new_subject.clone();
+    Storage::set_attestation(env, &attestation);
+    Storage::touch_subject(env, &old_subject);
+
+    Storage::append_audit_entry(env, &attestation_id, &AuditEntry {
+        action: AuditAction::Transferred,
+        actor: issuer,
+        timestamp: env.ledger().timestamp(),
+        details: Some(new_subject.to_string()),
+    });
+
+    Events::attestation_subject_transferred(env, &attestation_id, &old_subject, &new_subject);
+    Ok(())
+}
+
+/// Move up to `limit` of `from_issuer`'s attestations to `to_issuer`.
+///
+/// Intended for decommissioning an issuer: call repeatedly until it returns 0.
+/// Each call takes at most 50 ids from the front of the issuer index. Ids
+/// whose record no longer exists are dropped from the index rather than
+/// moved, as [`compact_subject_index`] does; the return value counts them
+/// with the moved ones so the loop still terminates.
+pub fn transfer_all_attestations(
+    env: &Env,
+    admin: Address,
+    from_issuer: Address,
+    to_issuer: Address,
+    limit: u32,
+) -> Result<u32, Error> {
+    const MAX_TRANSFER: u32 = 50;
+
+    admin.require_auth();
+    Validation::require_admin(env, &admin)?;
+    Storage::record_admin_action(env);
+    Validation::require_issuer(env, &to_issuer)?;
+
+    if from_issuer == to_issuer {
+        return Ok(0);
+    }
+
+    let ids = Storage::get_issuer_attestations(env, &from_issuer);
+    let count = ids.len().min(limit).min(MAX_TRANSFER);
+    if count == 0 {
+        return Ok(0);
+    }
+
+    let taken = ids.slice(0..count);
+    let remaining = ids.slice(count..);
+    let timestamp = env.ledger().timestamp();
+    let mut moved: Vec<String> = Vec::new(env);
+    for attestation_id in taken.iter() {
+        let Ok(mut attestation) = Storage::get_attestation(env, &attestation_id) else {
+            continue;
+        };
+        moved.push_back(attestation_id.clone());
+        attestation.issuer = to_issuer.clone();
+        Storage::set_attestation(env, &attestation);
+        Storage::append_audit_entry(env, &attestation_id, &AuditEntry {
+            action: AuditAction::Transferred,
+            actor: admin.clone(),
+            timestamp,
+            details: Some(to_issuer.to_string()),
+        });
+    }
+
+    Storage::set_issuer_attestations(env, &from_issuer, &remaining);
+    Storage::add_issuer_attestations_bulk(env, &to_issuer, &moved);
+    let chunked = crate::storage::ChunkedIndex::get_issuer_all(env, &from_issuer);
+    let mut chunked_remaining = Vec::new(env);
+    for id in chunked.iter() {
+        if !taken.contains(&id) {
+            chunked_remaining.push_back(id);
+        }
+    }
+    crate::storage::ChunkedIndex::set_issuer_all(env, &from_issuer, &chunked_remaining);
+    crate::storage::ChunkedIndex::add_issuer_bulk(env, &to_issuer, &moved);
+
+    let moved_count = moved.len() as u64;
+    let mut old_stats = Storage::get_issuer_stats(env, &from_issuer);
+    old_stats.total_issued = old_stats.total_issued.saturating_sub(moved_count);
+    Storage::set_issuer_stats(env, &from_issuer, &old_stats);
+
+    let mut new_stats = Storage::get_issuer_stats(env, &to_issuer);
+    new_stats.total_issued = new_stats.total_issued.saturating_add(moved_count);
+    Storage::set_issuer_stats(env, &to_issuer, &new_stats);
+
+    Events::bulk_transfer(env, &from_issuer, &to_issuer, moved.len());
+    Ok(count)
+}
+
 pub fn request_deletion(env: &Env, subject: Address, attestation_id: String) -> Result<(), Error> {
     subject.require_auth();
 
@@ -782,10 +2135,15 @@ pub fn request_deletion(env: &Env, subject: Address, attestation_id: String) ->
         return Err(Error::Unauthorized);
     }
 
+    if !attestation.deleted && !attestation.revoked {
+        Storage::decrement_claim_type_count(env, &attestation.claim_type);
+    }
     attestation.deleted = true;
     Storage::set_attestation(env, &attestation);
     Storage::remove_subject_attestation(env, &subject, &attestation_id);
     Storage::remove_valid_attestation(env, &subject, &attestation_id);
+    Storage::remove_subject_claim_type_id(env, &subject, &attestation.claim_type, &attestation_id);
+    Storage::remove_subject_revoked(env, &subject, &attestation_id);
     crate::storage::ChunkedIndex::remove_subject(env, &subject, &attestation_id);
 
     let timestamp = env.ledger().timestamp();
@@ -810,7 +2168,7 @@ pub fn amend_attestation(
     new_metadata: Option<String>,
 ) -> Result<(), Error> {
     issuer.require_auth();
-    Validation::require_not_paused(env)?;
+    Validation::require_create_not_paused(env)?;
     Validation::require_issuer(env, &issuer)?;
     Validation::validate_metadata(env, &new_metadata)?;
 
@@ -857,7 +2215,7 @@ pub fn amend_attestation(
 pub fn endorse_attestation(env: &Env, endorser: Address, attestation_id: String) -> Result<(), Error> {
     endorser.require_auth();
     Validation::require_issuer(env, &endorser)?;
-    Validation::require_not_paused(env)?;
+    Validation::require_create_not_paused(env)?;
 
     let attestation = Storage::get_attestation(env, &attestation_id)?;
     if attestation.issuer == endorser {
@@ -913,12 +2271,13 @@ pub fn create_attestation_as_delegate(
     metadata: Option<String>,
 ) -> Result<String, Error> {
     delegate.require_auth();
-    Validation::require_not_paused(env)?;
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Validation::require_create_not_paused(env)?;
     Validation::require_issuer(env, &delegator)?;
     Validation::validate_claim_type(&claim_type)?;
+    Validation::require_registered_claim_type(env, &claim_type)?;
     Validation::validate_metadata(env, &metadata)?;
     Validation::validate_metadata_hash_only(env, &metadata)?;
-    validate_native_expiration(env, expiration)?;
 
     // Verify delegation exists and is not expired.
     let delegation = Storage::get_delegation(env, &delegator, &delegate, &claim_type)
@@ -932,6 +2291,7 @@ pub fn create_attestation_as_delegate(
     if delegator == subject {
         return Err(Error::Unauthorized);
     }
+    let expiration = check_creation_policy(env, &delegator, &subject, &claim_type, expiration, false)?;
 
     let limits = Storage::get_limits(env);
     if Storage::get_issuer_attestations(env, &delegator).len() >= limits.max_attestations_per_issuer {
@@ -964,6 +2324,17 @@ pub fn create_attestation_as_delegate(
         source_tx: None,
         tags: None,
         revocation_reason: None,
+        pinned: false,
+        expiration_locked: false,
+        superseded_by: None,
+        stake: None,
+        revocable: true,
+        score: None,
+        category: Category::Other,
+        permanent: false,
+        parent_issuer: Storage::get_effective_parent(env, &delegator),
+        depends_on: None,
+        revocation_evidence: None,
     };
 
     store_attestation(env, &attestation);
@@ -977,6 +2348,7 @@ pub fn create_attestation_as_delegate(
             details: None,
         },
     );
+    record_creation(env, &attestation);
     Events::attestation_created(env, &attestation);
     Ok(attestation_id)
 }
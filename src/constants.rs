@@ -14,3 +14,27 @@ pub const DEFAULT_INSTANCE_LIFETIME: u32 = DAY_IN_LEDGERS * DEFAULT_TTL_DAYS;
 
 /// Only extend TTL on read if remaining TTL drops below this threshold.
 pub const MIN_TTL_THRESHOLD: u32 = 7 * DAY_IN_LEDGERS;
+
+/// How long past its expiration an attestation must be before
+/// `sweep_expired` may prune it.
+pub const SWEEP_BUFFER_SECS: u64 = 30 * SECS_PER_DAY;
+
+/// Attestation records `sweep_expired` reads per call, whatever its `limit`.
+pub const MAX_SWEEP_SCAN: u32 = 100;
+
+/// Revocation reason recorded on an attestation replaced by `supersede_attestation`.
+pub const SUPERSEDED_REASON: &str = "Superseded";
+
+/// Issuer registration/removal transitions retained per address for
+/// `was_issuer_at`; older ones are dropped first.
+pub const MAX_ISSUER_HISTORY: u32 = 8;
+
+/// Revoked attestation ids retained per subject for `verify_claim_detailed`
+/// and `get_claim_state`; older ones are dropped first.
+pub const MAX_SUBJECT_REVOKED: u32 = 20;
+
+/// Subscribers retained per (subject, claim type) for revocation notices.
+pub const MAX_REVOCATION_SUBSCRIBERS: u32 = 10;
+
+/// Default maximum number of entries in the claim-type registry.
+pub const DEFAULT_MAX_CLAIM_TYPES: u32 = 200;
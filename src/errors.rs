@@ -55,4 +55,40 @@ pub enum Error {
     LimitExceeded = 29,
     /// The proposal has been cancelled by the proposer.
     ProposalCancelled = 30,
+    /// Revocation evidence URI is empty or exceeds 256 characters.
+    InvalidEvidenceUri = 45,
+    /// Issuer has reached the configured per-ledger attestation cap.
+    IssuerRateLimited = 46,
+    /// The issuer has been paused by an admin.
+    IssuerPaused = 47,
+    /// The referenced parent attestation belongs to a different subject.
+    InvalidDependency = 48,
+    /// An expiration is required: explicit permanence is enforced and the
+    /// attestation was not created as permanent.
+    ExpirationRequired = 49,
+    /// The operation applies only to revoked attestations.
+    NotRevoked = 50,
+    /// The claim-type registry is at its configured maximum size.
+    RegistryFull = 51,
+    /// The issuer's time-limited authorization has lapsed.
+    IssuerExpired = 52,
+    /// The operation is only available on contracts initialized in dev mode.
+    NotPermitted = 53,
+    /// The issuer already holds a valid attestation of this singleton claim
+    /// type for the subject; revoke it before issuing a replacement.
+    SingletonViolation = 54,
+    /// The attestation was created as non-revocable.
+    NotRevocable = 55,
+    /// The declared stake amount is negative.
+    InvalidStake = 56,
+    /// The attestation's expiration was locked at creation.
+    ExpirationLocked = 57,
+    /// The same issuer revoked this subject's claim of this type too recently
+    /// to issue a new one.
+    RevocationCooldown = 58,
+    /// The issuer registry is frozen; issuers cannot be added or removed.
+    RegistryFrozen = 59,
+    /// The operation is timelocked: it was not queued, or its delay has not
+    /// yet elapsed.
+    TimelockNotReady = 60,
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol};
 
 use crate::types::{Attestation, IssuerTier};
 
@@ -15,6 +15,7 @@ const TOPIC_DEL_REQ: Symbol = symbol_short!("del_req");
 const TOPIC_ISS_REG: Symbol = symbol_short!("iss_reg");
 const TOPIC_ISS_TIER: Symbol = symbol_short!("iss_tier");
 const TOPIC_ISS_REM: Symbol = symbol_short!("iss_rem");
+const TOPIC_ISS_REN: Symbol = symbol_short!("iss_ren");
 const TOPIC_CLM_TYPE: Symbol = symbol_short!("clm_type");
 const TOPIC_MS_PROP: Symbol = symbol_short!("ms_prop");
 const TOPIC_MS_SIGN: Symbol = symbol_short!("ms_sign");
@@ -33,6 +34,20 @@ const TOPIC_WL_ON: Symbol = symbol_short!("wl_on");
 const TOPIC_WL_ADD: Symbol = symbol_short!("wl_add");
 const TOPIC_WL_REM: Symbol = symbol_short!("wl_rem");
 const TOPIC_TPL_DEL: Symbol = symbol_short!("tpl_del");
+const TOPIC_VERIFIED: Symbol = symbol_short!("verified");
+const TOPIC_ISS_PAUS: Symbol = symbol_short!("iss_paus");
+const TOPIC_ISS_RESM: Symbol = symbol_short!("iss_resm");
+const TOPIC_SUB_ADD: Symbol = symbol_short!("sub_add");
+const TOPIC_SUB_REM: Symbol = symbol_short!("sub_rem");
+const TOPIC_SUBJ_XFER: Symbol = symbol_short!("subj_xfer");
+const TOPIC_REV_AMEND: Symbol = symbol_short!("rev_amend");
+const TOPIC_CLM_UNREG: Symbol = symbol_short!("clm_unreg");
+const TOPIC_FEE_SETL: Symbol = symbol_short!("fee_setl");
+const TOPIC_REV_ACK: Symbol = symbol_short!("rev_ack");
+const TOPIC_DEV_RESET: Symbol = symbol_short!("dev_reset");
+const TOPIC_KEY_ROT: Symbol = symbol_short!("key_rot");
+const TOPIC_REV_NOTE: Symbol = symbol_short!("rev_note");
+const TOPIC_SWEPT: Symbol = symbol_short!("swept");
 
 pub struct Events;
 
@@ -42,6 +57,14 @@ impl Events {
             .publish((TOPIC_ADM_INIT,), (admin.clone(), timestamp));
     }
 
+    /// Emitted when a dev-mode deployment wipes its issuers and attestations.
+    pub fn dev_reset(env: &Env, admin: &Address, timestamp: u64) {
+        env.events()
+            .publish((TOPIC_DEV_RESET,), (admin.clone(), timestamp));
+    }
+
+    /// The trailing ledger sequence lets indexers order and reconcile
+    /// creations independently of ledger timestamps.
     pub fn attestation_created(env: &Env, attestation: &Attestation) {
         env.events().publish(
             (TOPIC_CREATED, attestation.subject.clone()),
@@ -51,6 +74,7 @@ impl Events {
                 attestation.claim_type.clone(),
                 attestation.timestamp,
                 attestation.metadata.clone(),
+                env.ledger().sequence(),
             ),
         );
     }
@@ -87,22 +111,58 @@ impl Events {
         );
     }
 
-    pub fn attestation_revoked(env: &Env, attestation_id: &String, issuer: &Address, reason: &Option<String>) {
+    /// The trailing claim type and subject make the event self-contained for
+    /// indexers that never saw the creation event.
+    pub fn attestation_revoked(env: &Env, attestation: &Attestation, reason: &Option<String>) {
+        env.events().publish(
+            (TOPIC_REVOKED, attestation.issuer.clone()),
+            (
+                attestation.id.clone(),
+                reason.clone(),
+                attestation.claim_type.clone(),
+                attestation.subject.clone(),
+            ),
+        );
+    }
+
+    /// Emitted after the `revoked` event when an attestation is replaced.
+    pub fn attestation_superseded(env: &Env, old: &Attestation, new_id: &String) {
         env.events().publish(
-            (TOPIC_REVOKED, issuer.clone()),
+            (symbol_short!("supersede"), old.subject.clone()),
+            (old.id.clone(), new_id.clone()),
+        );
+    }
+
+    /// Emitted when an admin rewrites the recorded reason of a revoked attestation.
+    pub fn revocation_reason_amended(env: &Env, attestation_id: &String, admin: &Address, reason: &Option<String>) {
+        env.events().publish(
+            (TOPIC_REV_AMEND, admin.clone()),
             (attestation_id.clone(), reason.clone()),
         );
     }
 
-    pub fn attestation_revoked_with_reason(
+    /// Emitted when a subject acknowledges the revocation of its attestation.
+    pub fn revocation_acknowledged(env: &Env, attestation_id: &String, subject: &Address) {
+        env.events()
+            .publish((TOPIC_REV_ACK, subject.clone()), attestation_id.clone());
+    }
+
+    /// Revocation event carrying the evidence URI alongside the reason.
+    pub fn attestation_revoked_with_evidence(
         env: &Env,
-        attestation_id: &String,
-        issuer: &Address,
+        attestation: &Attestation,
         reason: &Option<String>,
+        evidence_uri: &Option<String>,
     ) {
         env.events().publish(
-            (TOPIC_REVOKED, issuer.clone()),
-            (attestation_id.clone(), reason.clone()),
+            (TOPIC_REVOKED, attestation.issuer.clone()),
+            (
+                attestation.id.clone(),
+                reason.clone(),
+                evidence_uri.clone(),
+                attestation.claim_type.clone(),
+                attestation.subject.clone(),
+            ),
         );
     }
 
@@ -180,6 +240,11 @@ impl Events {
         );
     }
 
+    /// Emitted when an issuer gives up its own registration.
+    pub fn issuer_renounced(env: &Env, issuer: &Address, timestamp: u64) {
+        env.events().publish((TOPIC_ISS_REN, issuer.clone()), timestamp);
+    }
+
     pub fn claim_type_registered(env: &Env, claim_type: &String, description: &String) {
         env.events().publish(
             (TOPIC_CLM_TYPE, claim_type.clone()),
@@ -187,6 +252,17 @@ impl Events {
         );
     }
 
+    /// Emitted when an admin zeroes an issuer's metered balance after off-chain settlement.
+    pub fn issuer_fees_settled(env: &Env, issuer: &Address, admin: &Address, amount: i128) {
+        env.events()
+            .publish((TOPIC_FEE_SETL, issuer.clone()), (admin.clone(), amount));
+    }
+
+    pub fn claim_type_unregistered(env: &Env, claim_type: &String, admin: &Address) {
+        env.events()
+            .publish((TOPIC_CLM_UNREG, claim_type.clone()), admin.clone());
+    }
+
     pub fn multisig_proposed(
         env: &Env,
         proposal_id: &String,
@@ -260,6 +336,41 @@ impl Events {
         );
     }
 
+    /// Emitted when an issuer moves an attestation to a subject's new address.
+    pub fn attestation_subject_transferred(
+        env: &Env,
+        attestation_id: &String,
+        old_subject: &Address,
+        new_subject: &Address,
+    ) {
+        env.events().publish(
+            (TOPIC_SUBJ_XFER, old_subject.clone()),
+            (attestation_id.clone(), new_subject.clone()),
+        );
+    }
+
+    /// Emitted by `verify_and_log` so a relying party's check leaves an on-chain trace.
+    pub fn verification_performed(
+        env: &Env,
+        verifier: &Address,
+        subject: &Address,
+        claim_type: &String,
+        result: bool,
+    ) {
+        env.events().publish(
+            (TOPIC_VERIFIED, verifier.clone()),
+            (subject.clone(), claim_type.clone(), result),
+        );
+    }
+
+    /// Emitted once per `transfer_all_attestations` call summarising the move.
+    pub fn bulk_transfer(env: &Env, from_issuer: &Address, to_issuer: &Address, count: u32) {
+        env.events().publish(
+            (symbol_short!("bulk_xfer"), from_issuer.clone()),
+            (to_issuer.clone(), count),
+        );
+    }
+
     /// Emitted when a registered issuer endorses an existing attestation.
     pub fn attestation_endorsed(
         env: &Env,
@@ -295,6 +406,65 @@ impl Events {
             .publish((symbol_short!("unpaused"),), (admin.clone(), timestamp));
     }
 
+    pub fn issuer_paused(env: &Env, issuer: &Address, admin: &Address, timestamp: u64) {
+        env.events()
+            .publish((TOPIC_ISS_PAUS, issuer.clone()), (admin.clone(), timestamp));
+    }
+
+    pub fn issuer_unpaused(env: &Env, issuer: &Address, admin: &Address, timestamp: u64) {
+        env.events()
+            .publish((TOPIC_ISS_RESM, issuer.clone()), (admin.clone(), timestamp));
+    }
+
+    /// Emitted when an issuer sets or rotates its off-chain signing key.
+    pub fn issuer_key_rotated(env: &Env, issuer: &Address, public_key: &BytesN<32>) {
+        env.events()
+            .publish((TOPIC_KEY_ROT, issuer.clone()), public_key.clone());
+    }
+
+    /// Emitted when an admin freezes (`true`) or unfreezes the issuer registry.
+    pub fn issuer_registry_frozen(env: &Env, admin: &Address, frozen: bool) {
+        env.events()
+            .publish((symbol_short!("reg_frz"), admin.clone()), frozen);
+    }
+
+    /// Emitted when an admin queues a timelocked operation.
+    pub fn operation_queued(env: &Env, admin: &Address, op_hash: &BytesN<32>, ready_at: u64) {
+        env.events()
+            .publish((symbol_short!("op_queue"), admin.clone()), (op_hash.clone(), ready_at));
+    }
+
+    /// Emitted when a queued timelocked operation is executed.
+    pub fn operation_executed(env: &Env, admin: &Address, op_hash: &BytesN<32>) {
+        env.events()
+            .publish((symbol_short!("op_exec"), admin.clone()), op_hash.clone());
+    }
+
+    /// Emitted once per subscriber when a subscribed (subject, claim type)
+    /// attestation is revoked; topic'd on the subscriber so it can filter.
+    pub fn revocation_notice(env: &Env, subscriber: &Address, attestation: &Attestation) {
+        env.events().publish(
+            (TOPIC_REV_NOTE, subscriber.clone()),
+            (attestation.subject.clone(), attestation.claim_type.clone(), attestation.id.clone()),
+        );
+    }
+
+    /// Emitted when `sweep_expired` prunes at least one attestation.
+    pub fn expired_swept(env: &Env, pruned: u32, timestamp: u64) {
+        env.events()
+            .publish((TOPIC_SWEPT,), (pruned, timestamp));
+    }
+
+    pub fn sub_issuer_added(env: &Env, parent: &Address, sub_issuer: &Address, timestamp: u64) {
+        env.events()
+            .publish((TOPIC_SUB_ADD, parent.clone()), (sub_issuer.clone(), timestamp));
+    }
+
+    pub fn sub_issuer_removed(env: &Env, parent: &Address, sub_issuer: &Address, timestamp: u64) {
+        env.events()
+            .publish((TOPIC_SUB_REM, parent.clone()), (sub_issuer.clone(), timestamp));
+    }
+
     /// Emitted when an attestation's issuer is changed by the admin.
     pub fn attestation_transferred(
         env: &Env,
@@ -29,15 +29,15 @@ pub(crate) mod callback {
     }
 }
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String, Vec};
 
 use crate::events::Events;
 use crate::storage::Storage;
 use crate::types::{
-    Attestation, AttestationRequest, AttestationStatus, AuditAction, AuditEntry, ClaimTypeInfo,
-    ContractConfig, ContractMetadata, Endorsement, Error, FeeConfig, GlobalStats, HealthStatus,
-    IssuerMetadata, IssuerStats, IssuerTier, MultiSigProposal, RateLimitConfig, RequestStatus,
-    StorageLimits, TtlConfig, ATTESTATION_REQUEST_TTL_SECS, MULTISIG_PROPOSAL_TTL_SECS,
+    Attestation, AttestationRequest, AttestationStatus, AuditAction, AuditEntry, AutoExtendConfig, BackupAdmin, Category, ClaimQuery, ClaimState, ClaimTypeInfo,
+    ContractConfig, ContractMetadata, Endorsement, Error, ExpiryHistogram, FeeConfig, GlobalStats, HealthStatus,
+    IssuerMetadata, IssuerStats, IssuerTier, MultiSigProposal, Page, PauseFlags, RateLimitConfig, RequestStatus,
+    AttestationTemplate, StorageLimits, TimelockOperation, TtlConfig, VerifyResult, ATTESTATION_REQUEST_TTL_SECS, MULTISIG_PROPOSAL_TTL_SECS,
 };
 
 #[contract]
@@ -53,10 +53,67 @@ impl TrustLinkContract {
         admin::initialize(&env, admin, ttl_days)
     }
 
+    /// Initialize with the default TTL and register up to 50 issuers in the same call.
+    pub fn initialize_with_issuers(env: Env, admin: Address, issuers: Vec<Address>) -> Result<(), Error> {
+        admin::initialize_with_issuers(&env, admin, issuers)
+    }
+
+    /// Initialize a development deployment on which `reset` is permitted.
+    pub fn initialize_dev(env: Env, admin: Address, ttl_days: Option<u32>) -> Result<(), Error> {
+        admin::initialize_dev(&env, admin, ttl_days)
+    }
+
+    /// Initialize with `normalize_claim_types` set: claim types are ASCII-uppercased
+    /// wherever they are accepted, so matching is case-insensitive.
+    pub fn initialize_normalized(env: Env, admin: Address, ttl_days: Option<u32>) -> Result<(), Error> {
+        admin::initialize_normalized(&env, admin, ttl_days)
+    }
+
+    /// Initialize with `admin_overrides_revocable` set: `admin_revoke` may revoke
+    /// attestations created as non-revocable. Fixed for the contract's lifetime.
+    pub fn initialize_admin_override(env: Env, admin: Address, ttl_days: Option<u32>) -> Result<(), Error> {
+        admin::initialize_admin_override(&env, admin, ttl_days)
+    }
+
+    #[must_use]
+    pub fn is_normalize_claim_types(env: Env) -> bool {
+        admin::is_normalize_claim_types(&env)
+    }
+
+    #[must_use]
+    pub fn is_dev_mode(env: Env) -> bool {
+        admin::is_dev_mode(&env)
+    }
+
+    /// Clear all attestations and issuers; only on dev-mode deployments.
+    pub fn reset(env: Env, admin: Address) -> Result<(), Error> {
+        admin::reset(&env, admin)
+    }
+
     pub fn transfer_admin(env: Env, current_admin: Address, new_admin: Address) -> Result<(), Error> {
         admin::transfer_admin(&env, current_admin, new_admin)
     }
 
+    /// Designate a backup admin that may claim control after `inactivity_window`
+    /// seconds with no admin action.
+    pub fn set_backup_admin(env: Env, admin: Address, backup_admin: Address, inactivity_window: u64) -> Result<(), Error> {
+        admin::set_backup_admin(&env, admin, backup_admin, inactivity_window)
+    }
+
+    pub fn clear_backup_admin(env: Env, admin: Address) -> Result<(), Error> {
+        admin::clear_backup_admin(&env, admin)
+    }
+
+    #[must_use]
+    pub fn get_backup_admin(env: Env) -> Option<BackupAdmin> {
+        admin::get_backup_admin(&env)
+    }
+
+    /// Backup admin takes over after the primary has been inactive for the window.
+    pub fn claim_admin(env: Env, backup_admin: Address) -> Result<(), Error> {
+        admin::claim_admin(&env, backup_admin)
+    }
+
     pub fn propose_admin_transfer(env: Env, current_admin: Address, new_admin: Address) -> Result<(), Error> {
         admin::propose_admin_transfer(&env, current_admin, new_admin)
     }
@@ -86,6 +143,20 @@ impl TrustLinkContract {
         admin::get_admin(&env)
     }
 
+    /// The admin, or `None` before initialization. Named `_unchecked` because a
+    /// `try_get_admin` entrypoint would collide with the client's generated
+    /// `try_` wrapper for `get_admin`.
+    #[must_use]
+    pub fn get_admin_unchecked(env: Env) -> Option<Address> {
+        admin::get_admin_unchecked(&env)
+    }
+
+    /// The admin, or `fallback` before initialization.
+    #[must_use]
+    pub fn get_admin_or(env: Env, fallback: Address) -> Address {
+        admin::get_admin_or(&env, fallback)
+    }
+
     #[must_use]
     pub fn get_admin_council(env: Env) -> Result<Vec<Address>, Error> {
         admin::get_admin_council(&env)
@@ -103,6 +174,22 @@ impl TrustLinkContract {
         admin::remove_issuer(&env, admin, issuer)
     }
 
+    /// Issuer gives up its own registration; its attestations stay valid.
+    pub fn renounce_issuer(env: Env, issuer: Address) -> Result<(), Error> {
+        admin::renounce_issuer(&env, issuer)
+    }
+
+    /// Remove up to 50 issuers, skipping unregistered ones; returns how many were removed.
+    pub fn remove_issuers_batch(env: Env, admin: Address, issuers: Vec<Address>) -> Result<u32, Error> {
+        admin::remove_issuers_batch(&env, admin, issuers)
+    }
+
+    /// Number of registered issuers, without returning their addresses.
+    #[must_use]
+    pub fn get_issuer_total(env: Env) -> u32 {
+        admin::get_issuer_total(&env)
+    }
+
     #[must_use]
     pub fn get_issuer_list(env: Env, start: u32, limit: u32) -> Vec<Address> {
         admin::get_issuer_list(&env, start, limit)
@@ -161,11 +248,43 @@ impl TrustLinkContract {
         admin::is_issuer(&env, address)
     }
 
+    /// Check up to 50 addresses at once; results are positional.
+    pub fn are_issuers(env: Env, addresses: Vec<Address>) -> Result<Vec<bool>, Error> {
+        admin::are_issuers(&env, addresses)
+    }
+
+    /// Whether `address` was a registered issuer at timestamp `as_of`.
+    #[must_use]
+    pub fn was_issuer_at(env: Env, address: Address, as_of: u64) -> bool {
+        admin::was_issuer_at(&env, address, as_of)
+    }
+
     #[must_use]
     pub fn get_issuer_tier(env: Env, issuer: Address) -> Option<IssuerTier> {
         admin::get_issuer_tier(&env, issuer)
     }
 
+    /// Meter `fee` against the issuer for each new attestation of `claim_type`.
+    pub fn set_claim_type_fee(env: Env, admin: Address, claim_type: String, fee: i128) -> Result<(), Error> {
+        admin::set_claim_type_fee(&env, admin, claim_type, fee)
+    }
+
+    #[must_use]
+    pub fn get_claim_type_fee(env: Env, claim_type: String) -> i128 {
+        admin::get_claim_type_fee(&env, claim_type)
+    }
+
+    /// Metered fees the issuer owes and has not yet settled.
+    #[must_use]
+    pub fn get_issuer_owed(env: Env, issuer: Address) -> i128 {
+        admin::get_issuer_owed(&env, issuer)
+    }
+
+    /// Zero the issuer's owed balance after off-chain settlement; returns the amount cleared.
+    pub fn settle_issuer_fees(env: Env, admin: Address, issuer: Address) -> Result<i128, Error> {
+        admin::settle_issuer_fees(&env, admin, issuer)
+    }
+
     // -----------------------------------------------------------------------
     // Bridge management
     // -----------------------------------------------------------------------
@@ -216,6 +335,54 @@ impl TrustLinkContract {
         admin::get_rate_limit(&env)
     }
 
+    /// Cap how many attestations a single issuer may create within one ledger (0 disables).
+    pub fn set_max_per_ledger(env: Env, admin: Address, max_per_ledger: u32) -> Result<(), Error> {
+        admin::set_max_per_ledger(&env, admin, max_per_ledger)
+    }
+
+    /// Get the per-issuer per-ledger creation cap, or None if not set.
+    #[must_use]
+    pub fn get_max_per_ledger(env: Env) -> Option<u32> {
+        admin::get_max_per_ledger(&env)
+    }
+
+    /// Anchor expirations for `claim_type` to the subject's first attestation of that
+    /// type, `window_secs` later. `None` disables cohort mode.
+    pub fn set_cohort_expiration(
+        env: Env,
+        admin: Address,
+        claim_type: String,
+        window_secs: Option<u64>,
+    ) -> Result<(), Error> {
+        admin::set_cohort_expiration(&env, admin, claim_type, window_secs)
+    }
+
+    /// Get the cohort validity window for a claim type, or None if not set.
+    #[must_use]
+    pub fn get_cohort_expiration(env: Env, claim_type: String) -> Option<u64> {
+        admin::get_cohort_expiration(&env, claim_type)
+    }
+
+    /// Configure auto-extension on verification for `claim_type`; `None` disables it.
+    pub fn set_auto_extend(
+        env: Env,
+        admin: Address,
+        claim_type: String,
+        config: Option<AutoExtendConfig>,
+    ) -> Result<(), Error> {
+        admin::set_auto_extend(&env, admin, claim_type, config)
+    }
+
+    #[must_use]
+    pub fn get_auto_extend(env: Env, claim_type: String) -> Option<AutoExtendConfig> {
+        admin::get_auto_extend(&env, claim_type)
+    }
+
+    /// Let auto-extension apply to the caller's attestations of `claim_type`.
+    pub fn set_auto_extend_consent(env: Env, issuer: Address, claim_type: String, allowed: bool) -> Result<(), Error> {
+        admin::set_auto_extend_consent(&env, issuer, claim_type, allowed)
+    }
+
     /// Set a per-claim-type rate limit override.
     ///
     /// When set, this overrides the global rate limit for the specified claim type.
@@ -252,6 +419,111 @@ impl TrustLinkContract {
         admin::is_paused(&env)
     }
 
+    /// Pause or resume creation, revocation and issuer registration independently.
+    pub fn set_pause_flags(env: Env, admin: Address, flags: PauseFlags) -> Result<(), Error> {
+        admin::set_pause_flags(&env, admin, flags)
+    }
+
+    #[must_use]
+    pub fn get_pause_flags(env: Env) -> PauseFlags {
+        admin::get_pause_flags(&env)
+    }
+
+    /// Block issuer and sub-issuer registration and removal until unfrozen; attestations are unaffected.
+    pub fn freeze_issuer_registry(env: Env, admin: Address) -> Result<(), Error> {
+        admin::freeze_issuer_registry(&env, admin)
+    }
+
+    pub fn unfreeze_issuer_registry(env: Env, admin: Address) -> Result<(), Error> {
+        admin::unfreeze_issuer_registry(&env, admin)
+    }
+
+    #[must_use]
+    pub fn is_issuer_registry_frozen(env: Env) -> bool {
+        admin::is_issuer_registry_frozen(&env)
+    }
+
+    /// Require destructive admin operations to be queued for `seconds` first. Only increases
+    /// apply directly; lowering or disabling must be queued as `TimelockOperation::SetTimelock`.
+    pub fn set_operation_timelock(env: Env, admin: Address, seconds: u64) -> Result<(), Error> {
+        admin::set_operation_timelock(&env, admin, seconds)
+    }
+
+    #[must_use]
+    pub fn get_operation_timelock(env: Env) -> u64 {
+        admin::get_operation_timelock(&env)
+    }
+
+    /// The hash to pass to `queue_operation` for `operation`.
+    #[must_use]
+    pub fn hash_operation(env: Env, operation: TimelockOperation) -> BytesN<32> {
+        admin::hash_operation(&env, operation)
+    }
+
+    /// Queue a timelocked operation by hash; returns the time it becomes executable.
+    pub fn queue_operation(env: Env, admin: Address, op_hash: BytesN<32>) -> Result<u64, Error> {
+        admin::queue_operation(&env, admin, op_hash)
+    }
+
+    /// Ready time of a queued operation, if queued.
+    #[must_use]
+    pub fn get_queued_operation(env: Env, op_hash: BytesN<32>) -> Option<u64> {
+        admin::get_queued_operation(&env, op_hash)
+    }
+
+    /// Execute a queued operation once its delay has elapsed.
+    pub fn execute_operation(env: Env, admin: Address, operation: TimelockOperation) -> Result<(), Error> {
+        admin::execute_operation(&env, admin, operation)
+    }
+
+    /// Pause a single issuer; other issuers are unaffected.
+    pub fn pause_issuer(env: Env, admin: Address, issuer: Address) -> Result<(), Error> {
+        admin::pause_issuer(&env, admin, issuer)
+    }
+
+    pub fn unpause_issuer(env: Env, admin: Address, issuer: Address) -> Result<(), Error> {
+        admin::unpause_issuer(&env, admin, issuer)
+    }
+
+    #[must_use]
+    pub fn is_issuer_paused(env: Env, issuer: Address) -> bool {
+        admin::is_issuer_paused(&env, issuer)
+    }
+
+    /// Time-limit an issuer's authorization; `None` clears the limit.
+    pub fn set_issuer_expiry(env: Env, admin: Address, issuer: Address, expires_at: Option<u64>) -> Result<(), Error> {
+        admin::set_issuer_expiry(&env, admin, issuer, expires_at)
+    }
+
+    #[must_use]
+    pub fn get_issuer_expiry(env: Env, issuer: Address) -> Option<u64> {
+        admin::get_issuer_expiry(&env, issuer)
+    }
+
+    /// Assign the trust weight an issuer contributes to weighted claim checks.
+    pub fn set_issuer_weight(env: Env, admin: Address, issuer: Address, weight: u32) -> Result<(), Error> {
+        admin::set_issuer_weight(&env, admin, issuer, weight)
+    }
+
+    #[must_use]
+    pub fn get_issuer_weight(env: Env, issuer: Address) -> u32 {
+        admin::get_issuer_weight(&env, issuer)
+    }
+
+    /// Let `sub_issuer` mint on behalf of `parent_issuer` while the parent stays registered.
+    pub fn add_sub_issuer(env: Env, parent_issuer: Address, sub_issuer: Address) -> Result<(), Error> {
+        admin::add_sub_issuer(&env, parent_issuer, sub_issuer)
+    }
+
+    pub fn remove_sub_issuer(env: Env, parent_issuer: Address, sub_issuer: Address) -> Result<(), Error> {
+        admin::remove_sub_issuer(&env, parent_issuer, sub_issuer)
+    }
+
+    #[must_use]
+    pub fn get_sub_issuer_parent(env: Env, sub_issuer: Address) -> Option<Address> {
+        admin::get_sub_issuer_parent(&env, sub_issuer)
+    }
+
     // -----------------------------------------------------------------------
     // Contract Config
     // -----------------------------------------------------------------------
@@ -279,6 +551,37 @@ impl TrustLinkContract {
         admin::get_metadata_hash_only(&env)
     }
 
+    /// Enable or disable `idempotent_revoke` mode.
+    ///
+    /// When enabled, revoking an attestation the same issuer already revoked
+    /// returns `Ok(())` without emitting another event, so clients can retry
+    /// revocations safely. Disabled by default.
+    pub fn set_idempotent_revoke(env: Env, admin: Address, enabled: bool) -> Result<(), Error> {
+        admin::set_idempotent_revoke(&env, admin, enabled)
+    }
+
+    #[must_use]
+    pub fn get_idempotent_revoke(env: Env) -> bool {
+        admin::get_idempotent_revoke(&env)
+    }
+
+    /// When enabled, `expiration: None` is rejected with `ExpirationRequired`
+    /// unless the attestation is created via `create_permanent_attestation`.
+    pub fn set_enforce_explicit_permanence(env: Env, admin: Address, enabled: bool) -> Result<(), Error> {
+        admin::set_enforce_explicit_permanence(&env, admin, enabled)
+    }
+
+    #[must_use]
+    pub fn get_enforce_explicit_permanence(env: Env) -> bool {
+        admin::get_enforce_explicit_permanence(&env)
+    }
+
+    /// Whether this deployment was initialized with `initialize_admin_override`.
+    #[must_use]
+    pub fn get_admin_overrides_revocable(env: Env) -> bool {
+        admin::get_admin_overrides_revocable(&env)
+    }
+
     // -----------------------------------------------------------------------
     // Limits
     // -----------------------------------------------------------------------
@@ -300,6 +603,51 @@ impl TrustLinkContract {
         admin::register_claim_type(&env, admin, claim_type, description)
     }
 
+    /// Update the human-readable description of a registered claim type.
+    pub fn set_claim_type_description(
+        env: Env,
+        admin: Address,
+        claim_type: String,
+        description: String,
+    ) -> Result<(), Error> {
+        admin::set_claim_type_description(&env, admin, claim_type, description)
+    }
+
+    /// Remove a claim type from the registry; existing attestations are unaffected.
+    pub fn unregister_claim_type(env: Env, admin: Address, claim_type: String) -> Result<(), Error> {
+        admin::unregister_claim_type(&env, admin, claim_type)
+    }
+
+    /// Limit `claim_type` to one valid attestation per issuer and subject.
+    pub fn set_singleton_claim_type(env: Env, admin: Address, claim_type: String, singleton: bool) -> Result<(), Error> {
+        admin::set_singleton_claim_type(&env, admin, claim_type, singleton)
+    }
+
+    #[must_use]
+    pub fn is_singleton_claim_type(env: Env, claim_type: String) -> bool {
+        admin::is_singleton_claim_type(&env, claim_type)
+    }
+
+    /// Delay re-issuing `claim_type` to a subject after its issuer revoked the previous one.
+    pub fn set_revocation_cooldown(env: Env, admin: Address, claim_type: String, seconds: u64) -> Result<(), Error> {
+        admin::set_revocation_cooldown(&env, admin, claim_type, seconds)
+    }
+
+    #[must_use]
+    pub fn get_revocation_cooldown(env: Env, claim_type: String) -> u64 {
+        admin::get_revocation_cooldown(&env, claim_type)
+    }
+
+    /// Cap the number of registered claim types (default 200).
+    pub fn set_max_claim_types(env: Env, admin: Address, max: u32) -> Result<(), Error> {
+        admin::set_max_claim_types(&env, admin, max)
+    }
+
+    #[must_use]
+    pub fn get_max_claim_types(env: Env) -> u32 {
+        admin::get_max_claim_types(&env)
+    }
+
     #[must_use]
     pub fn get_claim_type_description(env: Env, claim_type: String) -> Option<String> {
         admin::get_claim_type_description(&env, claim_type)
@@ -362,6 +710,21 @@ impl TrustLinkContract {
         admin::remove_expiration_hook(&env, subject)
     }
 
+    /// Receive a `rev_note` event whenever `subject`'s `claim_type` attestation is
+    /// revoked. Requires the subject's authorization as well as the subscriber's.
+    pub fn subscribe_revocation(env: Env, subscriber: Address, subject: Address, claim_type: String) -> Result<(), Error> {
+        admin::subscribe_revocation(&env, subscriber, subject, claim_type)
+    }
+
+    pub fn unsubscribe_revocation(env: Env, subscriber: Address, subject: Address, claim_type: String) {
+        admin::unsubscribe_revocation(&env, subscriber, subject, claim_type)
+    }
+
+    #[must_use]
+    pub fn get_revocation_subscribers(env: Env, subject: Address, claim_type: String) -> Vec<Address> {
+        admin::get_revocation_subscribers(&env, subject, claim_type)
+    }
+
     // -----------------------------------------------------------------------
     // Attestation creation
     // -----------------------------------------------------------------------
@@ -378,6 +741,144 @@ impl TrustLinkContract {
         attestation::create_attestation(&env, issuer, subject, claim_type, expiration, metadata, tags)
     }
 
+    /// Create an attestation with a coarse [`Category`] for standardized filtering.
+    pub fn create_attestation_with_category(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        category: Category,
+        expiration: Option<u64>,
+        metadata: Option<String>,
+        tags: Option<Vec<String>>,
+    ) -> Result<String, Error> {
+        attestation::create_attestation_with_category(&env, issuer, subject, claim_type, category, expiration, metadata, tags)
+    }
+
+    /// Create an attestation from the issuer's ed25519 signature; anyone may relay it.
+    pub fn submit_signed_attestation(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        expiration: Option<u64>,
+        signature: BytesN<64>,
+    ) -> Result<String, Error> {
+        attestation::submit_signed_attestation(&env, issuer, subject, claim_type, expiration, signature)
+    }
+
+    /// Bytes the issuer must sign for the next `submit_signed_attestation`.
+    #[must_use]
+    pub fn signed_attestation_payload(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        expiration: Option<u64>,
+    ) -> Bytes {
+        attestation::signed_attestation_payload(&env, &issuer, &subject, &claim_type, expiration)
+    }
+
+    /// Set or rotate the issuer's off-chain signing key.
+    pub fn rotate_issuer_key(env: Env, issuer: Address, new_pubkey: BytesN<32>) -> Result<(), Error> {
+        admin::rotate_issuer_key(&env, issuer, new_pubkey)
+    }
+
+    #[must_use]
+    pub fn get_issuer_key(env: Env, issuer: Address) -> Option<BytesN<32>> {
+        admin::get_issuer_key(&env, issuer)
+    }
+
+    /// Create an attestation with `revocable = false`; `revoke_attestation` rejects it.
+    pub fn create_irrevocable_attestation(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        expiration: Option<u64>,
+        metadata: Option<String>,
+        tags: Option<Vec<String>>,
+    ) -> Result<String, Error> {
+        attestation::create_irrevocable_attestation(&env, issuer, subject, claim_type, expiration, metadata, tags)
+    }
+
+    /// Create an attestation expiring `valid_for_seconds` after the ledger's current time.
+    pub fn create_attestation_relative(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        valid_for_seconds: Option<u64>,
+    ) -> Result<String, Error> {
+        attestation::create_attestation_relative(&env, issuer, subject, claim_type, valid_for_seconds)
+    }
+
+    /// Create an attestation recording the collateral the issuer stakes behind it.
+    pub fn create_staked_attestation(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        stake: i128,
+        expiration: Option<u64>,
+        metadata: Option<String>,
+        tags: Option<Vec<String>>,
+    ) -> Result<String, Error> {
+        attestation::create_staked_attestation(&env, issuer, subject, claim_type, stake, expiration, metadata, tags)
+    }
+
+    /// Create an attestation with `expiration_locked = true`; its validity window is fixed for good.
+    pub fn create_locked_attestation(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        expiration: Option<u64>,
+        metadata: Option<String>,
+        tags: Option<Vec<String>>,
+    ) -> Result<String, Error> {
+        attestation::create_locked_attestation(&env, issuer, subject, claim_type, expiration, metadata, tags)
+    }
+
+    /// Create an attestation carrying a numeric score (e.g. a reputation rating).
+    pub fn create_attestation_with_score(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        score: i128,
+        expiration: Option<u64>,
+        metadata: Option<String>,
+        tags: Option<Vec<String>>,
+    ) -> Result<String, Error> {
+        attestation::create_attestation_with_score(&env, issuer, subject, claim_type, score, expiration, metadata, tags)
+    }
+
+    /// Create a deliberately non-expiring attestation (`permanent = true`).
+    pub fn create_permanent_attestation(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        metadata: Option<String>,
+        tags: Option<Vec<String>>,
+    ) -> Result<String, Error> {
+        attestation::create_permanent_attestation(&env, issuer, subject, claim_type, metadata, tags)
+    }
+
+    /// Create an attestation linked to a parent attestation of the same subject.
+    pub fn create_dependent_attestation(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        depends_on: String,
+        expiration: Option<u64>,
+        metadata: Option<String>,
+    ) -> Result<String, Error> {
+        attestation::create_dependent_attestation(&env, issuer, subject, claim_type, depends_on, expiration, metadata)
+    }
+
     pub fn create_attestation_valid_from(
         env: Env,
         issuer: Address,
@@ -391,58 +892,140 @@ impl TrustLinkContract {
         attestation::create_attestation_valid_from(&env, issuer, subject, claim_type, expiration, metadata, tags, valid_from)
     }
 
-    pub fn create_attestation_jurisdiction(
+    pub fn create_attestation_jurisdiction(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        expiration: Option<u64>,
+        metadata: Option<String>,
+        jurisdiction: Option<String>,
+        tags: Option<Vec<String>>,
+    ) -> Result<String, Error> {
+        attestation::create_attestation_jurisdiction(&env, issuer, subject, claim_type, expiration, metadata, jurisdiction, tags)
+    }
+
+    pub fn import_attestation(
+        env: Env,
+        admin: Address,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        timestamp: u64,
+        expiration: Option<u64>,
+    ) -> Result<String, Error> {
+        attestation::import_attestation(&env, admin, issuer, subject, claim_type, timestamp, expiration)
+    }
+
+    pub fn bridge_attestation(
+        env: Env,
+        bridge: Address,
+        subject: Address,
+        claim_type: String,
+        source_chain: String,
+        source_tx: String,
+    ) -> Result<String, Error> {
+        attestation::bridge_attestation(&env, bridge, subject, claim_type, source_chain, source_tx)
+    }
+
+    /// Pre-flight check: the error `create_attestation` would return, or `Ok(())`.
+    /// Writes nothing and does not require auth.
+    pub fn can_create_attestation(
+        env: Env,
+        issuer: Address,
+        subject: Address,
+        claim_type: String,
+        expiration: Option<u64>,
+    ) -> Result<(), Error> {
+        attestation::can_create_attestation(&env, issuer, subject, claim_type, expiration)
+    }
+
+    pub fn create_attestations_batch(
+        env: Env,
+        issuer: Address,
+        subjects: Vec<Address>,
+        claim_type: String,
+        expiration: Option<u64>,
+    ) -> Result<Vec<String>, Error> {
+        attestation::create_attestations_batch(&env, issuer, subjects, claim_type, expiration)
+    }
+
+    /// Subject confirms awareness that its attestation was revoked.
+    pub fn acknowledge_revocation(env: Env, subject: Address, attestation_id: String) -> Result<(), Error> {
+        attestation::acknowledge_revocation(&env, subject, attestation_id)
+    }
+
+    #[must_use]
+    pub fn is_revocation_acknowledged(env: Env, attestation_id: String) -> bool {
+        attestation::is_revocation_acknowledged(&env, attestation_id)
+    }
+
+    /// Revoke up to `limit` of a subject's attestations of `claim_type` from any issuer; returns the count.
+    pub fn admin_revoke_subject_claim_type(
+        env: Env,
+        admin: Address,
+        subject: Address,
+        claim_type: String,
+        limit: u32,
+    ) -> Result<u32, Error> {
+        attestation::admin_revoke_subject_claim_type(&env, admin, subject, claim_type, limit)
+    }
+
+    /// Admin revocation; fails with `AlreadyRevoked` on a revoked attestation.
+    pub fn admin_revoke(env: Env, admin: Address, attestation_id: String, reason: Option<String>) -> Result<(), Error> {
+        attestation::admin_revoke(&env, admin, attestation_id, reason)
+    }
+
+    /// Admin rewrites the recorded reason of a revoked attestation.
+    pub fn amend_revocation_reason(
+        env: Env,
+        admin: Address,
+        attestation_id: String,
+        reason: Option<String>,
+    ) -> Result<(), Error> {
+        attestation::amend_revocation_reason(&env, admin, attestation_id, reason)
+    }
+
+    pub fn revoke_attestation(env: Env, issuer: Address, attestation_id: String, reason: Option<String>) -> Result<(), Error> {
+        attestation::revoke_attestation(&env, issuer, attestation_id, reason)
+    }
+
+    /// Revoke an attestation as superseded and issue its replacement; returns the new id.
+    pub fn supersede_attestation(
         env: Env,
         issuer: Address,
-        subject: Address,
-        claim_type: String,
+        old_id: String,
         expiration: Option<u64>,
         metadata: Option<String>,
-        jurisdiction: Option<String>,
-        tags: Option<Vec<String>>,
     ) -> Result<String, Error> {
-        attestation::create_attestation_jurisdiction(&env, issuer, subject, claim_type, expiration, metadata, jurisdiction, tags)
+        attestation::supersede_attestation(&env, issuer, old_id, expiration, metadata)
     }
 
-    pub fn import_attestation(
+    /// Revoke an attestation identified by `(issuer, subject, claim_type, timestamp)` instead of its id.
+    pub fn revoke_by_content(
         env: Env,
-        admin: Address,
         issuer: Address,
         subject: Address,
         claim_type: String,
         timestamp: u64,
-        expiration: Option<u64>,
-    ) -> Result<String, Error> {
-        attestation::import_attestation(&env, admin, issuer, subject, claim_type, timestamp, expiration)
-    }
-
-    pub fn bridge_attestation(
-        env: Env,
-        bridge: Address,
-        subject: Address,
-        claim_type: String,
-        source_chain: String,
-        source_tx: String,
-    ) -> Result<String, Error> {
-        attestation::bridge_attestation(&env, bridge, subject, claim_type, source_chain, source_tx)
+    ) -> Result<(), Error> {
+        attestation::revoke_by_content(&env, issuer, subject, claim_type, timestamp)
     }
 
-    pub fn create_attestations_batch(
+    /// Revoke an attestation, attaching a URI that references the justification documents.
+    pub fn revoke_attestation_with_evidence(
         env: Env,
         issuer: Address,
-        subjects: Vec<Address>,
-        claim_type: String,
-        expiration: Option<u64>,
-    ) -> Result<Vec<String>, Error> {
-        attestation::create_attestations_batch(&env, issuer, subjects, claim_type, expiration)
-    }
-
-    pub fn revoke_attestation(env: Env, issuer: Address, attestation_id: String, reason: Option<String>) -> Result<(), Error> {
-        attestation::revoke_attestation(&env, issuer, attestation_id, reason)
+        attestation_id: String,
+        reason: Option<String>,
+        evidence_uri: Option<String>,
+    ) -> Result<(), Error> {
+        attestation::revoke_attestation_with_evidence(&env, issuer, attestation_id, reason, evidence_uri)
     }
 
-    pub fn renew_attestation(env: Env, issuer: Address, attestation_id: String, new_expiration: Option<u64>) -> Result<(), Error> {
-        attestation::renew_attestation(&env, issuer, attestation_id, new_expiration)
+    /// Extend an attestation's expiration by `extend_seconds` (from now if it has none).
+    pub fn renew_attestation(env: Env, issuer: Address, attestation_id: String, extend_seconds: u64) -> Result<(), Error> {
+        attestation::renew_attestation(&env, issuer, attestation_id, extend_seconds)
     }
 
     pub fn revoke_attestations_batch(env: Env, issuer: Address, attestation_ids: Vec<String>, reason: Option<String>) -> Result<u32, Error> {
@@ -457,6 +1040,61 @@ impl TrustLinkContract {
         attestation::transfer_attestation(&env, admin, attestation_id, new_issuer)
     }
 
+    /// Remove ids with no attestation record from the subject's indexes; returns the count removed.
+    pub fn compact_subject_index(env: Env, caller: Address, subject: Address) -> Result<u32, Error> {
+        attestation::compact_subject_index(&env, caller, subject)
+    }
+
+    /// Admin recovery: rebuild the subject's indexes from the candidates whose records
+    /// belong to it and are live; returns the rebuilt index size.
+    pub fn rebuild_subject_index(env: Env, admin: Address, subject: Address, candidate_ids: Vec<String>) -> Result<u32, Error> {
+        attestation::rebuild_subject_index(&env, admin, subject, candidate_ids)
+    }
+
+    /// Admin: rewrite attestations stored before the current layout; returns the count rewritten.
+    pub fn migrate(env: Env, admin: Address, attestation_ids: Vec<String>) -> Result<u32, Error> {
+        attestation::migrate_attestations(&env, admin, attestation_ids)
+    }
+
+    /// Subject-only: exempt one of their attestations from pruning and max out its TTL.
+    pub fn pin_attestation(env: Env, subject: Address, attestation_id: String) -> Result<(), Error> {
+        attestation::pin_attestation(&env, subject, attestation_id)
+    }
+
+    pub fn unpin_attestation(env: Env, subject: Address, attestation_id: String) -> Result<(), Error> {
+        attestation::unpin_attestation(&env, subject, attestation_id)
+    }
+
+    /// Prune up to `limit` long-expired attestations; callable by anyone. Returns the count pruned.
+    pub fn sweep_expired(env: Env, limit: u32) -> u32 {
+        attestation::sweep_expired(&env, limit)
+    }
+
+    /// Issuer moves a live attestation to the subject's new address; set
+    /// `require_consent` to also require the new subject's authorization.
+    pub fn transfer_to_subject(
+        env: Env,
+        issuer: Address,
+        attestation_id: String,
+        new_subject: Address,
+        require_consent: bool,
+    ) -> Result<(), Error> {
+        attestation::transfer_to_subject(&env, issuer, attestation_id, new_subject, require_consent)
+    }
+
+    /// Move up to `limit` (max 50) of `from_issuer`'s attestations to `to_issuer`.
+    /// Returns the number of ids processed, counting dangling ids that were dropped
+    /// instead of moved; call until it returns 0 to move everything.
+    pub fn transfer_all_attestations(
+        env: Env,
+        admin: Address,
+        from_issuer: Address,
+        to_issuer: Address,
+        limit: u32,
+    ) -> Result<u32, Error> {
+        attestation::transfer_all_attestations(&env, admin, from_issuer, to_issuer, limit)
+    }
+
     pub fn request_deletion(env: Env, subject: Address, attestation_id: String) -> Result<(), Error> {
         attestation::request_deletion(&env, subject, attestation_id)
     }
@@ -500,6 +1138,97 @@ impl TrustLinkContract {
         query::has_valid_claim(&env, subject, claim_type)
     }
 
+    /// Like `has_valid_claim`, but only counts attestations issued within the last `max_age_seconds`.
+    #[must_use]
+    pub fn has_fresh_valid_claim(env: Env, subject: Address, claim_type: String, max_age_seconds: u64) -> bool {
+        query::has_fresh_valid_claim(&env, subject, claim_type, max_age_seconds)
+    }
+
+    /// Whether the subject held a valid `claim_type` at timestamp `as_of`.
+    #[must_use]
+    pub fn has_valid_claim_as_of(env: Env, subject: Address, claim_type: String, as_of: u64) -> bool {
+        query::has_valid_claim_as_of(&env, subject, claim_type, as_of)
+    }
+
+    /// Valid at `as_of` and issued while the issuer was registered.
+    #[must_use]
+    pub fn verify_claim_historical(env: Env, subject: Address, claim_type: String, as_of: u64) -> bool {
+        query::verify_claim_historical(&env, subject, claim_type, as_of)
+    }
+
+    /// Like `has_valid_claim`, but also requires every parent in the `depends_on` chain to be valid.
+    #[must_use]
+    pub fn has_valid_claim_with_deps(env: Env, subject: Address, claim_type: String) -> bool {
+        query::has_valid_claim_with_deps(&env, subject, claim_type)
+    }
+
+    /// Evaluate an AND/OR claim policy against the subject's valid claims.
+    #[must_use]
+    pub fn evaluate_claim_query(env: Env, subject: Address, query: ClaimQuery) -> bool {
+        query::evaluate_claim_query(&env, subject, query)
+    }
+
+    /// True when a valid attestation of `claim_type` has a score within `[min, max]`.
+    #[must_use]
+    pub fn has_valid_claim_in_range(env: Env, subject: Address, claim_type: String, min: i128, max: i128) -> bool {
+        query::has_valid_claim_in_range(&env, subject, claim_type, min, max)
+    }
+
+    /// Verifier-signed claim check that may auto-extend a claim inside its grace window,
+    /// a bounded number of times and only while its issuer is active.
+    pub fn has_valid_claim_authed(env: Env, verifier: Address, subject: Address, claim_type: String) -> Result<bool, Error> {
+        query::has_valid_claim_authed(&env, verifier, subject, claim_type)
+    }
+
+    /// Verify a claim and emit a `verified` event recording the verifier and result.
+    pub fn verify_and_log(env: Env, verifier: Address, subject: Address, claim_type: String) -> bool {
+        query::verify_and_log(&env, verifier, subject, claim_type)
+    }
+
+    /// Coarse pre-filter: does the subject hold any valid attestation at all?
+    #[must_use]
+    pub fn has_any_valid_attestation(env: Env, subject: Address) -> bool {
+        query::has_any_valid_attestation(&env, subject)
+    }
+
+    /// Like `has_valid_claim`, but ignores attestations from issuers that are no longer registered.
+    #[must_use]
+    pub fn has_valid_claim_strict(env: Env, subject: Address, claim_type: String) -> bool {
+        query::has_valid_claim_strict(&env, subject, claim_type)
+    }
+
+    /// True when the weights of distinct issuers attesting `claim_type` sum to at least `min_weight`.
+    #[must_use]
+    pub fn has_weighted_claim(env: Env, subject: Address, claim_type: String, min_weight: u32) -> bool {
+        query::has_weighted_claim(&env, subject, claim_type, min_weight)
+    }
+
+    /// Best state of the subject's `claim_type` attestations: active, expired, revoked or none.
+    /// Only the subject's most recent revocations are reported as revoked.
+    #[must_use]
+    pub fn get_claim_state(env: Env, subject: Address, claim_type: String) -> ClaimState {
+        query::get_claim_state(&env, subject, claim_type)
+    }
+
+    /// Like `has_valid_claim`, but says why a check failed (expired, revoked, frozen, none).
+    /// Only the subject's most recent revocations are reported as revoked.
+    #[must_use]
+    pub fn verify_claim_detailed(env: Env, subject: Address, claim_type: String) -> VerifyResult {
+        query::verify_claim_detailed(&env, subject, claim_type)
+    }
+
+    /// True only when both subjects hold a valid attestation of `claim_type`.
+    #[must_use]
+    pub fn both_have_valid_claim(env: Env, subject_a: Address, subject_b: Address, claim_type: String) -> bool {
+        query::both_have_valid_claim(&env, subject_a, subject_b, claim_type)
+    }
+
+    /// True when any issuer in `trusted_issuers` holds a valid `claim_type` attestation for the subject.
+    #[must_use]
+    pub fn has_valid_claim_from_any(env: Env, subject: Address, claim_type: String, trusted_issuers: Vec<Address>) -> bool {
+        query::has_valid_claim_from_any(&env, subject, claim_type, trusted_issuers)
+    }
+
     pub fn has_valid_claim_from_issuer(env: Env, subject: Address, claim_type: String, issuer: Address) -> bool {
         query::has_valid_claim_from_issuer(&env, subject, claim_type, issuer)
     }
@@ -509,36 +1238,148 @@ impl TrustLinkContract {
         query::has_any_claim(&env, subject, claim_types)
     }
 
+    /// True when at least `n` distinct types from `claim_types` have a valid attestation.
+    #[must_use]
+    pub fn has_n_of_claims(env: Env, subject: Address, claim_types: Vec<String>, n: u32) -> bool {
+        query::has_n_of_claims(&env, subject, claim_types, n)
+    }
+
     #[must_use]
     pub fn has_all_claims(env: Env, subject: Address, claim_types: Vec<String>) -> bool {
         query::has_all_claims(&env, subject, claim_types)
     }
 
+    /// Bit `i` of the result is set when the subject holds a valid `claim_types[i]`.
+    pub fn claims_bitmap(env: Env, subject: Address, claim_types: Vec<String>) -> Result<u64, Error> {
+        query::claims_bitmap(&env, subject, claim_types)
+    }
+
     #[must_use]
     pub fn has_valid_claim_batch(env: Env, subjects: Vec<Address>, claim_type: String) -> Vec<bool> {
         query::has_valid_claim_batch(&env, subjects, claim_type)
     }
 
+    /// Check up to 50 heterogeneous `(subject, claim_type)` pairs; results are positional.
+    pub fn check_claims(env: Env, queries: Vec<(Address, String)>) -> Result<Vec<bool>, Error> {
+        query::check_claims(&env, queries)
+    }
+
     #[must_use]
     pub fn get_attestation(env: Env, attestation_id: String) -> Result<Attestation, Error> {
         query::get_attestation(&env, attestation_id)
     }
 
+    /// Return just the `(issuer, subject)` pair of an attestation.
+    #[must_use]
+    pub fn get_attestation_parties(env: Env, attestation_id: String) -> Result<(Address, Address), Error> {
+        query::get_attestation_parties(&env, attestation_id)
+    }
+
+    /// `(issuer, subject, claim_type, timestamp, expiration, revoked)` as a plain tuple for thin clients.
+    #[must_use]
+    pub fn get_attestation_flat(
+        env: Env,
+        attestation_id: String,
+    ) -> Result<(Address, Address, String, u64, Option<u64>, bool), Error> {
+        query::get_attestation_flat(&env, attestation_id)
+    }
+
+    /// Return just the issuer of an attestation.
+    #[must_use]
+    pub fn get_attestation_issuer(env: Env, attestation_id: String) -> Result<Address, Error> {
+        query::get_attestation_issuer(&env, attestation_id)
+    }
+
+    /// Attestation plus whether its issuer is still authorized, for historical audits.
+    /// (`get_attestation_with_issuer_status` would exceed the 32-character name limit.)
+    #[must_use]
+    pub fn get_attestation_with_issuer(env: Env, attestation_id: String) -> Result<(Attestation, bool), Error> {
+        query::get_attestation_with_issuer(&env, attestation_id)
+    }
+
+    /// Seconds since the attestation was created (0 if its timestamp is in the future).
+    #[must_use]
+    pub fn get_attestation_age(env: Env, attestation_id: String) -> Result<u64, Error> {
+        query::get_attestation_age(&env, attestation_id)
+    }
+
+    /// Return the revocation evidence URI for an attestation, or `None` if none was attached.
+    #[must_use]
+    pub fn get_revocation_evidence(env: Env, attestation_id: String) -> Option<String> {
+        query::get_revocation_evidence(&env, attestation_id)
+    }
+
     #[must_use]
     pub fn get_audit_log(env: Env, attestation_id: String) -> Vec<AuditEntry> {
         query::get_audit_log(&env, attestation_id)
     }
 
+    /// Ids from `attestation_id` through each replacement to the latest, following at most `max_depth` links.
+    #[must_use]
+    pub fn get_supersession_chain(env: Env, attestation_id: String, max_depth: u32) -> Vec<String> {
+        query::get_supersession_chain(&env, attestation_id, max_depth)
+    }
+
+    /// Current status; `NotFound` for unknown, deleted or pruned ids.
     #[must_use]
     pub fn get_attestation_status(env: Env, attestation_id: String) -> Result<AttestationStatus, Error> {
         query::get_attestation_status(&env, attestation_id)
     }
 
+    /// Current status, or `AttestationStatus::NotFound` instead of an error for missing ids.
+    #[must_use]
+    pub fn get_attestation_status_or(env: Env, attestation_id: String) -> AttestationStatus {
+        query::get_attestation_status_or(&env, attestation_id)
+    }
+
+    /// Refresh the cached status of up to 50 attestations, emitting `expired` for newly expired ones.
+    pub fn touch_batch(env: Env, attestation_ids: Vec<String>) -> Result<Vec<AttestationStatus>, Error> {
+        query::touch_batch(&env, attestation_ids)
+    }
+
     #[must_use]
     pub fn get_subject_attestations(env: Env, subject: Address, start: u32, limit: u32) -> Vec<String> {
         query::get_subject_attestations(&env, subject, start, limit)
     }
 
+    /// A page of the subject's attestation ids with `total` and `has_more`.
+    #[must_use]
+    pub fn get_subject_attestations_page(env: Env, subject: Address, start: u32, limit: u32) -> Page {
+        query::get_subject_attestations_page(&env, subject, start, limit)
+    }
+
+    /// Incremental sync: the subject's attestation ids created after `since_ts`.
+    #[must_use]
+    pub fn get_subject_attestations_since(
+        env: Env,
+        subject: Address,
+        since_ts: u64,
+        start: u32,
+        limit: u32,
+    ) -> Vec<String> {
+        query::get_subject_attestations_since(&env, subject, since_ts, start, limit)
+    }
+
+    /// Timestamp of the last change to the subject's attestations, for cache invalidation.
+    #[must_use]
+    pub fn get_subject_updated_at(env: Env, subject: Address) -> u64 {
+        query::get_subject_updated_at(&env, subject)
+    }
+
+    /// Return the attestation id at `index` in the subject's index (creation order), or None.
+    #[must_use]
+    pub fn get_subject_attestation_at(env: Env, subject: Address, index: u32) -> Option<String> {
+        query::get_subject_attestation_at(&env, subject, index)
+    }
+
+    /// Audit query: ids of attestations created within `[from_ts, to_ts]`.
+    ///
+    /// Scans every registered issuer's index; call via simulation.
+    #[must_use]
+    pub fn get_attestations_created_between(env: Env, from_ts: u64, to_ts: u64, start: u32, limit: u32) -> Vec<String> {
+        query::get_attestations_created_between(&env, from_ts, to_ts, start, limit)
+    }
+
     #[must_use]
     pub fn get_attestations_in_range(env: Env, subject: Address, from_ts: u64, to_ts: u64, start: u32, limit: u32) -> Vec<Attestation> {
         query::get_attestations_in_range(&env, subject, from_ts, to_ts, start, limit)
@@ -559,6 +1400,33 @@ impl TrustLinkContract {
         query::get_attestations_in_range_after(&env, subject, from_ts, to_ts, after_attestation_id, limit)
     }
 
+    /// Up to `scan_limit` of the subject's attestation ids, soonest-expiring first
+    /// (no expiration sorts last). The scan is bounded because sorting runs on-chain.
+    #[must_use]
+    pub fn get_subject_attestations_sorted(env: Env, subject: Address, scan_limit: u32) -> Vec<String> {
+        query::get_subject_attestations_sorted(&env, subject, scan_limit)
+    }
+
+    /// Ids at index positions `[offset, offset + count)`, clamped to the index length.
+    /// (`get_subject_attestation_ids_range` would exceed the 32-character name limit.)
+    #[must_use]
+    pub fn get_subject_attestation_id_range(env: Env, subject: Address, offset: u32, count: u32) -> Vec<String> {
+        query::get_subject_attestation_id_range(&env, subject, offset, count)
+    }
+
+    /// Subject's attestation ids in `category`. Named without `subject_` to stay
+    /// within the 32-character entrypoint limit.
+    #[must_use]
+    pub fn get_attestations_by_category(
+        env: Env,
+        subject: Address,
+        category: Category,
+        start: u32,
+        limit: u32,
+    ) -> Vec<String> {
+        query::get_attestations_by_category(&env, subject, category, start, limit)
+    }
+
     #[must_use]
     pub fn get_attestations_by_tag(env: Env, subject: Address, tag: String) -> Vec<String> {
         query::get_attestations_by_tag(&env, subject, tag)
@@ -574,6 +1442,25 @@ impl TrustLinkContract {
         query::get_issuer_attestations(&env, issuer, start, limit)
     }
 
+    /// Issuer's valid attestations bucketed by time to expiry (<1d, <7d, <30d, >30d, never).
+    #[must_use]
+    pub fn get_issuer_expiry_histogram(env: Env, issuer: Address, scan_limit: u32) -> ExpiryHistogram {
+        query::get_issuer_expiry_histogram(&env, issuer, scan_limit)
+    }
+
+    /// Resolved records for a page of the issuer's attestations.
+    #[must_use]
+    pub fn get_issuer_attestations_full(env: Env, issuer: Address, start: u32, limit: u32) -> Vec<Attestation> {
+        query::get_issuer_attestations_full(&env, issuer, start, limit)
+    }
+
+    /// Distinct claim types among the first `scan_limit` of the issuer's attestations,
+    /// plus a flag that is `true` when the scan stopped before the end of the index.
+    #[must_use]
+    pub fn get_issuer_claim_types(env: Env, issuer: Address, scan_limit: u32) -> (Vec<String>, bool) {
+        query::get_issuer_claim_types(&env, issuer, scan_limit)
+    }
+
     pub fn get_issuer_attestation_count(env: Env, issuer: Address) -> u32 {
         query::get_issuer_attestation_count(&env, issuer)
     }
@@ -583,15 +1470,40 @@ impl TrustLinkContract {
         query::get_valid_claims(&env, subject)
     }
 
+    /// Full records of the subject's currently valid attestations among the first
+    /// `scan_limit` index entries, plus a truncation flag.
+    #[must_use]
+    pub fn get_valid_attestations(env: Env, subject: Address, scan_limit: u32) -> (Vec<Attestation>, bool) {
+        query::get_valid_attestations(&env, subject, scan_limit)
+    }
+
+    /// Id of the most recent valid attestation backing `claim_type`, if any.
+    #[must_use]
+    pub fn resolve_valid_claim(env: Env, subject: Address, claim_type: String) -> Option<String> {
+        query::resolve_valid_claim(&env, subject, claim_type)
+    }
+
     #[must_use]
     pub fn get_attestation_by_type(env: Env, subject: Address, claim_type: String) -> Option<Attestation> {
         query::get_attestation_by_type(&env, subject, claim_type)
     }
 
+    /// Find the most recent valid attestation matching issuer, subject and claim type.
+    #[must_use]
+    pub fn find_attestation(env: Env, issuer: Address, subject: Address, claim_type: String) -> Option<Attestation> {
+        query::find_attestation(&env, issuer, subject, claim_type)
+    }
+
     pub fn get_subject_attestation_count(env: Env, subject: Address) -> u32 {
         query::get_subject_attestation_count(&env, subject)
     }
 
+    /// Number of non-revoked, non-deleted attestations of `claim_type`.
+    #[must_use]
+    pub fn get_claim_type_count(env: Env, claim_type: String) -> u32 {
+        query::get_claim_type_count(&env, claim_type)
+    }
+
     pub fn get_valid_claim_count(env: Env, subject: Address) -> u32 {
         query::get_valid_claim_count(&env, subject)
     }
@@ -628,6 +1540,8 @@ impl TrustLinkContract {
     // Multi-sig
     // -----------------------------------------------------------------------
 
+    /// Open a multi-sig proposal; multi-sig attestations carry no expiration
+    /// beyond a cohort window, so explicit-permanence mode blocks them.
     pub fn propose_attestation(
         env: Env,
         proposer: Address,
@@ -636,48 +1550,7 @@ impl TrustLinkContract {
         required_signers: Vec<Address>,
         threshold: u32,
     ) -> Result<String, Error> {
-        proposer.require_auth();
-        Validation::require_issuer(&env, &proposer)?;
-
-        // Validate all required signers are registered issuers.
-        for signer in required_signers.iter() {
-            Validation::require_issuer(&env, &signer)?;
-        }
-
-        let signer_count = required_signers.len();
-        if threshold == 0 || threshold > signer_count {
-            return Err(Error::InvalidThreshold);
-        }
-
-        let timestamp = env.ledger().timestamp();
-        let proposal_id =
-            MultiSigProposal::generate_id(&env, &proposer, &subject, &claim_type, timestamp);
-
-        // Proposer auto-signs on creation.
-        let mut signers = Vec::new(&env);
-        signers.push_back(proposer.clone());
-
-        let ttl_days = Storage::get_multisig_ttl(&env);
-        let ttl_secs = (ttl_days as u64) * SECS_PER_DAY;
-
-        let proposal = MultiSigProposal {
-            id: proposal_id.clone(),
-            proposer: proposer.clone(),
-            subject: subject.clone(),
-            claim_type,
-            required_signers,
-            threshold,
-            signers,
-            created_at: timestamp,
-            expires_at: timestamp + ttl_secs,
-            finalized: false,
-            cancelled: false,
-        };
-
-        Storage::set_multisig_proposal(&env, &proposal);
-        Storage::add_to_proposal_index(&env, &subject, &proposal_id);
-        Events::multisig_proposed(&env, &proposal_id, &proposer, &subject, threshold);
-        Ok(proposal_id)
+        multisig::propose_attestation(&env, proposer, subject, claim_type, required_signers, threshold)
     }
 
     pub fn cosign_attestation(env: Env, issuer: Address, proposal_id: String) -> Result<(), Error> {
@@ -735,6 +1608,7 @@ impl TrustLinkContract {
     pub fn set_multisig_ttl(env: Env, admin: Address, days: u32) -> Result<(), Error> {
         admin.require_auth();
         Validation::require_admin(&env, &admin)?;
+        Storage::record_admin_action(&env);
         Storage::set_multisig_ttl(&env, days);
         Ok(())
     }
@@ -905,40 +1779,22 @@ impl TrustLinkContract {
     }
 
     // -----------------------------------------------------------------------
-    // Attestation Templates
+    // Contract Metadata
     // -----------------------------------------------------------------------
 
-    /// Create or overwrite a named attestation template for the calling issuer.
-    ///
-    /// Templates capture default values for `claim_type`, optional expiration
-    /// window, and optional metadata. They can be instantiated later via
-    /// [`create_attestation_from_template`].
-    ///
-    /// # Errors
-    /// - [`Error::Unauthorized`] — `issuer` is not a registered issuer.
-    /// - [`Error::InvalidClaimType`] — `claim_type` is empty or invalid.
-    /// - [`Error::MetadataTooLong`] — `metadata_template` exceeds 256 bytes.
-    pub fn create_template(
+    /// Set the human-facing name, description and revision shown to registries.
+    pub fn set_contract_metadata(
         env: Env,
-        issuer: Address,
-        template_id: String,
-        template: AttestationTemplate,
+        admin: Address,
+        name: String,
+        description: String,
+        version: u32,
     ) -> Result<(), Error> {
-        issuer.require_auth();
-        Validation::require_issuer(&env, &issuer)?;
-        Validation::validate_claim_type(&template.claim_type)?;
-        Validation::validate_metadata(&env, &template.metadata_template)?;
+        admin::set_contract_metadata(&env, admin, name, description, version)
+    }
 
     pub fn get_contract_metadata(env: Env) -> Result<ContractMetadata, Error> {
-        let version = Storage::get_version(&env).ok_or(Error::NotInitialized)?;
-        Ok(ContractMetadata {
-            name: String::from_str(&env, "TrustLink"),
-            version,
-            description: String::from_str(
-                &env,
-                "On-chain attestation and verification system for the Stellar blockchain.",
-            ),
-        })
+        admin::get_contract_metadata(&env)
     }
 
     pub fn get_config(env: Env) -> ContractConfig {
@@ -951,20 +1807,52 @@ impl TrustLinkContract {
         });
 
         let version = Storage::get_version(&env).unwrap_or_else(|| String::from_str(&env, ""));
+        let (contract_name, contract_description) = match admin::get_contract_metadata(&env) {
+            Ok(metadata) => (metadata.name, metadata.description),
+            Err(_) => (String::from_str(&env, ""), String::from_str(&env, "")),
+        };
 
         ContractConfig {
             ttl_config,
             fee_config,
-            contract_name: String::from_str(&env, "TrustLink"),
+            contract_name,
             contract_version: version,
-            contract_description: String::from_str(
-                &env,
-                "On-chain attestation and verification system for the Stellar blockchain.",
-            ),
+            contract_description,
             multisig_ttl_days: Storage::get_multisig_ttl(&env),
         }
     }
 
+    // -----------------------------------------------------------------------
+    // Attestation Templates
+    // -----------------------------------------------------------------------
+
+    /// Create or overwrite a named attestation template for the calling issuer.
+    ///
+    /// Templates capture default values for `claim_type`, optional expiration
+    /// window, and optional metadata. They can be instantiated later via
+    /// [`create_attestation_from_template`].
+    ///
+    /// # Errors
+    /// - [`Error::Unauthorized`] — `issuer` is not a registered issuer.
+    /// - [`Error::InvalidClaimType`] — `claim_type` is empty or invalid.
+    /// - [`Error::MetadataTooLong`] — `metadata_template` exceeds 256 bytes.
+    pub fn create_template(
+        env: Env,
+        issuer: Address,
+        template_id: String,
+        template: AttestationTemplate,
+    ) -> Result<(), Error> {
+        issuer.require_auth();
+        Validation::require_issuer(&env, &issuer)?;
+        Validation::validate_claim_type(&template.claim_type)?;
+        Validation::validate_metadata(&env, &template.metadata_template)?;
+
+        Storage::set_template(&env, &issuer, &template_id, &template);
+        Storage::add_to_template_registry(&env, &issuer, &template_id);
+        Events::template_created(&env, &issuer, &template_id);
+        Ok(())
+    }
+
     /// Instantiate an attestation from a template, with optional field overrides.
     ///
     /// Loads the template for `(issuer, template_id)`, resolves the final
@@ -1016,17 +1904,9 @@ impl TrustLinkContract {
             template.metadata_template.clone()
         };
 
-        // Delegate to the shared internal creation path.
-        attestation::create_attestation_internal(
-            &env,
-            &attestation_id,
-            &AuditEntry {
-                action: AuditAction::Transferred,
-                actor: admin.clone(),
-                timestamp,
-                details: None,
-            },
-        );
+        // Delegate to the shared creation path.
+        attestation::create_attestation(&env, issuer, subject, template.claim_type, expiration, metadata, None)
+    }
 
     /// Return the ordered list of template IDs registered for `issuer`.
     ///
@@ -1,14 +1,19 @@
 use soroban_sdk::{Address, Env, String, Vec};
 
-use crate::attestation::store_attestation;
+use crate::attestation::{check_creation_policy, record_creation, store_attestation};
 use crate::events::Events;
 use crate::storage::Storage;
-use crate::types::{
-    Attestation, AttestationOrigin, Error, IssuerTier, MultiSigProposal,
-    MULTISIG_PROPOSAL_TTL_SECS,
-};
+use crate::types::{Attestation, AttestationOrigin, Category, Error, IssuerTier, MultiSigProposal, SECS_PER_DAY};
 use crate::validation::Validation;
 
+/// Open a multi-sig proposal, or create the attestation at once for a
+/// premium issuer's `ACCREDITED_INVESTOR` claim.
+///
+/// Multi-sig attestations take no caller-chosen expiration: they never
+/// expire unless the claim type has a cohort window. While
+/// `enforce_explicit_permanence` is on, a claim type without a cohort window
+/// therefore cannot be proposed and fails with
+/// [`Error::ExpirationRequired`].
 pub fn propose_attestation(
     env: &Env,
     proposer: Address,
@@ -18,8 +23,12 @@ pub fn propose_attestation(
     threshold: u32,
 ) -> Result<String, Error> {
     proposer.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Validation::require_issuer(env, &proposer)?;
-    Validation::require_not_paused(env)?;
+    Validation::require_create_not_paused(env)?;
+    Validation::validate_claim_type(&claim_type)?;
+    Validation::require_registered_claim_type(env, &claim_type)?;
+    let expiration = check_creation_policy(env, &proposer, &subject, &claim_type, None, false)?;
 
     // Premium issuers bypass multi-sig for ACCREDITED_INVESTOR.
     let accredited = String::from_str(env, "ACCREDITED_INVESTOR");
@@ -34,7 +43,7 @@ pub fn propose_attestation(
                 subject: subject.clone(),
                 claim_type: claim_type.clone(),
                 timestamp,
-                expiration: None,
+                expiration,
                 revoked: false,
                 metadata: None,
                 jurisdiction: None,
@@ -44,9 +53,21 @@ pub fn propose_attestation(
                 source_tx: None,
                 tags: None,
                 revocation_reason: None,
+                pinned: false,
+                expiration_locked: false,
+                superseded_by: None,
+                stake: None,
+                revocable: true,
+                score: None,
+                category: Category::Other,
+                permanent: false,
+                parent_issuer: Storage::get_effective_parent(env, &proposer),
+                depends_on: None,
+                revocation_evidence: None,
                 deleted: false,
             };
             store_attestation(env, &attestation);
+            record_creation(env, &attestation);
             Events::attestation_created(env, &attestation);
             return Ok(attestation_id);
         }
@@ -64,6 +85,7 @@ pub fn propose_attestation(
     let proposal_id = MultiSigProposal::generate_id(env, &proposer, &subject, &claim_type, timestamp);
     let mut signers = Vec::new(env);
     signers.push_back(proposer.clone());
+    let ttl_secs = (Storage::get_multisig_ttl(env) as u64) * SECS_PER_DAY;
     let proposal = MultiSigProposal {
         id: proposal_id.clone(),
         proposer: proposer.clone(),
@@ -73,10 +95,12 @@ pub fn propose_attestation(
         threshold,
         signers,
         created_at: timestamp,
-        expires_at: timestamp + MULTISIG_PROPOSAL_TTL_SECS,
+        expires_at: timestamp + ttl_secs,
         finalized: false,
+        cancelled: false,
     };
     Storage::set_multisig_proposal(env, &proposal);
+    Storage::add_to_proposal_index(env, &subject, &proposal_id);
     Events::multisig_proposed(env, &proposal_id, &proposer, &subject, threshold);
     Ok(proposal_id)
 }
@@ -84,7 +108,7 @@ pub fn propose_attestation(
 pub fn cosign_attestation(env: &Env, issuer: Address, proposal_id: String) -> Result<(), Error> {
     issuer.require_auth();
     Validation::require_issuer(env, &issuer)?;
-    Validation::require_not_paused(env)?;
+    Validation::require_create_not_paused(env)?;
 
     let mut proposal = Storage::get_multisig_proposal(env, &proposal_id)?;
     if proposal.finalized { return Err(Error::ProposalFinalized); }
@@ -109,6 +133,14 @@ pub fn cosign_attestation(env: &Env, issuer: Address, proposal_id: String) -> Re
         proposal.finalized = true;
         Storage::set_multisig_proposal(env, &proposal);
 
+        // Re-check policy at finalization: the type may have been
+        // unregistered, or a singleton or cooldown come into force, while
+        // signatures were being collected.
+        Validation::require_registered_claim_type(env, &proposal.claim_type)?;
+        let expiration = check_creation_policy(
+            env, &proposal.proposer, &proposal.subject, &proposal.claim_type, None, false,
+        )?;
+
         let attestation_id = Attestation::generate_id(
             env, &proposal.proposer, &proposal.subject, &proposal.claim_type, proposal.created_at,
         );
@@ -118,7 +150,7 @@ pub fn cosign_attestation(env: &Env, issuer: Address, proposal_id: String) -> Re
             subject: proposal.subject.clone(),
             claim_type: proposal.claim_type.clone(),
             timestamp: proposal.created_at,
-            expiration: None,
+            expiration,
             revoked: false,
             deleted: false,
             metadata: None,
@@ -129,9 +161,21 @@ pub fn cosign_attestation(env: &Env, issuer: Address, proposal_id: String) -> Re
             source_tx: None,
             tags: None,
             revocation_reason: None,
+            pinned: false,
+            expiration_locked: false,
+            superseded_by: None,
+            stake: None,
+            revocable: true,
+            score: None,
+            category: Category::Other,
+            permanent: false,
+            parent_issuer: Storage::get_effective_parent(env, &proposal.proposer),
+            depends_on: None,
+            revocation_evidence: None,
         };
 
         store_attestation(env, &attestation);
+        record_creation(env, &attestation);
         Events::attestation_created(env, &attestation);
         Events::multisig_activated(env, &proposal_id, &attestation_id);
     } else {
@@ -1,28 +1,41 @@
 use soroban_sdk::{Address, Env, String, Vec};
 
-use crate::attestation::maybe_trigger_expiration_hook;
+use crate::attestation::{maybe_auto_extend, maybe_trigger_expiration_hook};
+use crate::constants::SECS_PER_DAY;
 use crate::events::Events;
 use crate::storage::Storage;
 use crate::types::{
-    Attestation, AttestationStatus, AttestationVersionSnapshot, AuditEntry, Delegation,
-    DisputeRecord, Error, GlobalStats,
+    Attestation, AttestationStatus, AttestationVersionSnapshot, AuditEntry, Category, ClaimQuery, ClaimState,
+    Delegation, DisputeRecord, Error, ExpiryHistogram, GlobalStats, Page, VerifyResult, MAX_CLAIM_QUERY_DEPTH,
+    MAX_DEPENDENCY_DEPTH,
 };
+use crate::validation::Validation;
 
 /// Returns `true` if the subject holds at least one valid attestation for `claim_type`.
 ///
-/// Loads the subject's attestation index, then iterates entries in insertion order.
-/// Returns immediately on the first matching attestation with status
-/// [`AttestationStatus::Valid`] (short-circuit evaluation).
+/// Loads the subject's per-(subject, claim type) index, then iterates entries
+/// in insertion order. Returns immediately on the first matching attestation
+/// with status [`AttestationStatus::Valid`] (short-circuit evaluation).
 ///
-/// **Complexity:** O(n) in the worst case, where n is the number of attestations
-/// indexed for the subject. Loading the subject index is O(n), and the attestation
-/// scan is also O(n) when no valid match exists or when the only valid match is
-/// the last indexed entry. The best case is O(1) attestation reads when the first
-/// indexed entry is a valid match.
+/// **Complexity:** O(n) in the worst case, where n is the number of
+/// attestations of `claim_type` indexed for the subject. The best case is O(1)
+/// attestation reads when the first indexed entry is a valid match.
+///
+/// An empty `claim_type` can never match, so it returns `false` before any
+/// storage is read.
+///
+/// Subjects whose attestations predate the per-claim-type index have an
+/// empty list; for them the valid index is scanned instead and the list is
+/// backfilled from it, so later checks take the fast path.
 pub fn has_valid_claim(env: &Env, subject: Address, claim_type: String) -> bool {
-    // Use the pre-filtered valid-attestations index (non-revoked, non-deleted)
-    // to avoid reading records that can never produce a true result.
-    let attestation_ids = Storage::get_valid_attestations(env, &subject);
+    if claim_type.is_empty() {
+        return false;
+    }
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let mut attestation_ids = Storage::get_subject_claim_type_ids(env, &subject, &claim_type);
+    if attestation_ids.is_empty() {
+        attestation_ids = backfill_claim_type_ids(env, &subject, &claim_type);
+    }
     let current_time = env.ledger().timestamp();
 
     for attestation_id in attestation_ids.iter() {
@@ -30,7 +43,7 @@ pub fn has_valid_claim(env: &Env, subject: Address, claim_type: String) -> bool
             if attestation.claim_type != claim_type {
                 continue;
             }
-            if attestation.get_status(current_time) == AttestationStatus::Valid {
+            if effective_status(env, &attestation, current_time) == AttestationStatus::Valid {
                 maybe_trigger_expiration_hook(
                     env,
                     &subject,
@@ -45,14 +58,446 @@ pub fn has_valid_claim(env: &Env, subject: Address, claim_type: String) -> bool
     false
 }
 
+/// Rebuild `subject`'s per-claim-type list for `claim_type` from the valid
+/// index, for subjects indexed before that list existed.
+fn backfill_claim_type_ids(env: &Env, subject: &Address, claim_type: &String) -> Vec<String> {
+    let mut ids = Vec::new(env);
+    for attestation_id in Storage::get_valid_attestations(env, subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if &attestation.claim_type == claim_type && !attestation.revoked && !attestation.deleted {
+                ids.push_back(attestation_id);
+            }
+        }
+    }
+    if !ids.is_empty() {
+        Storage::set_subject_claim_type_ids(env, subject, claim_type, &ids);
+    }
+    ids
+}
+
+/// Like [`has_valid_claim`], but the matching attestation must also have been
+/// issued within the last `max_age_seconds` (e.g. "KYC within 90 days").
+/// Recency of issuance is checked independently of expiration.
+pub fn has_fresh_valid_claim(env: &Env, subject: Address, claim_type: String, max_age_seconds: u64) -> bool {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let current_time = env.ledger().timestamp();
+    let oldest_allowed = current_time.saturating_sub(max_age_seconds);
+    for attestation_id in Storage::get_valid_attestations(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if attestation.claim_type == claim_type
+                && attestation.timestamp >= oldest_allowed
+                && effective_status(env, &attestation, current_time) == AttestationStatus::Valid
+            {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Like [`has_valid_claim`], but evaluated at `as_of` instead of the current
+/// ledger time. Attestations created after `as_of` are ignored and expiration
+/// is checked against `as_of`; revocation is not time-aware, so a revoked
+/// attestation never counts.
+pub fn has_valid_claim_as_of(env: &Env, subject: Address, claim_type: String, as_of: u64) -> bool {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    for attestation_id in Storage::get_valid_attestations(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if attestation.claim_type == claim_type
+                && !attestation.deleted
+                && attestation.timestamp <= as_of
+                && effective_status(env, &attestation, as_of) == AttestationStatus::Valid
+            {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Audit-grade check: like [`has_valid_claim_as_of`], and the attestation's
+/// issuer must also have been a registered issuer at the attestation's
+/// creation time, per [`crate::admin::was_issuer_at`]. For attestations made
+/// by a sub-issuer, which is never in the registry itself, the recorded
+/// `parent_issuer` must have been registered instead. Later removal of the
+/// issuer does not matter; imported attestations dated before their issuer's
+/// registration, and bridged ones, never count.
+pub fn verify_claim_historical(env: &Env, subject: Address, claim_type: String, as_of: u64) -> bool {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    for attestation_id in Storage::get_valid_attestations(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if attestation.claim_type == claim_type
+                && !attestation.deleted
+                && attestation.timestamp <= as_of
+                && effective_status(env, &attestation, as_of) == AttestationStatus::Valid
+                && crate::admin::was_issuer_at(
+                    env,
+                    attestation.parent_issuer.clone().unwrap_or(attestation.issuer.clone()),
+                    attestation.timestamp,
+                )
+            {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Like [`has_valid_claim`], but an attestation only counts if every
+/// attestation in its `depends_on` chain is also currently valid. Chains longer
+/// than [`MAX_DEPENDENCY_DEPTH`] are treated as invalid.
+pub fn has_valid_claim_with_deps(env: &Env, subject: Address, claim_type: String) -> bool {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let current_time = env.ledger().timestamp();
+    for attestation_id in Storage::get_valid_attestations(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if attestation.claim_type == claim_type
+                && effective_status(env, &attestation, current_time) == AttestationStatus::Valid
+                && dependencies_valid(env, &attestation, current_time)
+            {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Status of `attestation` with its expiration clamped to that of every
+/// attestation in its `depends_on` chain, so a derived credential never
+/// outlives its basis. Missing parents and chains deeper than
+/// [`MAX_DEPENDENCY_DEPTH`] leave the attestation's own status unchanged.
+pub(crate) fn effective_status(env: &Env, attestation: &Attestation, current_time: u64) -> AttestationStatus {
+    let status = attestation.get_status(current_time);
+    if status != AttestationStatus::Valid {
+        return status;
+    }
+    let mut next = attestation.depends_on.clone();
+    let mut depth = 0;
+    while let Some(parent_id) = next {
+        depth += 1;
+        if depth > MAX_DEPENDENCY_DEPTH {
+            break;
+        }
+        let parent = match Storage::get_attestation(env, &parent_id) {
+            Ok(parent) => parent,
+            Err(_) => break,
+        };
+        match parent.expiration {
+            Some(expiration) if expiration == 0 || current_time >= expiration => {
+                return AttestationStatus::Expired;
+            }
+            _ => {}
+        }
+        next = parent.depends_on;
+    }
+    status
+}
+
+fn dependencies_valid(env: &Env, attestation: &Attestation, current_time: u64) -> bool {
+    let mut next = attestation.depends_on.clone();
+    let mut depth = 0;
+    while let Some(parent_id) = next {
+        depth += 1;
+        if depth > MAX_DEPENDENCY_DEPTH {
+            return false;
+        }
+        let parent = match Storage::get_attestation(env, &parent_id) {
+            Ok(parent) => parent,
+            Err(_) => return false,
+        };
+        if parent.deleted || parent.get_status(current_time) != AttestationStatus::Valid {
+            return false;
+        }
+        next = parent.depends_on;
+    }
+    true
+}
+
+/// Evaluate a [`ClaimQuery`] against the subject's current valid claims.
+///
+/// Queries nested deeper than [`MAX_CLAIM_QUERY_DEPTH`] evaluate to `false`.
+pub fn evaluate_claim_query(env: &Env, subject: Address, query: ClaimQuery) -> bool {
+    evaluate_claim_query_at(env, &subject, &query, 1)
+}
+
+fn evaluate_claim_query_at(env: &Env, subject: &Address, query: &ClaimQuery, depth: u32) -> bool {
+    if depth > MAX_CLAIM_QUERY_DEPTH {
+        return false;
+    }
+    match query {
+        ClaimQuery::Type(claim_type) => has_valid_claim(env, subject.clone(), claim_type.clone()),
+        ClaimQuery::And(queries) => {
+            if queries.is_empty() {
+                return false;
+            }
+            for inner in queries.iter() {
+                if !evaluate_claim_query_at(env, subject, &inner, depth + 1) {
+                    return false;
+                }
+            }
+            true
+        }
+        ClaimQuery::Or(queries) => {
+            for inner in queries.iter() {
+                if evaluate_claim_query_at(env, subject, &inner, depth + 1) {
+                    return true;
+                }
+            }
+            false
+        }
+    }
+}
+
+/// Check `has_valid_claim` on behalf of `verifier` and record the outcome in a
+/// `verified` event, giving auditors proof that the check happened.
+pub fn verify_and_log(env: &Env, verifier: Address, subject: Address, claim_type: String) -> bool {
+    verifier.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let result = has_valid_claim(env, subject.clone(), claim_type.clone());
+    Events::verification_performed(env, &verifier, &subject, &claim_type, result);
+    result
+}
+
+/// Whether `subject` holds a valid `claim_type` attestation whose `score`
+/// lies in `[min, max]`. Attestations without a score never match.
+pub fn has_valid_claim_in_range(env: &Env, subject: Address, claim_type: String, min: i128, max: i128) -> bool {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let current_time = env.ledger().timestamp();
+    for attestation_id in Storage::get_valid_attestations(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            let in_range = attestation.score.is_some_and(|score| score >= min && score <= max);
+            if attestation.claim_type == claim_type
+                && in_range
+                && effective_status(env, &attestation, current_time) == AttestationStatus::Valid
+            {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Authenticated [`has_valid_claim`]: `verifier` must sign, and a match inside
+/// its claim type's auto-extend grace window is extended when the issuer has
+/// pre-authorized it and is still active, up to the claim type's
+/// `max_extensions` (see `set_auto_extend`).
+///
+/// # Errors
+/// - [`Error::InvalidClaimType`] — `claim_type` is empty, which usually means
+///   the caller passed an uninitialized string.
+pub fn has_valid_claim_authed(env: &Env, verifier: Address, subject: Address, claim_type: String) -> Result<bool, Error> {
+    verifier.require_auth();
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    if claim_type.is_empty() {
+        return Err(Error::InvalidClaimType);
+    }
+    let current_time = env.ledger().timestamp();
+    for attestation_id in Storage::get_valid_attestations(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if attestation.claim_type == claim_type
+                && effective_status(env, &attestation, current_time) == AttestationStatus::Valid
+            {
+                maybe_auto_extend(env, &attestation, current_time);
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Return `true` if the subject holds at least one currently valid
+/// attestation of any claim type. Stops at the first match.
+pub fn has_any_valid_attestation(env: &Env, subject: Address) -> bool {
+    let current_time = env.ledger().timestamp();
+    for attestation_id in Storage::get_valid_attestations(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if !attestation.deleted && effective_status(env, &attestation, current_time) == AttestationStatus::Valid {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Like [`has_valid_claim`], but only honours attestations whose issuer is
+/// still registered. Removing an issuer retroactively invalidates their claims
+/// for strict consumers; for sub-issued attestations the parent must remain
+/// registered.
+pub fn has_valid_claim_strict(env: &Env, subject: Address, claim_type: String) -> bool {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let attestation_ids = Storage::get_valid_attestations(env, &subject);
+    let current_time = env.ledger().timestamp();
+
+    for attestation_id in attestation_ids.iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if attestation.claim_type != claim_type {
+                continue;
+            }
+            let registered = match &attestation.parent_issuer {
+                Some(parent) => Storage::is_issuer(env, parent),
+                None => Storage::is_issuer(env, &attestation.issuer),
+            };
+            if effective_status(env, &attestation, current_time) == AttestationStatus::Valid && registered {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Whether the summed weights of the distinct issuers holding a valid
+/// `claim_type` attestation for `subject` reach `min_weight`.
+///
+/// Each issuer counts once no matter how many attestations it issued; weights
+/// are set with `set_issuer_weight` and default to `0`.
+pub fn has_weighted_claim(env: &Env, subject: Address, claim_type: String, min_weight: u32) -> bool {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let current_time = env.ledger().timestamp();
+    let mut counted: Vec<Address> = Vec::new(env);
+    let mut total: u32 = 0;
+    for attestation_id in Storage::get_valid_attestations(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if attestation.claim_type != claim_type
+                || counted.contains(&attestation.issuer)
+                || effective_status(env, &attestation, current_time) != AttestationStatus::Valid
+            {
+                continue;
+            }
+            total = total.saturating_add(Storage::get_issuer_weight(env, &attestation.issuer));
+            counted.push_back(attestation.issuer);
+            if total >= min_weight {
+                return true;
+            }
+        }
+    }
+    total >= min_weight
+}
+
+/// Explain the outcome of a `claim_type` check for `subject`, reporting the
+/// most favourable matching attestation: `Valid`, then `Frozen` (valid but
+/// issued by a paused issuer), `Expired`, `Revoked`, and finally
+/// `NoAttestation`.
+///
+/// `Revoked` is read from the subject's revoked list, which keeps only the
+/// newest [`MAX_SUBJECT_REVOKED`](crate::constants::MAX_SUBJECT_REVOKED)
+/// revocations. Older revocations, and revocations made before the list
+/// existed, report `NoAttestation` until `rebuild_subject_index` is given
+/// their ids.
+pub fn verify_claim_detailed(env: &Env, subject: Address, claim_type: String) -> VerifyResult {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let current_time = env.ledger().timestamp();
+    let mut result = VerifyResult::NoAttestation;
+    for attestation_id in Storage::get_subject_attestations(env, &subject).iter() {
+        let Ok(attestation) = Storage::get_attestation(env, &attestation_id) else {
+            continue;
+        };
+        if attestation.deleted || attestation.claim_type != claim_type {
+            continue;
+        }
+        match effective_status(env, &attestation, current_time) {
+            AttestationStatus::Valid => {
+                let paused = Storage::is_issuer_paused(env, &attestation.issuer)
+                    || attestation
+                        .parent_issuer
+                        .as_ref()
+                        .is_some_and(|parent| Storage::is_issuer_paused(env, parent));
+                if !paused {
+                    return VerifyResult::Valid;
+                }
+                result = VerifyResult::Frozen;
+            }
+            AttestationStatus::Expired if result == VerifyResult::NoAttestation => {
+                result = VerifyResult::Expired;
+            }
+            _ => {}
+        }
+    }
+    if result != VerifyResult::NoAttestation {
+        return result;
+    }
+    for attestation_id in Storage::get_subject_revoked(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if !attestation.deleted && attestation.claim_type == claim_type {
+                return VerifyResult::Revoked;
+            }
+        }
+    }
+    result
+}
+
+/// Most favourable state among `subject`'s `claim_type` attestations:
+/// `Active` > `Expired` > `Revoked` > `None`. Not-yet-valid attestations
+/// count as neither active nor expired. `Revoked` has the same reach as in
+/// [`verify_claim_detailed`]; revocations outside it report `None`.
+pub fn get_claim_state(env: &Env, subject: Address, claim_type: String) -> ClaimState {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let current_time = env.ledger().timestamp();
+    let mut state = ClaimState::None;
+    for attestation_id in Storage::get_subject_attestations(env, &subject).iter() {
+        let Ok(attestation) = Storage::get_attestation(env, &attestation_id) else {
+            continue;
+        };
+        if attestation.deleted || attestation.claim_type != claim_type {
+            continue;
+        }
+        match effective_status(env, &attestation, current_time) {
+            AttestationStatus::Valid => return ClaimState::Active,
+            AttestationStatus::Expired => state = ClaimState::Expired,
+            _ => {}
+        }
+    }
+    if state != ClaimState::None {
+        return state;
+    }
+    for attestation_id in Storage::get_subject_revoked(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if !attestation.deleted && attestation.claim_type == claim_type {
+                return ClaimState::Revoked;
+            }
+        }
+    }
+    state
+}
+
+/// Whether both subjects hold a valid `claim_type` attestation. Skips the
+/// second lookup when the first subject already fails.
+pub fn both_have_valid_claim(env: &Env, subject_a: Address, subject_b: Address, claim_type: String) -> bool {
+    has_valid_claim(env, subject_a, claim_type.clone()) && has_valid_claim(env, subject_b, claim_type)
+}
+
+/// Whether `subject` holds a valid `claim_type` attestation from any issuer
+/// in the caller-supplied `trusted_issuers`, independent of the registry.
+pub fn has_valid_claim_from_any(env: &Env, subject: Address, claim_type: String, trusted_issuers: Vec<Address>) -> bool {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    if trusted_issuers.is_empty() {
+        return false;
+    }
+    let current_time = env.ledger().timestamp();
+    for attestation_id in Storage::get_subject_attestations(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if attestation.deleted
+                || attestation.claim_type != claim_type
+                || !trusted_issuers.contains(&attestation.issuer)
+            {
+                continue;
+            }
+            if effective_status(env, &attestation, current_time) == AttestationStatus::Valid {
+                return true;
+            }
+        }
+    }
+    false
+}
+
 pub fn has_valid_claim_from_issuer(env: &Env, subject: Address, claim_type: String, issuer: Address) -> bool {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     let attestation_ids = Storage::get_subject_attestations(env, &subject);
     let current_time = env.ledger().timestamp();
     for attestation_id in attestation_ids.iter() {
         if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
             if attestation.deleted { continue; }
             if attestation.claim_type == claim_type && attestation.issuer == issuer {
-                match attestation.get_status(current_time) {
+                match effective_status(env, &attestation, current_time) {
                     AttestationStatus::Valid => {
                         maybe_trigger_expiration_hook(
                             env,
@@ -81,11 +526,12 @@ pub fn has_any_claim(env: &Env, subject: Address, claim_types: Vec<String>) -> b
     let attestation_ids = Storage::get_subject_attestations(env, &subject);
     let current_time = env.ledger().timestamp();
     for claim_type in claim_types.iter() {
+        let claim_type = Validation::normalize_claim_type(env, &claim_type);
         for attestation_id in attestation_ids.iter() {
             if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
                 if !attestation.deleted
                     && attestation.claim_type == claim_type
-                    && attestation.get_status(current_time) == AttestationStatus::Valid
+                    && effective_status(env, &attestation, current_time) == AttestationStatus::Valid
                 {
                     maybe_trigger_expiration_hook(
                         env,
@@ -107,11 +553,12 @@ pub fn has_all_claims(env: &Env, subject: Address, claim_types: Vec<String>) ->
     let attestation_ids = Storage::get_subject_attestations(env, &subject);
     let current_time = env.ledger().timestamp();
     'claims: for claim_type in claim_types.iter() {
+        let claim_type = Validation::normalize_claim_type(env, &claim_type);
         for attestation_id in attestation_ids.iter() {
             if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
                 if !attestation.deleted
                     && attestation.claim_type == claim_type
-                    && attestation.get_status(current_time) == AttestationStatus::Valid
+                    && effective_status(env, &attestation, current_time) == AttestationStatus::Valid
                 {
                     continue 'claims;
                 }
@@ -122,6 +569,61 @@ pub fn has_all_claims(env: &Env, subject: Address, claim_types: Vec<String>) ->
     true
 }
 
+/// Whether `subject` holds valid attestations for at least `n` distinct
+/// types in `claim_types`. Repeated types count once, so an `n` larger than
+/// the list can never be met.
+pub fn has_n_of_claims(env: &Env, subject: Address, claim_types: Vec<String>, n: u32) -> bool {
+    if n == 0 {
+        return true;
+    }
+    if n > claim_types.len() {
+        return false;
+    }
+    let mut satisfied: Vec<String> = Vec::new(env);
+    for claim_type in claim_types.iter() {
+        let claim_type = Validation::normalize_claim_type(env, &claim_type);
+        if satisfied.contains(&claim_type) {
+            continue;
+        }
+        if has_valid_claim(env, subject.clone(), claim_type.clone()) {
+            satisfied.push_back(claim_type);
+            if satisfied.len() >= n {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Pack claim checks into a bitmask: bit `i` is set when the subject holds a
+/// valid attestation for `claim_types[i]`.
+///
+/// # Errors
+/// - [`Error::LimitExceeded`] — more than 64 claim types requested.
+pub fn claims_bitmap(env: &Env, subject: Address, claim_types: Vec<String>) -> Result<u64, Error> {
+    if claim_types.len() > 64 {
+        return Err(Error::LimitExceeded);
+    }
+    let attestation_ids = Storage::get_subject_attestations(env, &subject);
+    let current_time = env.ledger().timestamp();
+    let mut bitmap: u64 = 0;
+    for (i, claim_type) in claim_types.iter().enumerate() {
+        let claim_type = Validation::normalize_claim_type(env, &claim_type);
+        for attestation_id in attestation_ids.iter() {
+            if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+                if !attestation.deleted
+                    && attestation.claim_type == claim_type
+                    && effective_status(env, &attestation, current_time) == AttestationStatus::Valid
+                {
+                    bitmap |= 1u64 << i;
+                    break;
+                }
+            }
+        }
+    }
+    Ok(bitmap)
+}
+
 /// Check if multiple subjects all have a valid attestation for the given claim type.
 /// Returns a Vec<bool> where each element corresponds to whether that subject has the claim.
 /// This is more efficient than making individual has_valid_claim calls.
@@ -134,6 +636,23 @@ pub fn has_valid_claim_batch(env: &Env, subjects: Vec<Address>, claim_type: Stri
     results
 }
 
+/// Check arbitrary `(subject, claim_type)` pairs in one call. Result `i`
+/// answers `has_valid_claim` for `queries[i]`.
+///
+/// # Errors
+/// - [`Error::LimitExceeded`] — more than 50 pairs supplied.
+pub fn check_claims(env: &Env, queries: Vec<(Address, String)>) -> Result<Vec<bool>, Error> {
+    const MAX_QUERIES: u32 = 50;
+    if queries.len() > MAX_QUERIES {
+        return Err(Error::LimitExceeded);
+    }
+    let mut results = Vec::new(env);
+    for (subject, claim_type) in queries.iter() {
+        results.push_back(has_valid_claim(env, subject, claim_type));
+    }
+    Ok(results)
+}
+
 pub fn get_attestation(env: &Env, attestation_id: String) -> Result<Attestation, Error> {
     let attestation = Storage::get_attestation(env, &attestation_id)?;
     if attestation.deleted {
@@ -142,6 +661,60 @@ pub fn get_attestation(env: &Env, attestation_id: String) -> Result<Attestation,
     Ok(attestation)
 }
 
+/// Return `(issuer, subject)` for an attestation.
+pub fn get_attestation_parties(env: &Env, attestation_id: String) -> Result<(Address, Address), Error> {
+    let attestation = get_attestation(env, attestation_id)?;
+    Ok((attestation.issuer, attestation.subject))
+}
+
+/// Return `(issuer, subject, claim_type, timestamp, expiration, revoked)`
+/// for clients that cannot decode the full [`Attestation`] type. The tuple
+/// shape is fixed even as the struct gains fields.
+pub fn get_attestation_flat(
+    env: &Env,
+    attestation_id: String,
+) -> Result<(Address, Address, String, u64, Option<u64>, bool), Error> {
+    let attestation = get_attestation(env, attestation_id)?;
+    Ok((
+        attestation.issuer,
+        attestation.subject,
+        attestation.claim_type,
+        attestation.timestamp,
+        attestation.expiration,
+        attestation.revoked,
+    ))
+}
+
+/// Return the issuer of an attestation.
+pub fn get_attestation_issuer(env: &Env, attestation_id: String) -> Result<Address, Error> {
+    let attestation = get_attestation(env, attestation_id)?;
+    Ok(attestation.issuer)
+}
+
+/// Return an attestation together with whether its issuer is still
+/// authorized (registered and unpaused, or a sub-issuer of such a parent).
+/// The flag is informational and does not affect the attestation's status.
+pub fn get_attestation_with_issuer(env: &Env, attestation_id: String) -> Result<(Attestation, bool), Error> {
+    let attestation = get_attestation(env, attestation_id)?;
+    let issuer_authorized = Validation::is_issuer_active(env, &attestation.issuer);
+    Ok((attestation, issuer_authorized))
+}
+
+/// Seconds elapsed since the attestation was created.
+///
+/// Returns `0` if the stored timestamp is ahead of the current ledger time.
+pub fn get_attestation_age(env: &Env, attestation_id: String) -> Result<u64, Error> {
+    let attestation = get_attestation(env, attestation_id)?;
+    Ok(env.ledger().timestamp().saturating_sub(attestation.timestamp))
+}
+
+/// Return the evidence URI attached when the attestation was revoked, if any.
+pub fn get_revocation_evidence(env: &Env, attestation_id: String) -> Option<String> {
+    Storage::get_attestation(env, &attestation_id)
+        .ok()
+        .and_then(|attestation| attestation.revocation_evidence)
+}
+
 pub fn get_audit_log(env: &Env, attestation_id: String) -> Vec<AuditEntry> {
     Storage::get_audit_log(env, &attestation_id)
 }
@@ -152,21 +725,139 @@ pub fn get_delegation(
     delegate: Address,
     claim_type: String,
 ) -> Option<Delegation> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     Storage::get_delegation(env, &delegator, &delegate, &claim_type)
 }
 
+/// Follow `superseded_by` links from `attestation_id`, returning the ids from
+/// it to the latest replacement. At most `max_depth` links are followed, and
+/// the walk stops at the first repeated id. Empty when `attestation_id` does
+/// not exist.
+pub fn get_supersession_chain(env: &Env, attestation_id: String, max_depth: u32) -> Vec<String> {
+    let mut chain = Vec::new(env);
+    let Ok(mut current) = Storage::get_attestation(env, &attestation_id) else {
+        return chain;
+    };
+    chain.push_back(attestation_id);
+    for _ in 0..max_depth {
+        let Some(next_id) = current.superseded_by.clone() else {
+            break;
+        };
+        if chain.contains(&next_id) {
+            break;
+        }
+        let Ok(next) = Storage::get_attestation(env, &next_id) else {
+            break;
+        };
+        chain.push_back(next_id);
+        current = next;
+    }
+    chain
+}
+
+/// Current status of an attestation.
+///
+/// Pruning keeps no tombstone: an id removed by `sweep_expired` is
+/// indistinguishable from one that never existed.
+///
+/// # Errors
+/// - [`Error::NotFound`] — the id is unknown, deleted or pruned.
 pub fn get_attestation_status(env: &Env, attestation_id: String) -> Result<AttestationStatus, Error> {
     let attestation = Storage::get_attestation(env, &attestation_id)?;
     if attestation.deleted {
         return Err(Error::NotFound);
     }
-    let status = attestation.get_status(env.ledger().timestamp());
+    let status = effective_status(env, &attestation, env.ledger().timestamp());
     if status == AttestationStatus::Expired {
         Events::attestation_expired(env, &attestation_id, &attestation.subject);
     }
     Ok(status)
 }
 
+/// Like [`get_attestation_status`] but never errors: unknown, deleted or
+/// pruned ids map to [`AttestationStatus::NotFound`].
+pub fn get_attestation_status_or(env: &Env, attestation_id: String) -> AttestationStatus {
+    get_attestation_status(env, attestation_id).unwrap_or(AttestationStatus::NotFound)
+}
+
+/// Re-evaluate the status of up to 50 attestations, caching each result and
+/// emitting `expired` only for attestations whose cached status was not
+/// already `Expired`. Lets indexers surface time-based transitions in bulk.
+///
+/// # Errors
+/// - [`Error::LimitExceeded`] — more than 50 ids supplied.
+/// - [`Error::NotFound`] — any id does not exist or was deleted.
+pub fn touch_batch(env: &Env, attestation_ids: Vec<String>) -> Result<Vec<AttestationStatus>, Error> {
+    const MAX_BATCH: u32 = 50;
+
+    if attestation_ids.len() > MAX_BATCH {
+        return Err(Error::LimitExceeded);
+    }
+
+    let current_time = env.ledger().timestamp();
+    let mut statuses = Vec::new(env);
+    for attestation_id in attestation_ids.iter() {
+        let attestation = get_attestation(env, attestation_id.clone())?;
+        let status = effective_status(env, &attestation, current_time);
+        let previous = Storage::get_last_status(env, &attestation_id);
+        if previous.as_ref() != Some(&status) {
+            if status == AttestationStatus::Expired {
+                Events::attestation_expired(env, &attestation_id, &attestation.subject);
+            }
+            Storage::set_last_status(env, &attestation_id, &status);
+        }
+        statuses.push_back(status);
+    }
+    Ok(statuses)
+}
+
+/// Raw slice `[offset, offset + count)` of the subject's flat index,
+/// clamped to its length. Unlike [`get_subject_attestations`] no record is
+/// loaded or filtered, so the window maps one-to-one onto index positions.
+pub fn get_subject_attestation_id_range(env: &Env, subject: Address, offset: u32, count: u32) -> Vec<String> {
+    let ids = Storage::get_subject_attestations(env, &subject);
+    let len = ids.len();
+    let start = offset.min(len);
+    let end = offset.saturating_add(count).min(len);
+    ids.slice(start..end)
+}
+
+/// Ids of up to `scan_limit` of the subject's attestations, soonest-expiring
+/// first. Attestations without an expiration sort last; ties keep creation
+/// order. The sort is an O(n²) insertion sort run on-chain, so `scan_limit`
+/// bounds both the storage reads and the comparison cost; only the first
+/// `scan_limit` index entries are considered.
+pub fn get_subject_attestations_sorted(env: &Env, subject: Address, scan_limit: u32) -> Vec<String> {
+    let ids = Storage::get_subject_attestations(env, &subject);
+    let mut entries: Vec<(u64, String)> = Vec::new(env);
+    for id in ids.iter().take(scan_limit as usize) {
+        let attestation = match Storage::get_attestation(env, &id) {
+            Ok(a) if !a.deleted => a,
+            _ => continue,
+        };
+        let key = attestation.expiration.unwrap_or(u64::MAX);
+        let mut pos = entries.len();
+        while pos > 0 {
+            match entries.get(pos - 1) {
+                Some((prev, _)) if prev > key => pos -= 1,
+                _ => break,
+            }
+        }
+        entries.insert(pos, (key, id));
+    }
+    let mut result = Vec::new(env);
+    for (_, id) in entries.iter() {
+        result.push_back(id);
+    }
+    result
+}
+
+/// Return a page of the subject's attestation ids.
+///
+/// Ids are ordered by insertion (creation order) and that order is stable:
+/// removing an id (revocation, deletion) shifts later ids down by one and
+/// never reorders the rest. Consecutive pages `[0, n)`, `[n, 2n)`, … taken
+/// against an unchanged index are therefore contiguous and non-overlapping.
 pub fn get_subject_attestations(env: &Env, subject: Address, start: u32, limit: u32) -> Vec<String> {
     // Use the chunked index: loads only the chunks that overlap [start, start+limit).
     let ids = crate::storage::ChunkedIndex::get_subject_page(env, &subject, start, limit);
@@ -181,6 +872,60 @@ pub fn get_subject_attestations(env: &Env, subject: Address, start: u32, limit:
     result
 }
 
+/// [`get_subject_attestations`] together with the index size and whether
+/// another page follows, so clients can page without a separate count call.
+pub fn get_subject_attestations_page(env: &Env, subject: Address, start: u32, limit: u32) -> Page {
+    let window = crate::storage::ChunkedIndex::get_subject_page(env, &subject, start, limit);
+    let total = crate::storage::ChunkedIndex::subject_count(env, &subject);
+    let mut ids = Vec::new(env);
+    for id in window.iter() {
+        if let Ok(a) = Storage::get_attestation(env, &id) {
+            if !a.deleted {
+                ids.push_back(id);
+            }
+        }
+    }
+    let has_more = start.saturating_add(window.len()) < total;
+    Page { ids, total, has_more }
+}
+
+/// Ids of the subject's attestations created strictly after `since_ts`,
+/// paginated over the filtered set. Indexers can skip the call entirely when
+/// [`get_subject_updated_at`] has not advanced past their last sync.
+pub fn get_subject_attestations_since(
+    env: &Env,
+    subject: Address,
+    since_ts: u64,
+    start: u32,
+    limit: u32,
+) -> Vec<String> {
+    let mut filtered = Vec::new(env);
+    for id in Storage::get_subject_attestations(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &id) {
+            if !attestation.deleted && attestation.timestamp > since_ts {
+                filtered.push_back(id);
+            }
+        }
+    }
+    crate::storage::paginate(env, &filtered, start, limit)
+}
+
+/// Ledger timestamp of the last create/revoke/update touching `subject`'s
+/// attestations, or `0` if none. Off-chain caches compare it to decide
+/// whether to re-fetch.
+pub fn get_subject_updated_at(env: &Env, subject: Address) -> u64 {
+    Storage::get_subject_updated_at(env, &subject)
+}
+
+/// Return the attestation id at position `index` of the subject's index.
+///
+/// Ids are ordered by insertion (creation order); revocation or deletion
+/// removes an id and shifts later ids down by one. Returns `None` when
+/// `index` is out of range.
+pub fn get_subject_attestation_at(env: &Env, subject: Address, index: u32) -> Option<String> {
+    crate::storage::ChunkedIndex::get_subject_at(env, &subject, index)
+}
+
 /// Search the subject's attestations between `from_ts` and `to_ts`, excluding deleted records.
 ///
 /// This implementation uses offset-based pagination over the current filtered result set.
@@ -296,6 +1041,56 @@ pub fn get_attestations_in_range_after(
     result
 }
 
+/// Return ids of attestations whose `timestamp` falls in `[from_ts, to_ts]`,
+/// paginated over the filtered set.
+///
+/// There is no global attestation index, so this walks every registered
+/// issuer's index and loads each record. Gas grows with the total number of
+/// attestations held by registered issuers; attestations of removed issuers
+/// are not included. Intended for off-chain audit reads via simulation.
+pub fn get_attestations_created_between(
+    env: &Env,
+    from_ts: u64,
+    to_ts: u64,
+    start: u32,
+    limit: u32,
+) -> Vec<String> {
+    let mut filtered = Vec::new(env);
+    if from_ts > to_ts {
+        return filtered;
+    }
+    for issuer in Storage::get_issuer_list(env).iter() {
+        for id in Storage::get_issuer_attestations(env, &issuer).iter() {
+            if let Ok(attestation) = Storage::get_attestation(env, &id) {
+                if !attestation.deleted && attestation.timestamp >= from_ts && attestation.timestamp <= to_ts {
+                    filtered.push_back(id);
+                }
+            }
+        }
+    }
+    crate::storage::paginate(env, &filtered, start, limit)
+}
+
+/// Return the subject's non-deleted attestation ids in `category`,
+/// paginated over the filtered set.
+pub fn get_attestations_by_category(
+    env: &Env,
+    subject: Address,
+    category: Category,
+    start: u32,
+    limit: u32,
+) -> Vec<String> {
+    let mut filtered = Vec::new(env);
+    for id in Storage::get_subject_attestations(env, &subject).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &id) {
+            if !attestation.deleted && attestation.category == category {
+                filtered.push_back(id);
+            }
+        }
+    }
+    crate::storage::paginate(env, &filtered, start, limit)
+}
+
 pub fn get_attestations_by_tag(env: &Env, subject: Address, tag: String) -> Vec<String> {
     let attestation_ids = Storage::get_subject_attestations(env, &subject);
     let mut result = Vec::new(env);
@@ -351,6 +1146,71 @@ pub fn get_issuer_attestations(env: &Env, issuer: Address, start: u32, limit: u3
     result
 }
 
+/// Bucket up to `scan_limit` of the issuer's currently valid attestations by
+/// time remaining until expiry, for dashboards.
+pub fn get_issuer_expiry_histogram(env: &Env, issuer: Address, scan_limit: u32) -> ExpiryHistogram {
+    let current_time = env.ledger().timestamp();
+    let ids = Storage::get_issuer_attestations(env, &issuer);
+    let mut histogram = ExpiryHistogram { truncated: ids.len() > scan_limit, ..Default::default() };
+    for attestation_id in ids.iter().take(scan_limit as usize) {
+        let attestation = match Storage::get_attestation(env, &attestation_id) {
+            Ok(a) if !a.deleted => a,
+            _ => continue,
+        };
+        if effective_status(env, &attestation, current_time) != AttestationStatus::Valid {
+            continue;
+        }
+        match attestation.expiration {
+            None => histogram.never += 1,
+            Some(expiration) => {
+                let remaining = expiration.saturating_sub(current_time);
+                if remaining < SECS_PER_DAY {
+                    histogram.under_1d += 1;
+                } else if remaining < 7 * SECS_PER_DAY {
+                    histogram.under_7d += 1;
+                } else if remaining < 30 * SECS_PER_DAY {
+                    histogram.under_30d += 1;
+                } else {
+                    histogram.over_30d += 1;
+                }
+            }
+        }
+    }
+    histogram
+}
+
+/// Full records for the same page [`get_issuer_attestations`] would return
+/// as ids. Ids whose record was pruned or deleted are skipped.
+pub fn get_issuer_attestations_full(env: &Env, issuer: Address, start: u32, limit: u32) -> Vec<Attestation> {
+    let ids = crate::storage::ChunkedIndex::get_issuer_page(env, &issuer, start, limit);
+    let mut result = Vec::new(env);
+    for id in ids.iter() {
+        if let Ok(a) = Storage::get_attestation(env, &id) {
+            if !a.deleted {
+                result.push_back(a);
+            }
+        }
+    }
+    result
+}
+
+/// Collect the distinct claim types among the first `scan_limit` entries of
+/// the issuer's attestation index. The returned flag is `true` when the index
+/// holds more entries than were scanned, i.e. the list may be incomplete.
+pub fn get_issuer_claim_types(env: &Env, issuer: Address, scan_limit: u32) -> (Vec<String>, bool) {
+    let attestation_ids = Storage::get_issuer_attestations(env, &issuer);
+    let scanned = attestation_ids.len().min(scan_limit);
+    let mut claim_types: Vec<String> = Vec::new(env);
+    for attestation_id in attestation_ids.slice(0..scanned).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if !attestation.deleted && !claim_types.contains(&attestation.claim_type) {
+                claim_types.push_back(attestation.claim_type);
+            }
+        }
+    }
+    (claim_types, attestation_ids.len() > scanned)
+}
+
 pub fn get_issuer_attestation_count(env: &Env, issuer: Address) -> u32 {
     Storage::get_issuer_attestations(env, &issuer).len()
 }
@@ -360,7 +1220,7 @@ pub fn get_valid_claims(env: &Env, subject: Address) -> Vec<String> {
     let mut result = Vec::new(env);
     for attestation_id in Storage::get_subject_attestations(env, &subject).iter() {
         if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
-            if !attestation.deleted && attestation.get_status(current_time) == AttestationStatus::Valid {
+            if !attestation.deleted && effective_status(env, &attestation, current_time) == AttestationStatus::Valid {
                 let mut already_present = false;
                 for existing in result.iter() {
                     if existing == attestation.claim_type {
@@ -377,7 +1237,61 @@ pub fn get_valid_claims(env: &Env, subject: Address) -> Vec<String> {
     result
 }
 
+/// Return the full records of currently valid attestations among the first
+/// `scan_limit` entries of the subject's index. The flag is `true` when the
+/// index holds more entries than were scanned.
+pub fn get_valid_attestations(env: &Env, subject: Address, scan_limit: u32) -> (Vec<Attestation>, bool) {
+    let attestation_ids = Storage::get_subject_attestations(env, &subject);
+    let scanned = attestation_ids.len().min(scan_limit);
+    let current_time = env.ledger().timestamp();
+    let mut result = Vec::new(env);
+    for attestation_id in attestation_ids.slice(0..scanned).iter() {
+        if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+            if !attestation.deleted && effective_status(env, &attestation, current_time) == AttestationStatus::Valid {
+                result.push_back(attestation);
+            }
+        }
+    }
+    (result, attestation_ids.len() > scanned)
+}
+
 pub fn get_attestation_by_type(env: &Env, subject: Address, claim_type: String) -> Option<Attestation> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    let attestation_ids = Storage::get_subject_attestations(env, &subject);
+    let current_time = env.ledger().timestamp();
+    let mut index = attestation_ids.len();
+    while index > 0 {
+        index -= 1;
+        if let Some(attestation_id) = attestation_ids.get(index) {
+            if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
+                if !attestation.deleted
+                    && attestation.claim_type == claim_type
+                    && effective_status(env, &attestation, current_time) == AttestationStatus::Valid
+                {
+                    return Some(attestation);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Id of the most recently indexed valid `claim_type` attestation for
+/// `subject`, so relying contracts can record which credential satisfied a
+/// check. `None` when only expired or revoked attestations exist.
+pub fn resolve_valid_claim(env: &Env, subject: Address, claim_type: String) -> Option<String> {
+    get_attestation_by_type(env, subject, claim_type).map(|attestation| attestation.id)
+}
+
+/// Return the most recent valid attestation from `issuer` to `subject` for
+/// `claim_type`, without requiring the caller to know the derived id.
+pub fn find_attestation(
+    env: &Env,
+    issuer: Address,
+    subject: Address,
+    claim_type: String,
+) -> Option<Attestation> {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
     let attestation_ids = Storage::get_subject_attestations(env, &subject);
     let current_time = env.ledger().timestamp();
     let mut index = attestation_ids.len();
@@ -386,8 +1300,9 @@ pub fn get_attestation_by_type(env: &Env, subject: Address, claim_type: String)
         if let Some(attestation_id) = attestation_ids.get(index) {
             if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
                 if !attestation.deleted
+                    && attestation.issuer == issuer
                     && attestation.claim_type == claim_type
-                    && attestation.get_status(current_time) == AttestationStatus::Valid
+                    && effective_status(env, &attestation, current_time) == AttestationStatus::Valid
                 {
                     return Some(attestation);
                 }
@@ -401,12 +1316,19 @@ pub fn get_subject_attestation_count(env: &Env, subject: Address) -> u32 {
     crate::storage::ChunkedIndex::subject_count(env, &subject)
 }
 
+/// Live attestations of `claim_type` across all subjects: incremented on
+/// creation, decremented on revocation or deletion. Expiry is not tracked.
+pub fn get_claim_type_count(env: &Env, claim_type: String) -> u32 {
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Storage::get_claim_type_count(env, &claim_type)
+}
+
 pub fn get_valid_claim_count(env: &Env, subject: Address) -> u32 {
     let current_time = env.ledger().timestamp();
     let mut count = 0u32;
     for attestation_id in Storage::get_subject_attestations(env, &subject).iter() {
         if let Ok(attestation) = Storage::get_attestation(env, &attestation_id) {
-            if !attestation.deleted && attestation.get_status(current_time) == AttestationStatus::Valid {
+            if !attestation.deleted && effective_status(env, &attestation, current_time) == AttestationStatus::Valid {
                 count += 1;
             }
         }
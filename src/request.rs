@@ -1,10 +1,10 @@
 use soroban_sdk::{Address, Env, String, Vec};
 
-use crate::attestation::{store_attestation, validate_native_expiration};
+use crate::attestation::{check_creation_policy, record_creation, store_attestation};
 use crate::events::Events;
 use crate::storage::Storage;
 use crate::types::{
-    Attestation, AttestationOrigin, AttestationRequest, Error, RequestStatus,
+    Attestation, AttestationOrigin, AttestationRequest, Category, Error, RequestStatus,
     ATTESTATION_REQUEST_TTL_SECS,
 };
 use crate::validation::Validation;
@@ -16,7 +16,8 @@ pub fn request_attestation(
     claim_type: String,
 ) -> Result<String, Error> {
     subject.require_auth();
-    Validation::require_not_paused(env)?;
+    let claim_type = Validation::normalize_claim_type(env, &claim_type);
+    Validation::require_create_not_paused(env)?;
     Validation::require_issuer(env, &issuer)?;
     Validation::validate_claim_type(&claim_type)?;
 
@@ -53,7 +54,7 @@ pub fn fulfill_request(
     expiration: Option<u64>,
 ) -> Result<String, Error> {
     issuer.require_auth();
-    Validation::require_not_paused(env)?;
+    Validation::require_create_not_paused(env)?;
     Validation::require_issuer(env, &issuer)?;
 
     let mut request = Storage::get_request(env, &request_id)?;
@@ -72,7 +73,8 @@ pub fn fulfill_request(
         return Err(Error::RequestExpired);
     }
 
-    validate_native_expiration(env, expiration)?;
+    Validation::require_registered_claim_type(env, &request.claim_type)?;
+    let expiration = check_creation_policy(env, &issuer, &request.subject, &request.claim_type, expiration, false)?;
 
     let attestation_id = Attestation::generate_id(env, &issuer, &request.subject, &request.claim_type, current_time);
     if Storage::has_attestation(env, &attestation_id) {
@@ -103,10 +105,22 @@ pub fn fulfill_request(
         source_tx: None,
         tags: None,
         revocation_reason: None,
+        pinned: false,
+        expiration_locked: false,
+        superseded_by: None,
+        stake: None,
+        revocable: true,
+        score: None,
+        category: Category::Other,
+        permanent: false,
+        parent_issuer: Storage::get_effective_parent(env, &issuer),
+        depends_on: None,
+        revocation_evidence: None,
         deleted: false,
     };
 
     store_attestation(env, &attestation);
+    record_creation(env, &attestation);
     Events::attestation_created(env, &attestation);
 
     request.status = RequestStatus::Fulfilled;
@@ -125,7 +139,7 @@ pub fn reject_request(
     reason: Option<String>,
 ) -> Result<(), Error> {
     issuer.require_auth();
-    Validation::require_not_paused(env)?;
+    Validation::require_create_not_paused(env)?;
     Validation::require_issuer(env, &issuer)?;
     crate::attestation::validate_reason(&reason)?;
 
@@ -157,7 +171,7 @@ pub fn reject_request(
 
 pub fn cancel_request(env: &Env, subject: Address, request_id: String) -> Result<(), Error> {
     subject.require_auth();
-    Validation::require_not_paused(env)?;
+    Validation::require_create_not_paused(env)?;
 
     let mut request = Storage::get_request(env, &request_id)?;
 
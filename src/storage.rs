@@ -2,13 +2,13 @@
 //!
 //! Single point of contact between contract logic and on-chain storage.
 
-use crate::constants::{DAY_IN_LEDGERS, DEFAULT_INSTANCE_LIFETIME};
+use crate::constants::{DAY_IN_LEDGERS, DEFAULT_INSTANCE_LIFETIME, DEFAULT_MAX_CLAIM_TYPES, MAX_ISSUER_HISTORY, MAX_SUBJECT_REVOKED};
 use crate::types::{
-    Attestation, AttestationRequest, AuditEntry, ClaimTypeInfo, Endorsement, Error, ExpirationHook,
-    FeeConfig, GlobalStats, IssuerMetadata, IssuerStats, IssuerTier, MultiSigProposal,
+    Attestation, AttestationRequest, AttestationStatus, AuditEntry, AutoExtendConfig, BackupAdmin, ClaimTypeInfo, ContractMetadata, Endorsement, Error, ExpirationHook,
+    FeeConfig, GlobalStats, IssuerMetadata, IssuerStats, IssuerTier, MultiSigProposal, PauseFlags,
     RateLimitConfig, StorageLimits, TtlConfig,
 };
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Val, Vec};
 
 #[contracttype]
 pub enum StorageKey {
@@ -52,7 +52,8 @@ pub enum StorageKey {
     /// An attestation request record.
     AttestationRequest(String),
     IssuerPendingRequests(Address),
-    /// Contract paused flag.
+    /// Legacy contract-wide paused `bool`; superseded by [`PauseKey::Flags`]
+    /// and only read as a fallback until the next pause-flag write.
     Paused,
     /// Whitelist enabled flag per issuer — when true, only whitelisted subjects are accepted.
     WhitelistEnabled(Address),
@@ -66,6 +67,24 @@ pub enum StorageKey {
     ProposalIndex(Address),
     /// Configurable TTL in days for multisig proposals (default: 7).
     MultisigTtl,
+    /// Maximum attestations a single issuer may create within one ledger.
+    MaxAttestationsPerLedger,
+    /// Cohort validity window (seconds) for a claim type, anchored to a subject's first attestation.
+    CohortExpiration(String),
+    /// Per-issuer pause flag, independent of the contract-wide pause.
+    IssuerPaused(Address),
+    /// Last status surfaced for an attestation by `touch_batch`.
+    LastStatus(String),
+    /// Registered issuer a sub-issuer acts on behalf of.
+    SubIssuerParent(Address),
+    /// Admin-set human-facing description of this deployment.
+    ContractMetadata,
+    /// Ledger timestamp of the last write to any of a subject's attestations.
+    SubjectUpdatedAt(Address),
+    /// Maximum number of claim types the registry may hold.
+    MaxClaimTypes,
+    /// Backup admin and the inactivity window after which it may take over.
+    BackupAdmin,
 }
 
 /// Composite key for per-issuer-per-claim-type last issuance timestamps.
@@ -78,6 +97,206 @@ pub struct ClaimTypeIssuanceKey {
     pub claim_type: String,
 }
 
+/// Composite key for the per-issuer creation counter of a single ledger.
+/// Lives in temporary storage, so counters for past ledgers simply expire.
+#[contracttype]
+#[derive(Clone)]
+pub struct IssuerLedgerCountKey {
+    pub issuer: Address,
+    pub ledger_seq: u32,
+}
+
+/// Composite key for the timestamp of a subject's first attestation of a claim type.
+#[contracttype]
+#[derive(Clone)]
+pub struct SubjectFirstSeenKey {
+    pub subject: Address,
+    pub claim_type: String,
+}
+
+/// Key for the metered creation fee of a claim type. `StorageKey` is close to
+/// the 50-variant spec limit, so new keys are standalone structs.
+#[contracttype]
+#[derive(Clone)]
+pub struct ClaimTypeFeeKey {
+    pub claim_type: String,
+}
+
+/// Key for the metered fees an issuer owes but has not yet settled off-chain.
+#[contracttype]
+#[derive(Clone)]
+pub struct IssuerOwedKey {
+    pub issuer: Address,
+}
+
+/// Marker set once a subject acknowledges the revocation of an attestation.
+#[contracttype]
+#[derive(Clone)]
+pub struct RevocationAckKey {
+    pub attestation_id: String,
+}
+
+/// Instance-storage singletons added after `StorageKey` reached the
+/// 50-variant spec limit.
+#[contracttype]
+#[derive(Clone)]
+pub enum SingletonKey {
+    /// Set once at `initialize_dev`; enables `reset`.
+    DevMode,
+    /// While set, the issuer set cannot be changed.
+    IssuerRegistryFrozen,
+    /// Set once at `initialize_normalized`; claim types are ASCII-uppercased
+    /// on creation and verification.
+    NormalizeClaimTypes,
+    /// Delay in seconds between queuing and executing a destructive admin
+    /// operation; absent means the timelock is off.
+    OperationTimelock,
+}
+
+/// Key for the live-attestation counter of a claim type. A struct rather than
+/// a `StorageKey` variant because that enum is at the spec's variant limit.
+#[contracttype]
+#[derive(Clone)]
+pub struct ClaimTypeCountKey {
+    pub claim_type: String,
+}
+
+/// Marker for claim types limited to one valid attestation per issuer and subject.
+#[contracttype]
+#[derive(Clone)]
+pub struct SingletonClaimTypeKey {
+    pub claim_type: String,
+}
+
+/// Key for a claim type's auto-extend-on-use configuration.
+#[contracttype]
+#[derive(Clone)]
+pub struct AutoExtendKey {
+    pub claim_type: String,
+}
+
+/// Marker recording an issuer's consent to auto-extension of a claim type.
+#[contracttype]
+#[derive(Clone)]
+pub struct AutoExtendConsentKey {
+    pub issuer: Address,
+    pub claim_type: String,
+}
+
+/// Number of times an attestation has been auto-extended.
+#[contracttype]
+#[derive(Clone)]
+pub struct AutoExtendCountKey {
+    pub attestation_id: String,
+}
+
+/// Key for the ed25519 public key an issuer signs off-chain attestations with.
+#[contracttype]
+#[derive(Clone)]
+pub struct IssuerSigningKey {
+    pub issuer: Address,
+}
+
+/// Key for the next nonce an issuer's signed attestation must carry.
+#[contracttype]
+#[derive(Clone)]
+pub struct SigningNonceKey {
+    pub issuer: Address,
+}
+
+/// Key for the ledger timestamp after which an issuer's authorization lapses.
+#[contracttype]
+#[derive(Clone)]
+pub struct IssuerExpiryKey {
+    pub issuer: Address,
+}
+
+/// Key for the admin-assigned trust weight of an issuer.
+#[contracttype]
+#[derive(Clone)]
+pub struct IssuerWeightKey {
+    pub issuer: Address,
+}
+
+/// Key for the ids of a subject's revoked attestations, which the live
+/// subject indexes drop.
+#[contracttype]
+#[derive(Clone)]
+pub struct SubjectRevokedKey {
+    pub subject: Address,
+}
+
+/// Key for the contracts notified when a subject's claim of one type is revoked.
+#[contracttype]
+#[derive(Clone)]
+pub struct RevocationSubscribersKey {
+    pub subject: Address,
+    pub claim_type: String,
+}
+
+/// Key for the admin-configured re-creation cooldown after a revocation.
+#[contracttype]
+#[derive(Clone)]
+pub struct RevocationCooldownKey {
+    pub claim_type: String,
+}
+
+/// Key for the time of the latest revocation of an (issuer, subject, claim
+/// type) tuple. Only written while that claim type has a cooldown.
+#[contracttype]
+#[derive(Clone)]
+pub struct LastRevocationKey {
+    pub issuer: Address,
+    pub subject: Address,
+    pub claim_type: String,
+}
+
+/// Key for the ready-time of a queued timelocked operation, by operation hash.
+#[contracttype]
+#[derive(Clone)]
+pub struct QueuedOperationKey {
+    pub op_hash: BytesN<32>,
+}
+
+/// Key for an address's bounded log of `(timestamp, authorized)` issuer
+/// registration transitions.
+#[contracttype]
+#[derive(Clone)]
+pub struct IssuerHistoryKey {
+    pub issuer: Address,
+}
+
+/// Key for the ids of a subject's live attestations of one claim type, so
+/// verification reads a short list instead of the whole subject index.
+#[contracttype]
+#[derive(Clone)]
+pub struct SubjectClaimTypeKey {
+    pub subject: Address,
+    pub claim_type: String,
+}
+
+/// Instance key for the per-operation [`PauseFlags`]. A new key rather than
+/// [`StorageKey::Paused`], which holds a `bool` on older deployments.
+#[contracttype]
+#[derive(Clone)]
+pub enum PauseKey {
+    Flags,
+}
+
+/// Persistent keys for `sweep_expired` bookkeeping.
+#[contracttype]
+#[derive(Clone)]
+pub enum SweepKey {
+    /// Addresses outside the issuer and bridge lists whose index may still
+    /// hold attestations: removed issuers and sub-issuers.
+    ExtraSources,
+    /// `(source, position)` at which the last bounded sweep stopped.
+    Cursor,
+    /// Revoked attestation ids that carry an expiration. Revocation drops an
+    /// id from its issuer index, so the sweep reaches it through this list.
+    Revoked,
+}
+
 fn get_ttl_lifetime(env: &Env) -> u32 {
     if let Some(config) = env
         .storage()
@@ -169,6 +388,15 @@ impl Storage {
         env.storage().instance().get(&StorageKey::Version)
     }
 
+    pub fn set_contract_metadata(env: &Env, metadata: &ContractMetadata) {
+        env.storage().instance().set(&StorageKey::ContractMetadata, metadata);
+        env.storage().instance().extend_ttl(DEFAULT_INSTANCE_LIFETIME, DEFAULT_INSTANCE_LIFETIME);
+    }
+
+    pub fn get_contract_metadata(env: &Env) -> Option<ContractMetadata> {
+        env.storage().instance().get(&StorageKey::ContractMetadata)
+    }
+
     pub fn set_fee_config(env: &Env, fee_config: &FeeConfig) {
         let ttl = get_ttl_lifetime(env);
         env.storage().instance().set(&StorageKey::FeeConfig, fee_config);
@@ -204,6 +432,9 @@ impl Storage {
     }
 
     pub fn add_issuer(env: &Env, issuer: &Address) {
+        if !Self::is_issuer(env, issuer) {
+            Self::record_issuer_transition(env, issuer, true);
+        }
         let key = StorageKey::Issuer(issuer.clone());
         let ttl = get_ttl_lifetime(env);
         env.storage().persistent().set(&key, &true);
@@ -222,7 +453,14 @@ impl Storage {
     }
 
     pub fn remove_issuer(env: &Env, issuer: &Address) {
+        if Self::is_issuer(env, issuer) {
+            Self::record_issuer_transition(env, issuer, false);
+        }
         env.storage().persistent().remove(&StorageKey::Issuer(issuer.clone()));
+        env.storage().persistent().remove(&StorageKey::IssuerPaused(issuer.clone()));
+        if !Self::get_issuer_attestations(env, issuer).is_empty() {
+            Self::add_sweep_source(env, issuer);
+        }
         // Remove from IssuerList
         let existing = Self::get_issuer_list(env);
         let mut updated = Vec::new(env);
@@ -277,17 +515,44 @@ impl Storage {
         env.storage().persistent().has(&StorageKey::Attestation(id.clone()))
     }
 
+    /// Delete an attestation record; indexes are left to the caller.
+    pub fn remove_attestation(env: &Env, id: &String) {
+        env.storage().persistent().remove(&StorageKey::Attestation(id.clone()));
+    }
+
+    /// Persist an attestation and bump its subject's last-updated timestamp.
     pub fn set_attestation(env: &Env, attestation: &Attestation) {
         let key = StorageKey::Attestation(attestation.id.clone());
         let ttl = get_ttl_lifetime(env);
         env.storage().persistent().set(&key, attestation);
         env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        Self::touch_subject(env, &attestation.subject);
+    }
+
+    /// Record that `subject`'s attestation set changed at the current ledger time.
+    pub fn touch_subject(env: &Env, subject: &Address) {
+        let key = StorageKey::SubjectUpdatedAt(subject.clone());
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, &env.ledger().timestamp());
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
+    pub fn get_subject_updated_at(env: &Env, subject: &Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&StorageKey::SubjectUpdatedAt(subject.clone()))
+            .unwrap_or(0)
     }
 
     pub fn get_attestation(env: &Env, id: &String) -> Result<Attestation, Error> {
         env.storage().persistent().get(&StorageKey::Attestation(id.clone())).ok_or(Error::NotFound)
     }
 
+    /// Raw stored value of an attestation, whatever layout it was written in.
+    pub fn get_attestation_val(env: &Env, id: &String) -> Option<Val> {
+        env.storage().persistent().get(&StorageKey::Attestation(id.clone()))
+    }
+
     pub fn get_subject_attestations(env: &Env, subject: &Address) -> Vec<String> {
         env.storage().persistent().get(&StorageKey::SubjectAttestations(subject.clone())).unwrap_or(Vec::new(env))
     }
@@ -304,12 +569,18 @@ impl Storage {
     pub fn remove_subject_attestation(env: &Env, subject: &Address, attestation_id: &String) {
         let key = StorageKey::SubjectAttestations(subject.clone());
         let ttl = get_ttl_lifetime(env);
-        let existing = Self::get_subject_attestations(env, subject);
-        let mut updated = Vec::new(env);
-        for id in existing.iter() {
-            if &id != attestation_id { updated.push_back(id); }
+        let mut list = Self::get_subject_attestations(env, subject);
+        if remove_preserving_order(&mut list, attestation_id) {
+            env.storage().persistent().set(&key, &list);
+            env.storage().persistent().extend_ttl(&key, ttl, ttl);
         }
-        env.storage().persistent().set(&key, &updated);
+    }
+
+    /// Overwrite the subject index with `attestation_ids`.
+    pub fn set_subject_attestations(env: &Env, subject: &Address, attestation_ids: &Vec<String>) {
+        let key = StorageKey::SubjectAttestations(subject.clone());
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, attestation_ids);
         env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
@@ -326,6 +597,24 @@ impl Storage {
         env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
+    pub fn remove_issuer_attestation(env: &Env, issuer: &Address, attestation_id: &String) {
+        let key = StorageKey::IssuerAttestations(issuer.clone());
+        let ttl = get_ttl_lifetime(env);
+        let mut list = Self::get_issuer_attestations(env, issuer);
+        if remove_preserving_order(&mut list, attestation_id) {
+            env.storage().persistent().set(&key, &list);
+            env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        }
+    }
+
+    /// Overwrite the issuer index with `attestation_ids`.
+    pub fn set_issuer_attestations(env: &Env, issuer: &Address, attestation_ids: &Vec<String>) {
+        let key = StorageKey::IssuerAttestations(issuer.clone());
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, attestation_ids);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
     /// Append multiple attestation IDs to the issuer index in a single write.
     ///
     /// Used by `create_attestations_batch` to replace N per-item writes with
@@ -379,6 +668,35 @@ impl Storage {
         env.storage().persistent().get(&StorageKey::ClaimTypeList).unwrap_or(Vec::new(env))
     }
 
+    /// Remove a claim type from the registry. Returns `false` if it was not registered.
+    pub fn remove_claim_type(env: &Env, claim_type: &String) -> bool {
+        let key = StorageKey::ClaimType(claim_type.clone());
+        if !env.storage().persistent().has(&key) {
+            return false;
+        }
+        env.storage().persistent().remove(&key);
+        let list_key = StorageKey::ClaimTypeList;
+        let mut list = Self::get_claim_type_list(env);
+        if remove_preserving_order(&mut list, claim_type) {
+            let ttl = get_ttl_lifetime(env);
+            env.storage().persistent().set(&list_key, &list);
+            env.storage().persistent().extend_ttl(&list_key, ttl, ttl);
+        }
+        true
+    }
+
+    pub fn get_max_claim_types(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&StorageKey::MaxClaimTypes)
+            .unwrap_or(DEFAULT_MAX_CLAIM_TYPES)
+    }
+
+    pub fn set_max_claim_types(env: &Env, max: u32) {
+        env.storage().instance().set(&StorageKey::MaxClaimTypes, &max);
+        env.storage().instance().extend_ttl(DEFAULT_INSTANCE_LIFETIME, DEFAULT_INSTANCE_LIFETIME);
+    }
+
     /// Persist storage limits in instance storage.
     pub fn set_limits(env: &Env, limits: &StorageLimits) {
         let ttl = get_ttl_lifetime(env);
@@ -432,150 +750,639 @@ impl Storage {
     pub fn set_proposal(env: &Env, proposal: &CouncilProposal) {
         let key = StorageKey::CouncilProposal(proposal.id);
         let ttl = get_ttl_lifetime(env);
-        env.storage().persistent().set(&key, proposal);
+        env.storage().persistent().set(&key, proposal);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
+    pub fn remove_from_whitelist(env: &Env, issuer: &Address, subject: &Address) {
+        env.storage().persistent().remove(&StorageKey::IssuerWhitelist(issuer.clone(), subject.clone()));
+    }
+
+    pub fn is_subject_whitelisted(env: &Env, issuer: &Address, subject: &Address) -> bool {
+        Self::is_whitelisted(env, issuer, subject)
+    }
+
+    pub fn add_subject_to_whitelist(env: &Env, issuer: &Address, subject: &Address) {
+        Self::add_to_whitelist(env, issuer, subject);
+    }
+
+    pub fn remove_subject_from_whitelist(env: &Env, issuer: &Address, subject: &Address) {
+        Self::remove_from_whitelist(env, issuer, subject);
+    }
+
+    /// Set every pause flag at once.
+    pub fn set_paused(env: &Env, paused: bool) {
+        Self::set_pause_flags(
+            env,
+            &PauseFlags {
+                create: paused,
+                revoke: paused,
+                register: paused,
+            },
+        );
+    }
+
+    /// True when any pause flag is set.
+    pub fn is_paused(env: &Env) -> bool {
+        let flags = Self::get_pause_flags(env);
+        flags.create || flags.revoke || flags.register
+    }
+
+    /// Write the pause flags and drop the legacy `bool` they replace.
+    pub fn set_pause_flags(env: &Env, flags: &PauseFlags) {
+        env.storage().instance().set(&PauseKey::Flags, flags);
+        env.storage().instance().remove(&StorageKey::Paused);
+        env.storage().instance().extend_ttl(DEFAULT_INSTANCE_LIFETIME, DEFAULT_INSTANCE_LIFETIME);
+    }
+
+    /// Current pause flags. Deployments paused before per-operation flags
+    /// existed hold a `bool` under [`StorageKey::Paused`]; it maps to all
+    /// three flags until the next write.
+    pub fn get_pause_flags(env: &Env) -> PauseFlags {
+        if let Some(flags) = env.storage().instance().get(&PauseKey::Flags) {
+            return flags;
+        }
+        let paused: bool = env.storage().instance().get(&StorageKey::Paused).unwrap_or(false);
+        PauseFlags { create: paused, revoke: paused, register: paused }
+    }
+
+    pub fn is_issuer_paused(env: &Env, issuer: &Address) -> bool {
+        env.storage().persistent().has(&StorageKey::IssuerPaused(issuer.clone()))
+    }
+
+    pub fn set_issuer_paused(env: &Env, issuer: &Address, paused: bool) {
+        let key = StorageKey::IssuerPaused(issuer.clone());
+        if paused {
+            let ttl = get_ttl_lifetime(env);
+            env.storage().persistent().set(&key, &true);
+            env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    pub fn get_global_stats(env: &Env) -> GlobalStats {
+        env.storage().instance()
+            .get(&StorageKey::GlobalStats)
+            .unwrap_or(GlobalStats { total_attestations: 0, total_revocations: 0, total_issuers: 0 })
+    }
+
+    pub fn set_global_stats(env: &Env, stats: &GlobalStats) {
+        Self::set_global_stats_raw(env, stats)
+    }
+
+    pub fn get_global_stats_raw(env: &Env) -> GlobalStats {
+        Self::get_global_stats(env)
+    }
+
+    fn set_global_stats_raw(env: &Env, stats: &GlobalStats) {
+        let ttl = get_ttl_lifetime(env);
+        env.storage().instance().set(&StorageKey::GlobalStats, stats);
+        env.storage().instance().extend_ttl(ttl, ttl);
+    }
+
+    /// Increment `total_attestations` by `count`.
+    pub fn increment_total_attestations(env: &Env, count: u64) {
+        let mut stats = Self::get_global_stats(env);
+        stats.total_attestations = stats.total_attestations.saturating_add(count);
+        Self::set_global_stats(env, &stats);
+    }
+
+    pub fn increment_total_revocations(env: &Env, by: u64) {
+        let mut s = Self::get_global_stats_raw(env);
+        s.total_revocations = s.total_revocations.saturating_add(by);
+        Self::set_global_stats_raw(env, &s);
+    }
+
+    pub fn increment_total_issuers(env: &Env) {
+        let mut s = Self::get_global_stats_raw(env);
+        s.total_issuers = s.total_issuers.saturating_add(1);
+        Self::set_global_stats_raw(env, &s);
+    }
+
+    pub fn decrement_total_issuers(env: &Env) {
+        let mut s = Self::get_global_stats_raw(env);
+        s.total_issuers = s.total_issuers.saturating_sub(1);
+        Self::set_global_stats_raw(env, &s);
+    }
+
+    pub fn get_issuer_stats(env: &Env, issuer: &Address) -> IssuerStats {
+        env.storage().persistent().get(&StorageKey::IssuerStats(issuer.clone()))
+            .unwrap_or(IssuerStats { total_issued: 0 })
+    }
+
+    pub fn set_issuer_stats(env: &Env, issuer: &Address, stats: &IssuerStats) {
+        let key = StorageKey::IssuerStats(issuer.clone());
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, stats);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
+    pub fn set_issuer_tier(env: &Env, issuer: &Address, tier: &IssuerTier) {
+        let key = StorageKey::IssuerTier(issuer.clone());
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, tier);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
+    pub fn get_issuer_tier(env: &Env, issuer: &Address) -> Option<IssuerTier> {
+        env.storage().persistent().get(&StorageKey::IssuerTier(issuer.clone()))
+    }
+
+    pub fn get_limits(env: &Env) -> StorageLimits {
+        env.storage().instance().get(&StorageKey::StorageLimits).unwrap_or_default()
+    }
+
+    pub fn set_limits(env: &Env, limits: &StorageLimits) {
+        let ttl = get_ttl_lifetime(env);
+        env.storage().instance().set(&StorageKey::StorageLimits, limits);
+        env.storage().instance().extend_ttl(ttl, ttl);
+    }
+
+    pub fn get_rate_limit_config(env: &Env) -> Option<RateLimitConfig> {
+        env.storage().instance().get(&StorageKey::RateLimitConfig)
+    }
+
+    pub fn set_rate_limit_config(env: &Env, config: &RateLimitConfig) {
+        let ttl = get_ttl_lifetime(env);
+        env.storage().instance().set(&StorageKey::RateLimitConfig, config);
+        env.storage().instance().extend_ttl(ttl, ttl);
+    }
+
+    /// Get the per-claim-type rate limit override for a claim type, or None if not set.
+    pub fn get_claim_type_rate_limit(env: &Env, claim_type: &String) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::ClaimTypeRateLimit(claim_type.clone()))
+    }
+
+    /// Set a per-claim-type rate limit override.
+    pub fn set_claim_type_rate_limit(env: &Env, claim_type: &String, interval_secs: u64) {
+        let ttl = get_ttl_lifetime(env);
+        env.storage()
+            .instance()
+            .set(&StorageKey::ClaimTypeRateLimit(claim_type.clone()), &interval_secs);
+        env.storage().instance().extend_ttl(ttl, ttl);
+    }
+
+    pub fn get_last_issuance_time(env: &Env, issuer: &Address) -> Option<u64> {
+        env.storage().persistent().get(&StorageKey::LastIssuanceTime(issuer.clone()))
+    }
+
+    pub fn set_last_issuance_time(env: &Env, issuer: &Address, timestamp: u64) {
+        let key = StorageKey::LastIssuanceTime(issuer.clone());
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, &timestamp);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
+    /// Per-ledger creation cap for each issuer, or `None` if not configured.
+    pub fn get_max_per_ledger(env: &Env) -> Option<u32> {
+        env.storage().instance().get(&StorageKey::MaxAttestationsPerLedger)
+    }
+
+    pub fn set_max_per_ledger(env: &Env, max_per_ledger: u32) {
+        let ttl = get_ttl_lifetime(env);
+        env.storage().instance().set(&StorageKey::MaxAttestationsPerLedger, &max_per_ledger);
+        env.storage().instance().extend_ttl(ttl, ttl);
+    }
+
+    /// Number of attestations `issuer` has created in the current ledger.
+    pub fn get_issuer_ledger_count(env: &Env, issuer: &Address) -> u32 {
+        let key = IssuerLedgerCountKey { issuer: issuer.clone(), ledger_seq: env.ledger().sequence() };
+        env.storage().temporary().get(&key).unwrap_or(0)
+    }
+
+    pub fn increment_issuer_ledger_count(env: &Env, issuer: &Address, count: u32) {
+        let key = IssuerLedgerCountKey { issuer: issuer.clone(), ledger_seq: env.ledger().sequence() };
+        let current: u32 = env.storage().temporary().get(&key).unwrap_or(0);
+        env.storage().temporary().set(&key, &current.saturating_add(count));
+    }
+
+    /// Cohort validity window for `claim_type`, or `None` if cohort mode is off.
+    pub fn get_cohort_expiration(env: &Env, claim_type: &String) -> Option<u64> {
+        env.storage().instance().get(&StorageKey::CohortExpiration(claim_type.clone()))
+    }
+
+    pub fn set_cohort_expiration(env: &Env, claim_type: &String, window_secs: u64) {
+        let ttl = get_ttl_lifetime(env);
+        env.storage().instance().set(&StorageKey::CohortExpiration(claim_type.clone()), &window_secs);
+        env.storage().instance().extend_ttl(ttl, ttl);
+    }
+
+    pub fn remove_cohort_expiration(env: &Env, claim_type: &String) {
+        env.storage().instance().remove(&StorageKey::CohortExpiration(claim_type.clone()));
+    }
+
+    /// Timestamp of the subject's first attestation of `claim_type`, if any.
+    pub fn get_subject_first_seen(env: &Env, subject: &Address, claim_type: &String) -> Option<u64> {
+        let key = SubjectFirstSeenKey { subject: subject.clone(), claim_type: claim_type.clone() };
+        env.storage().persistent().get(&key)
+    }
+
+    /// Record the subject's first-seen timestamp for `claim_type` unless one already exists.
+    pub fn set_subject_first_seen_if_absent(env: &Env, subject: &Address, claim_type: &String, timestamp: u64) {
+        let key = SubjectFirstSeenKey { subject: subject.clone(), claim_type: claim_type.clone() };
+        if env.storage().persistent().has(&key) {
+            return;
+        }
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, &timestamp);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
+    pub fn get_last_status(env: &Env, attestation_id: &String) -> Option<AttestationStatus> {
+        env.storage().persistent().get(&StorageKey::LastStatus(attestation_id.clone()))
+    }
+
+    pub fn set_last_status(env: &Env, attestation_id: &String, status: &AttestationStatus) {
+        let key = StorageKey::LastStatus(attestation_id.clone());
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, status);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
+    pub fn get_sub_issuer_parent(env: &Env, sub_issuer: &Address) -> Option<Address> {
+        env.storage().persistent().get(&StorageKey::SubIssuerParent(sub_issuer.clone()))
+    }
+
+    pub fn set_sub_issuer_parent(env: &Env, sub_issuer: &Address, parent: &Address) {
+        let key = StorageKey::SubIssuerParent(sub_issuer.clone());
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, parent);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        Self::add_sweep_source(env, sub_issuer);
+    }
+
+    /// Issuer indexes `sweep_expired` walks beyond the issuer and bridge lists.
+    pub fn get_sweep_sources(env: &Env) -> Vec<Address> {
+        env.storage().persistent().get(&SweepKey::ExtraSources).unwrap_or(Vec::new(env))
+    }
+
+    fn add_sweep_source(env: &Env, address: &Address) {
+        let mut sources = Self::get_sweep_sources(env);
+        if sources.contains(address) {
+            return;
+        }
+        sources.push_back(address.clone());
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&SweepKey::ExtraSources, &sources);
+        env.storage().persistent().extend_ttl(&SweepKey::ExtraSources, ttl, ttl);
+    }
+
+    pub fn get_sweep_cursor(env: &Env) -> (u32, u32) {
+        env.storage().persistent().get(&SweepKey::Cursor).unwrap_or((0, 0))
+    }
+
+    pub fn set_sweep_cursor(env: &Env, cursor: (u32, u32)) {
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&SweepKey::Cursor, &cursor);
+        env.storage().persistent().extend_ttl(&SweepKey::Cursor, ttl, ttl);
+    }
+
+    /// Revoked ids awaiting `sweep_expired`, oldest first.
+    pub fn get_revoked_sweep_ids(env: &Env) -> Vec<String> {
+        env.storage().persistent().get(&SweepKey::Revoked).unwrap_or(Vec::new(env))
+    }
+
+    pub fn set_revoked_sweep_ids(env: &Env, attestation_ids: &Vec<String>) {
+        if attestation_ids.is_empty() {
+            env.storage().persistent().remove(&SweepKey::Revoked);
+            return;
+        }
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&SweepKey::Revoked, attestation_ids);
+        env.storage().persistent().extend_ttl(&SweepKey::Revoked, ttl, ttl);
+    }
+
+    pub fn add_revoked_sweep_id(env: &Env, attestation_id: &String) {
+        let mut list = Self::get_revoked_sweep_ids(env);
+        if list.contains(attestation_id) {
+            return;
+        }
+        list.push_back(attestation_id.clone());
+        Self::set_revoked_sweep_ids(env, &list);
+    }
+
+    pub fn remove_revoked_sweep_id(env: &Env, attestation_id: &String) {
+        let list = Self::get_revoked_sweep_ids(env);
+        if let Some(index) = list.first_index_of(attestation_id) {
+            let mut list = list;
+            list.remove(index);
+            Self::set_revoked_sweep_ids(env, &list);
+        }
+    }
+
+    pub fn remove_sub_issuer_parent(env: &Env, sub_issuer: &Address) {
+        env.storage().persistent().remove(&StorageKey::SubIssuerParent(sub_issuer.clone()));
+    }
+
+    /// Parent on whose behalf `issuer` acts, or `None` for directly registered issuers.
+    pub fn get_effective_parent(env: &Env, issuer: &Address) -> Option<Address> {
+        if Self::is_issuer(env, issuer) {
+            return None;
+        }
+        Self::get_sub_issuer_parent(env, issuer)
+    }
+
+    pub fn get_claim_type_fee(env: &Env, claim_type: &String) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&ClaimTypeFeeKey { claim_type: claim_type.clone() })
+            .unwrap_or(0)
+    }
+
+    pub fn set_claim_type_fee(env: &Env, claim_type: &String, fee: i128) {
+        let key = ClaimTypeFeeKey { claim_type: claim_type.clone() };
+        if fee == 0 {
+            env.storage().persistent().remove(&key);
+            return;
+        }
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, &fee);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
+    pub fn get_issuer_owed(env: &Env, issuer: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&IssuerOwedKey { issuer: issuer.clone() })
+            .unwrap_or(0)
+    }
+
+    pub fn set_issuer_owed(env: &Env, issuer: &Address, owed: i128) {
+        let key = IssuerOwedKey { issuer: issuer.clone() };
+        if owed == 0 {
+            env.storage().persistent().remove(&key);
+            return;
+        }
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, &owed);
         env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
-    pub fn remove_from_whitelist(env: &Env, issuer: &Address, subject: &Address) {
-        env.storage().persistent().remove(&StorageKey::IssuerWhitelist(issuer.clone(), subject.clone()));
+    pub fn is_revocation_acknowledged(env: &Env, attestation_id: &String) -> bool {
+        env.storage()
+            .persistent()
+            .has(&RevocationAckKey { attestation_id: attestation_id.clone() })
     }
 
-    pub fn is_subject_whitelisted(env: &Env, issuer: &Address, subject: &Address) -> bool {
-        Self::is_whitelisted(env, issuer, subject)
+    pub fn set_revocation_acknowledged(env: &Env, attestation_id: &String) {
+        let key = RevocationAckKey { attestation_id: attestation_id.clone() };
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
-    pub fn add_subject_to_whitelist(env: &Env, issuer: &Address, subject: &Address) {
-        Self::add_to_whitelist(env, issuer, subject);
+    pub fn get_issuer_expiry(env: &Env, issuer: &Address) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&IssuerExpiryKey { issuer: issuer.clone() })
     }
 
-    pub fn remove_subject_from_whitelist(env: &Env, issuer: &Address, subject: &Address) {
-        Self::remove_from_whitelist(env, issuer, subject);
+    pub fn set_issuer_expiry(env: &Env, issuer: &Address, expires_at: Option<u64>) {
+        let key = IssuerExpiryKey { issuer: issuer.clone() };
+        match expires_at {
+            Some(ts) => {
+                let ttl = get_ttl_lifetime(env);
+                env.storage().persistent().set(&key, &ts);
+                env.storage().persistent().extend_ttl(&key, ttl, ttl);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
     }
 
-    pub fn set_paused(env: &Env, paused: bool) {
-        env.storage().instance().set(&StorageKey::Paused, &paused);
-        env.storage().instance().extend_ttl(DEFAULT_INSTANCE_LIFETIME, DEFAULT_INSTANCE_LIFETIME);
+    pub fn get_issuer_weight(env: &Env, issuer: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&IssuerWeightKey { issuer: issuer.clone() })
+            .unwrap_or(0)
     }
 
-    pub fn is_paused(env: &Env) -> bool {
-        env.storage().instance().get(&StorageKey::Paused).unwrap_or(false)
+    pub fn set_issuer_weight(env: &Env, issuer: &Address, weight: u32) {
+        let key = IssuerWeightKey { issuer: issuer.clone() };
+        if weight == 0 {
+            env.storage().persistent().remove(&key);
+            return;
+        }
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, &weight);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
-    pub fn get_global_stats(env: &Env) -> GlobalStats {
-        env.storage().instance()
-            .get(&StorageKey::GlobalStats)
-            .unwrap_or(GlobalStats { total_attestations: 0, total_revocations: 0, total_issuers: 0 })
+    pub fn is_singleton_claim_type(env: &Env, claim_type: &String) -> bool {
+        env.storage()
+            .persistent()
+            .has(&SingletonClaimTypeKey { claim_type: claim_type.clone() })
     }
 
-    pub fn set_global_stats(env: &Env, stats: &GlobalStats) {
-        Self::set_global_stats_raw(env, stats)
+    pub fn set_singleton_claim_type(env: &Env, claim_type: &String, singleton: bool) {
+        let key = SingletonClaimTypeKey { claim_type: claim_type.clone() };
+        if singleton {
+            let ttl = get_ttl_lifetime(env);
+            env.storage().persistent().set(&key, &true);
+            env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
     }
 
-    pub fn get_global_stats_raw(env: &Env) -> GlobalStats {
-        Self::get_global_stats(env)
+    pub fn get_auto_extend(env: &Env, claim_type: &String) -> Option<AutoExtendConfig> {
+        env.storage()
+            .persistent()
+            .get(&AutoExtendKey { claim_type: claim_type.clone() })
     }
 
-    fn set_global_stats_raw(env: &Env, stats: &GlobalStats) {
-        let ttl = get_ttl_lifetime(env);
-        env.storage().instance().set(&StorageKey::GlobalStats, stats);
-        env.storage().instance().extend_ttl(ttl, ttl);
+    pub fn set_auto_extend(env: &Env, claim_type: &String, config: &Option<AutoExtendConfig>) {
+        let key = AutoExtendKey { claim_type: claim_type.clone() };
+        match config {
+            Some(config) => {
+                let ttl = get_ttl_lifetime(env);
+                env.storage().persistent().set(&key, config);
+                env.storage().persistent().extend_ttl(&key, ttl, ttl);
+            }
+            None => env.storage().persistent().remove(&key),
+        }
     }
 
-    /// Increment `total_attestations` by `count`.
-    pub fn increment_total_attestations(env: &Env, count: u64) {
-        let mut stats = Self::get_global_stats(env);
-        stats.total_attestations = stats.total_attestations.saturating_add(count);
-        Self::set_global_stats(env, &stats);
+    pub fn has_auto_extend_consent(env: &Env, issuer: &Address, claim_type: &String) -> bool {
+        env.storage().persistent().has(&AutoExtendConsentKey {
+            issuer: issuer.clone(),
+            claim_type: claim_type.clone(),
+        })
     }
 
-    pub fn increment_total_revocations(env: &Env, by: u64) {
-        let mut s = Self::get_global_stats_raw(env);
-        s.total_revocations = s.total_revocations.saturating_add(by);
-        Self::set_global_stats_raw(env, &s);
+    pub fn set_auto_extend_consent(env: &Env, issuer: &Address, claim_type: &String, allowed: bool) {
+        let key = AutoExtendConsentKey { issuer: issuer.clone(), claim_type: claim_type.clone() };
+        if allowed {
+            let ttl = get_ttl_lifetime(env);
+            env.storage().persistent().set(&key, &true);
+            env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
     }
 
-    pub fn increment_total_issuers(env: &Env) {
-        let mut s = Self::get_global_stats_raw(env);
-        s.total_issuers = s.total_issuers.saturating_add(1);
-        Self::set_global_stats_raw(env, &s);
+    pub fn get_auto_extend_count(env: &Env, attestation_id: &String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&AutoExtendCountKey { attestation_id: attestation_id.clone() })
+            .unwrap_or(0)
     }
 
-    pub fn decrement_total_issuers(env: &Env) {
-        let mut s = Self::get_global_stats_raw(env);
-        s.total_issuers = s.total_issuers.saturating_sub(1);
-        Self::set_global_stats_raw(env, &s);
+    pub fn set_auto_extend_count(env: &Env, attestation_id: &String, count: u32) {
+        let key = AutoExtendCountKey { attestation_id: attestation_id.clone() };
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, &count);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
-    pub fn get_issuer_stats(env: &Env, issuer: &Address) -> IssuerStats {
-        env.storage().persistent().get(&StorageKey::IssuerStats(issuer.clone()))
-            .unwrap_or(IssuerStats { total_issued: 0 })
+    pub fn get_issuer_signing_key(env: &Env, issuer: &Address) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&IssuerSigningKey { issuer: issuer.clone() })
     }
 
-    pub fn set_issuer_stats(env: &Env, issuer: &Address, stats: &IssuerStats) {
-        let key = StorageKey::IssuerStats(issuer.clone());
+    pub fn set_issuer_signing_key(env: &Env, issuer: &Address, public_key: &BytesN<32>) {
+        let key = IssuerSigningKey { issuer: issuer.clone() };
         let ttl = get_ttl_lifetime(env);
-        env.storage().persistent().set(&key, stats);
+        env.storage().persistent().set(&key, public_key);
         env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
-    pub fn set_issuer_tier(env: &Env, issuer: &Address, tier: &IssuerTier) {
-        let key = StorageKey::IssuerTier(issuer.clone());
+    pub fn get_signing_nonce(env: &Env, issuer: &Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&SigningNonceKey { issuer: issuer.clone() })
+            .unwrap_or(0)
+    }
+
+    pub fn set_signing_nonce(env: &Env, issuer: &Address, nonce: u64) {
+        let key = SigningNonceKey { issuer: issuer.clone() };
         let ttl = get_ttl_lifetime(env);
-        env.storage().persistent().set(&key, tier);
+        env.storage().persistent().set(&key, &nonce);
         env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
-    pub fn get_issuer_tier(env: &Env, issuer: &Address) -> Option<IssuerTier> {
-        env.storage().persistent().get(&StorageKey::IssuerTier(issuer.clone()))
+    pub fn get_subject_revoked(env: &Env, subject: &Address) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&SubjectRevokedKey { subject: subject.clone() })
+            .unwrap_or(Vec::new(env))
     }
 
-    pub fn get_limits(env: &Env) -> StorageLimits {
-        env.storage().instance().get(&StorageKey::StorageLimits).unwrap_or_default()
+    pub fn get_revocation_subscribers(env: &Env, subject: &Address, claim_type: &String) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&RevocationSubscribersKey { subject: subject.clone(), claim_type: claim_type.clone() })
+            .unwrap_or(Vec::new(env))
     }
 
-    pub fn set_limits(env: &Env, limits: &StorageLimits) {
+    pub fn set_revocation_subscribers(env: &Env, subject: &Address, claim_type: &String, subscribers: &Vec<Address>) {
+        let key = RevocationSubscribersKey { subject: subject.clone(), claim_type: claim_type.clone() };
+        if subscribers.is_empty() {
+            env.storage().persistent().remove(&key);
+            return;
+        }
         let ttl = get_ttl_lifetime(env);
-        env.storage().instance().set(&StorageKey::StorageLimits, limits);
-        env.storage().instance().extend_ttl(ttl, ttl);
+        env.storage().persistent().set(&key, subscribers);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
-    pub fn get_rate_limit_config(env: &Env) -> Option<RateLimitConfig> {
-        env.storage().instance().get(&StorageKey::RateLimitConfig)
+    pub fn clear_subject_revoked(env: &Env, subject: &Address) {
+        env.storage().persistent().remove(&SubjectRevokedKey { subject: subject.clone() });
     }
 
-    pub fn set_rate_limit_config(env: &Env, config: &RateLimitConfig) {
+    pub fn set_subject_revoked(env: &Env, subject: &Address, attestation_ids: &Vec<String>) {
+        if attestation_ids.is_empty() {
+            Self::clear_subject_revoked(env, subject);
+            return;
+        }
+        let key = SubjectRevokedKey { subject: subject.clone() };
         let ttl = get_ttl_lifetime(env);
-        env.storage().instance().set(&StorageKey::RateLimitConfig, config);
-        env.storage().instance().extend_ttl(ttl, ttl);
+        env.storage().persistent().set(&key, attestation_ids);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
-    /// Get the per-claim-type rate limit override for a claim type, or None if not set.
-    pub fn get_claim_type_rate_limit(env: &Env, claim_type: &String) -> Option<u64> {
-        env.storage()
-            .instance()
-            .get(&StorageKey::ClaimTypeRateLimit(claim_type.clone()))
+    /// Record a revoked id for `subject`, keeping the newest
+    /// [`MAX_SUBJECT_REVOKED`] entries.
+    pub fn add_subject_revoked(env: &Env, subject: &Address, attestation_id: &String) {
+        let mut list = Self::get_subject_revoked(env, subject);
+        if list.contains(attestation_id) {
+            return;
+        }
+        while list.len() >= MAX_SUBJECT_REVOKED {
+            list.pop_front();
+        }
+        list.push_back(attestation_id.clone());
+        Self::set_subject_revoked(env, subject, &list);
     }
 
-    /// Set a per-claim-type rate limit override.
-    pub fn set_claim_type_rate_limit(env: &Env, claim_type: &String, interval_secs: u64) {
-        let ttl = get_ttl_lifetime(env);
+    pub fn remove_subject_revoked(env: &Env, subject: &Address, attestation_id: &String) {
+        let list = Self::get_subject_revoked(env, subject);
+        if let Some(index) = list.first_index_of(attestation_id) {
+            let mut list = list;
+            list.remove(index);
+            Self::set_subject_revoked(env, subject, &list);
+        }
+    }
+
+    pub fn get_revocation_cooldown(env: &Env, claim_type: &String) -> u64 {
         env.storage()
-            .instance()
-            .set(&StorageKey::ClaimTypeRateLimit(claim_type.clone()), &interval_secs);
-        env.storage().instance().extend_ttl(ttl, ttl);
+            .persistent()
+            .get(&RevocationCooldownKey { claim_type: claim_type.clone() })
+            .unwrap_or(0)
     }
 
-    pub fn get_last_issuance_time(env: &Env, issuer: &Address) -> Option<u64> {
-        env.storage().persistent().get(&StorageKey::LastIssuanceTime(issuer.clone()))
+    pub fn set_revocation_cooldown(env: &Env, claim_type: &String, seconds: u64) {
+        let key = RevocationCooldownKey { claim_type: claim_type.clone() };
+        if seconds == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            let ttl = get_ttl_lifetime(env);
+            env.storage().persistent().set(&key, &seconds);
+            env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        }
     }
 
-    pub fn set_last_issuance_time(env: &Env, issuer: &Address, timestamp: u64) {
-        let key = StorageKey::LastIssuanceTime(issuer.clone());
+    pub fn get_last_revocation(env: &Env, issuer: &Address, subject: &Address, claim_type: &String) -> Option<u64> {
+        env.storage().persistent().get(&LastRevocationKey {
+            issuer: issuer.clone(),
+            subject: subject.clone(),
+            claim_type: claim_type.clone(),
+        })
+    }
+
+    pub fn set_last_revocation(env: &Env, issuer: &Address, subject: &Address, claim_type: &String, timestamp: u64) {
+        let key = LastRevocationKey {
+            issuer: issuer.clone(),
+            subject: subject.clone(),
+            claim_type: claim_type.clone(),
+        };
         let ttl = get_ttl_lifetime(env);
         env.storage().persistent().set(&key, &timestamp);
         env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
+    pub fn get_issuer_history(env: &Env, issuer: &Address) -> Vec<(u64, bool)> {
+        env.storage()
+            .persistent()
+            .get(&IssuerHistoryKey { issuer: issuer.clone() })
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn record_issuer_transition(env: &Env, issuer: &Address, authorized: bool) {
+        let key = IssuerHistoryKey { issuer: issuer.clone() };
+        let ttl = get_ttl_lifetime(env);
+        let mut history = Self::get_issuer_history(env, issuer);
+        history.push_back((env.ledger().timestamp(), authorized));
+        while history.len() > MAX_ISSUER_HISTORY {
+            history.pop_front();
+        }
+        env.storage().persistent().set(&key, &history);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
     pub fn get_audit_log(env: &Env, attestation_id: &String) -> Vec<AuditEntry> {
         env.storage().persistent().get(&StorageKey::AuditLog(attestation_id.clone())).unwrap_or(Vec::new(env))
     }
@@ -781,6 +1588,86 @@ impl Storage {
 
     // ── Pending admin transfer ────────────────────────────────────────────────
 
+    pub fn is_dev_mode(env: &Env) -> bool {
+        env.storage().instance().get(&SingletonKey::DevMode).unwrap_or(false)
+    }
+
+    pub fn set_dev_mode(env: &Env) {
+        env.storage().instance().set(&SingletonKey::DevMode, &true);
+        env.storage().instance().extend_ttl(DEFAULT_INSTANCE_LIFETIME, DEFAULT_INSTANCE_LIFETIME);
+    }
+
+    pub fn is_normalize_claim_types(env: &Env) -> bool {
+        env.storage().instance().get(&SingletonKey::NormalizeClaimTypes).unwrap_or(false)
+    }
+
+    pub fn set_normalize_claim_types(env: &Env) {
+        env.storage().instance().set(&SingletonKey::NormalizeClaimTypes, &true);
+        env.storage().instance().extend_ttl(DEFAULT_INSTANCE_LIFETIME, DEFAULT_INSTANCE_LIFETIME);
+    }
+
+    pub fn is_issuer_registry_frozen(env: &Env) -> bool {
+        env.storage().instance().get(&SingletonKey::IssuerRegistryFrozen).unwrap_or(false)
+    }
+
+    pub fn set_issuer_registry_frozen(env: &Env, frozen: bool) {
+        if frozen {
+            env.storage().instance().set(&SingletonKey::IssuerRegistryFrozen, &true);
+        } else {
+            env.storage().instance().remove(&SingletonKey::IssuerRegistryFrozen);
+        }
+        env.storage().instance().extend_ttl(DEFAULT_INSTANCE_LIFETIME, DEFAULT_INSTANCE_LIFETIME);
+    }
+
+    pub fn get_operation_timelock(env: &Env) -> u64 {
+        env.storage().instance().get(&SingletonKey::OperationTimelock).unwrap_or(0)
+    }
+
+    pub fn set_operation_timelock(env: &Env, seconds: u64) {
+        if seconds > 0 {
+            env.storage().instance().set(&SingletonKey::OperationTimelock, &seconds);
+        } else {
+            env.storage().instance().remove(&SingletonKey::OperationTimelock);
+        }
+        env.storage().instance().extend_ttl(DEFAULT_INSTANCE_LIFETIME, DEFAULT_INSTANCE_LIFETIME);
+    }
+
+    pub fn get_queued_operation(env: &Env, op_hash: &BytesN<32>) -> Option<u64> {
+        env.storage().persistent().get(&QueuedOperationKey { op_hash: op_hash.clone() })
+    }
+
+    pub fn set_queued_operation(env: &Env, op_hash: &BytesN<32>, ready_at: u64) {
+        let key = QueuedOperationKey { op_hash: op_hash.clone() };
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, &ready_at);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
+    pub fn remove_queued_operation(env: &Env, op_hash: &BytesN<32>) {
+        env.storage().persistent().remove(&QueuedOperationKey { op_hash: op_hash.clone() });
+    }
+
+    pub fn get_backup_admin(env: &Env) -> Option<BackupAdmin> {
+        env.storage().instance().get(&StorageKey::BackupAdmin)
+    }
+
+    pub fn set_backup_admin(env: &Env, backup: &BackupAdmin) {
+        env.storage().instance().set(&StorageKey::BackupAdmin, backup);
+        env.storage().instance().extend_ttl(DEFAULT_INSTANCE_LIFETIME, DEFAULT_INSTANCE_LIFETIME);
+    }
+
+    pub fn remove_backup_admin(env: &Env) {
+        env.storage().instance().remove(&StorageKey::BackupAdmin);
+    }
+
+    /// Reset the backup-admin inactivity timer. No-op when no backup is configured.
+    pub fn record_admin_action(env: &Env) {
+        if let Some(mut backup) = Self::get_backup_admin(env) {
+            backup.last_admin_action = env.ledger().timestamp();
+            Self::set_backup_admin(env, &backup);
+        }
+    }
+
     pub fn set_pending_admin_transfer(env: &Env, transfer: &PendingAdminTransfer) {
         let ttl = get_ttl_lifetime(env);
         env.storage().instance().set(&StorageKey::PendingAdminTransfer, transfer);
@@ -844,20 +1731,67 @@ impl Storage {
         env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
+    pub fn set_valid_attestations(env: &Env, subject: &Address, attestation_ids: &Vec<String>) {
+        let key = StorageKey::ValidAttestations(subject.clone());
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, attestation_ids);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
     pub fn remove_valid_attestation(env: &Env, subject: &Address, attestation_id: &String) {
         let key = StorageKey::ValidAttestations(subject.clone());
         let ttl = get_ttl_lifetime(env);
-        let existing = Self::get_valid_attestations(env, subject);
-        let mut updated = Vec::new(env);
-        for id in existing.iter() {
-            if &id != attestation_id {
-                updated.push_back(id);
-            }
+        let mut list = Self::get_valid_attestations(env, subject);
+        if remove_preserving_order(&mut list, attestation_id) {
+            env.storage().persistent().set(&key, &list);
+            env.storage().persistent().extend_ttl(&key, ttl, ttl);
         }
-        env.storage().persistent().set(&key, &updated);
+    }
+
+    // ── Subject + claim type index ────────────────────────────────────────────
+
+    pub fn get_subject_claim_type_ids(env: &Env, subject: &Address, claim_type: &String) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&SubjectClaimTypeKey { subject: subject.clone(), claim_type: claim_type.clone() })
+            .unwrap_or(Vec::new(env))
+    }
+
+    pub fn add_subject_claim_type_id(env: &Env, subject: &Address, claim_type: &String, attestation_id: &String) {
+        let key = SubjectClaimTypeKey { subject: subject.clone(), claim_type: claim_type.clone() };
+        let ttl = get_ttl_lifetime(env);
+        let mut list = Self::get_subject_claim_type_ids(env, subject, claim_type);
+        list.push_back(attestation_id.clone());
+        env.storage().persistent().set(&key, &list);
+        env.storage().persistent().extend_ttl(&key, ttl, ttl);
+    }
+
+    pub fn set_subject_claim_type_ids(env: &Env, subject: &Address, claim_type: &String, attestation_ids: &Vec<String>) {
+        let key = SubjectClaimTypeKey { subject: subject.clone(), claim_type: claim_type.clone() };
+        if attestation_ids.is_empty() {
+            env.storage().persistent().remove(&key);
+            return;
+        }
+        let ttl = get_ttl_lifetime(env);
+        env.storage().persistent().set(&key, attestation_ids);
         env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
+    pub fn remove_subject_claim_type_id(env: &Env, subject: &Address, claim_type: &String, attestation_id: &String) {
+        let key = SubjectClaimTypeKey { subject: subject.clone(), claim_type: claim_type.clone() };
+        let mut list = Self::get_subject_claim_type_ids(env, subject, claim_type);
+        if !remove_preserving_order(&mut list, attestation_id) {
+            return;
+        }
+        if list.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            let ttl = get_ttl_lifetime(env);
+            env.storage().persistent().set(&key, &list);
+            env.storage().persistent().extend_ttl(&key, ttl, ttl);
+        }
+    }
+
     // ── Delegator index ───────────────────────────────────────────────────────
 
     pub fn get_delegator_index(env: &Env, delegator: &Address) -> Vec<(Address, String)> {
@@ -878,23 +1812,24 @@ impl Storage {
 
     // ── Claim type counts ─────────────────────────────────────────────────────
 
-    pub fn get_claim_type_count(env: &Env, claim_type: &String) -> u64 {
+    /// Number of live (non-revoked, non-deleted) attestations of `claim_type`.
+    pub fn get_claim_type_count(env: &Env, claim_type: &String) -> u32 {
         env.storage()
             .persistent()
-            .get(&StorageKey::ClaimTypeCount(claim_type.clone()))
-            .unwrap_or(0u64)
+            .get(&ClaimTypeCountKey { claim_type: claim_type.clone() })
+            .unwrap_or(0u32)
     }
 
-    pub fn increment_claim_type_count(env: &Env, claim_type: &String) {
-        let key = StorageKey::ClaimTypeCount(claim_type.clone());
+    pub fn increment_claim_type_count(env: &Env, claim_type: &String, by: u32) {
+        let key = ClaimTypeCountKey { claim_type: claim_type.clone() };
         let ttl = get_ttl_lifetime(env);
         let current = Self::get_claim_type_count(env, claim_type);
-        env.storage().persistent().set(&key, &current.saturating_add(1));
+        env.storage().persistent().set(&key, &current.saturating_add(by));
         env.storage().persistent().extend_ttl(&key, ttl, ttl);
     }
 
     pub fn decrement_claim_type_count(env: &Env, claim_type: &String) {
-        let key = StorageKey::ClaimTypeCount(claim_type.clone());
+        let key = ClaimTypeCountKey { claim_type: claim_type.clone() };
         let ttl = get_ttl_lifetime(env);
         let current = Self::get_claim_type_count(env, claim_type);
         env.storage().persistent().set(&key, &current.saturating_sub(1));
@@ -1019,6 +1954,13 @@ impl Storage {
         }
     }
 
+    /// Extend an attestation record to the network's maximum TTL.
+    pub fn extend_attestation_ttl_to_max(env: &Env, attestation_id: &String) {
+        let key = StorageKey::Attestation(attestation_id.clone());
+        let max_ttl = env.storage().max_ttl();
+        env.storage().persistent().extend_ttl(&key, max_ttl, max_ttl);
+    }
+
     /// Retrieve global contract statistics, returning zeroed defaults if not yet set.
     pub fn get_global_stats(env: &Env) -> GlobalStats {
         env.storage()
@@ -1135,13 +2077,28 @@ impl Storage {
     }
 }
 
+/// Remove the first occurrence of `id` from `list`, shifting later entries
+/// down so the relative order of the remaining ids is unchanged. Index
+/// removal must never swap-remove: pagination relies on stable positions.
+///
+/// Returns `true` if an entry was removed.
+pub fn remove_preserving_order(list: &mut Vec<String>, id: &String) -> bool {
+    match list.first_index_of(id) {
+        Some(index) => {
+            list.remove(index);
+            true
+        }
+        None => false,
+    }
+}
+
 pub fn paginate(env: &Env, list: &Vec<String>, start: u32, limit: u32) -> Vec<String> {
     let mut result = Vec::new(env);
     let len = list.len();
     if start >= len {
         return result;
     }
-    let end = (start + limit).min(len);
+    let end = start.saturating_add(limit).min(len);
     for i in start..end {
         if let Some(item) = list.get(i) {
             result.push_back(item);
@@ -1324,26 +2281,27 @@ impl ChunkedIndex {
         Self::write_issuer_chunks(env, issuer, &existing);
     }
 
+    /// Overwrite the issuer's chunked index with `ids`.
+    pub fn set_issuer_all(env: &Env, issuer: &Address, ids: &Vec<String>) {
+        Self::write_issuer_chunks(env, issuer, ids);
+    }
+
+    pub fn set_subject_all(env: &Env, subject: &Address, ids: &Vec<String>) {
+        Self::write_subject_chunks(env, subject, ids);
+    }
+
     pub fn remove_subject(env: &Env, subject: &Address, id: &String) {
-        let ids = Self::get_subject_ids(env, subject);
-        let mut updated = Vec::new(env);
-        for item in ids.iter() {
-            if &item != id {
-                updated.push_back(item.clone());
-            }
+        let mut ids = Self::get_subject_ids(env, subject);
+        if remove_preserving_order(&mut ids, id) {
+            Self::write_subject_chunks(env, subject, &ids);
         }
-        Self::write_subject_chunks(env, subject, &updated);
     }
 
     pub fn remove_issuer(env: &Env, issuer: &Address, id: &String) {
-        let ids = Self::get_issuer_ids(env, issuer);
-        let mut updated = Vec::new(env);
-        for item in ids.iter() {
-            if &item != id {
-                updated.push_back(item.clone());
-            }
+        let mut ids = Self::get_issuer_ids(env, issuer);
+        if remove_preserving_order(&mut ids, id) {
+            Self::write_issuer_chunks(env, issuer, &ids);
         }
-        Self::write_issuer_chunks(env, issuer, &updated);
     }
 
     pub fn subject_count(env: &Env, subject: &Address) -> u32 {
@@ -1364,6 +2322,13 @@ impl ChunkedIndex {
         paginate(env, &ids, start, limit)
     }
 
+    /// Return the id at logical position `index` in the subject's index,
+    /// loading only the chunk that contains it.
+    pub fn get_subject_at(env: &Env, subject: &Address, index: u32) -> Option<String> {
+        let chunk = Self::get_subject_chunk(env, subject, index / CHUNKED_INDEX_CHUNK_SIZE);
+        chunk.get(index % CHUNKED_INDEX_CHUNK_SIZE)
+    }
+
     pub fn get_subject_all(env: &Env, subject: &Address) -> Vec<String> {
         Self::get_subject_ids(env, subject)
     }
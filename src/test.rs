@@ -2232,8 +2232,7 @@ fn test_audit_log_renew_appends_entry() {
     let claim_type = String::from_str(&env, "KYC_PASSED");
 
     let id = client.create_attestation(&issuer, &subject, &claim_type, &None, &None, &None);
-    let new_exp = env.ledger().timestamp() + 86_400 * 30;
-    client.renew_attestation(&issuer, &id, &Some(new_exp));
+    client.renew_attestation(&issuer, &id, &(86_400 * 30));
     let log = client.get_audit_log(&id);
 
     assert_eq!(log.len(), 2);
@@ -2274,8 +2273,7 @@ fn test_audit_log_is_append_only_across_multiple_actions() {
     let claim_type = String::from_str(&env, "KYC_PASSED");
 
     let id = client.create_attestation(&issuer, &subject, &claim_type, &None, &None, &None);
-    let new_exp = env.ledger().timestamp() + 86_400 * 30;
-    client.renew_attestation(&issuer, &id, &Some(new_exp));
+    client.renew_attestation(&issuer, &id, &(86_400 * 30));
     client.revoke_attestation(&issuer, &id, &None);
     let log = client.get_audit_log(&id);
 
@@ -2885,8 +2883,7 @@ mod ttl_tests {
             l.timestamp += 10_000;
         });
 
-        let new_expiry: Option<u64> = Some(env.ledger().timestamp() + 100_000);
-        client.renew_attestation(&issuer, &id, &new_expiry);
+        client.renew_attestation(&issuer, &id, &100_000);
 
         let ttl = env.as_contract(&contract_id, || {
             env.storage()
@@ -7561,6 +7558,50 @@ mod claim_type_requirement_tests {
         let result = client.try_create_attestations_batch(&issuer, &subjects, &unregistered, &None);
         assert_eq!(result, Err(Ok(types::Error::InvalidClaimType)));
     }
+
+    #[test]
+    fn test_import_bridge_and_multisig_respect_claim_type_requirement() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, subject, client) = setup(&env);
+        let cosigner = Address::generate(&env);
+        let bridge = Address::generate(&env);
+        client.register_issuer(&admin, &cosigner);
+        client.register_bridge(&admin, &bridge);
+        let registered = String::from_str(&env, "REGISTERED");
+        let unregistered = String::from_str(&env, "UNREGISTERED");
+        client.register_claim_type(&admin, &registered, &String::from_str(&env, "Registered"));
+        let required = Vec::from_array(&env, [issuer.clone(), cosigner.clone()]);
+        let proposal_id = client.propose_attestation(&issuer, &subject, &registered, &required, &2);
+        client.set_registered_claim_type(&admin, &true);
+
+        env.ledger().with_mut(|l| l.timestamp = 5_000);
+        assert_eq!(
+            client.try_import_attestation(&admin, &issuer, &subject, &unregistered, &1_000, &None),
+            Err(Ok(types::Error::InvalidClaimType))
+        );
+        assert_eq!(
+            client.try_bridge_attestation(
+                &bridge,
+                &subject,
+                &unregistered,
+                &String::from_str(&env, "ethereum"),
+                &String::from_str(&env, "0xabc"),
+            ),
+            Err(Ok(types::Error::InvalidClaimType))
+        );
+        assert_eq!(
+            client.try_propose_attestation(&issuer, &subject, &unregistered, &required, &2),
+            Err(Ok(types::Error::InvalidClaimType))
+        );
+
+        // Unregistering while signatures are collected blocks finalization.
+        client.unregister_claim_type(&admin, &registered);
+        assert_eq!(
+            client.try_cosign_attestation(&cosigner, &proposal_id),
+            Err(Ok(types::Error::InvalidClaimType))
+        );
+    }
 }
 
 // ============================================================================
@@ -8400,3 +8441,5567 @@ mod timelock_tests {
         assert_eq!(result, Err(Ok(Error::CouncilProposalExecuted)));
     }
 }
+
+mod renewal_tests {
+    use super::*;
+
+    #[test]
+    fn test_renew_extends_soon_to_expire_attestation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().set_timestamp(1_000);
+        let id = client.create_attestation(&issuer, &subject, &claim, &Some(1_100), &None, &None);
+
+        env.ledger().set_timestamp(1_050);
+        client.renew_attestation(&issuer, &id, &86_400);
+
+        assert_eq!(client.get_attestation(&id).expiration, Some(1_100 + 86_400));
+
+        env.ledger().set_timestamp(1_200);
+        assert!(client.has_valid_claim(&subject, &claim));
+    }
+
+    #[test]
+    fn test_renew_non_expiring_attestation_extends_from_now() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().set_timestamp(5_000);
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        client.renew_attestation(&issuer, &id, &3_600);
+
+        assert_eq!(client.get_attestation(&id).expiration, Some(5_000 + 3_600));
+    }
+
+    #[test]
+    fn test_renew_emits_renewed_event_with_new_expiration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().set_timestamp(1_000);
+        let id = client.create_attestation(&issuer, &subject, &claim, &Some(2_000), &None, &None);
+        client.renew_attestation(&issuer, &id, &500);
+
+        let events = env.events().all();
+        let (_, _, data) = events.last().unwrap();
+        let (event_id, new_exp): (String, Option<u64>) =
+            soroban_sdk::TryFromVal::try_from_val(&env, &data).unwrap();
+        assert_eq!(event_id, id);
+        assert_eq!(new_exp, Some(2_500));
+    }
+
+    #[test]
+    fn test_renew_revoked_attestation_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+
+        assert_eq!(
+            client.try_renew_attestation(&issuer, &id, &3_600),
+            Err(Ok(Error::AlreadyRevoked))
+        );
+    }
+
+    #[test]
+    fn test_renew_by_other_issuer_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let other = Address::generate(&env);
+        client.register_issuer(&admin, &other);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        assert_eq!(
+            client.try_renew_attestation(&other, &id, &3_600),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+}
+
+mod zero_timestamp_tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_expiration_rejected_on_zero_timestamp_ledger() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().set_timestamp(0);
+        assert_eq!(
+            client.try_create_attestation(&issuer, &subject, &claim, &Some(0), &None, &None),
+            Err(Ok(Error::InvalidExpiration))
+        );
+    }
+
+    #[test]
+    fn test_zero_timestamp_ledger_with_and_without_expiration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let permanent = String::from_str(&env, "KYC_PASSED");
+        let expiring = String::from_str(&env, "AML_CLEARED");
+
+        env.ledger().set_timestamp(0);
+        let permanent_id = client.create_attestation(&issuer, &subject, &permanent, &None, &None, &None);
+        let expiring_id = client.create_attestation(&issuer, &subject, &expiring, &Some(100), &None, &None);
+
+        assert_eq!(client.get_attestation_status(&permanent_id), AttestationStatus::Valid);
+        assert_eq!(client.get_attestation_status(&expiring_id), AttestationStatus::Valid);
+        assert!(client.has_valid_claim(&subject, &permanent));
+        assert!(client.has_valid_claim(&subject, &expiring));
+
+        env.ledger().set_timestamp(100);
+        assert_eq!(client.get_attestation_status(&expiring_id), AttestationStatus::Expired);
+        assert!(!client.has_valid_claim(&subject, &expiring));
+    }
+
+    #[test]
+    fn test_get_status_zero_expiration_is_never_valid() {
+        let env = Env::default();
+        let attestation = Attestation {
+            id: String::from_str(&env, "id"),
+            issuer: Address::generate(&env),
+            subject: Address::generate(&env),
+            claim_type: String::from_str(&env, "KYC_PASSED"),
+            timestamp: 0,
+            expiration: Some(0),
+            revoked: false,
+            metadata: None,
+            jurisdiction: None,
+            valid_from: None,
+            origin: AttestationOrigin::Native,
+            source_chain: None,
+            source_tx: None,
+            tags: None,
+            revocation_reason: None,
+            pinned: false,
+            expiration_locked: false,
+            superseded_by: None,
+            stake: None,
+            revocable: true,
+            score: None,
+            category: Category::Other,
+            permanent: false,
+            parent_issuer: None,
+            depends_on: None,
+            revocation_evidence: None,
+            deleted: false,
+        };
+
+        assert_eq!(attestation.get_status(0), AttestationStatus::Expired);
+    }
+}
+
+mod find_attestation_tests {
+    use super::*;
+
+    #[test]
+    fn test_find_attestation_returns_most_recent_match() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().set_timestamp(1_000);
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        env.ledger().set_timestamp(2_000);
+        let latest = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        let found = client.find_attestation(&issuer, &subject, &claim).unwrap();
+        assert_eq!(found.id, latest);
+        assert_eq!(found.timestamp, 2_000);
+    }
+
+    #[test]
+    fn test_find_attestation_requires_all_criteria_to_match() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let other_issuer = Address::generate(&env);
+        client.register_issuer(&admin, &other_issuer);
+        let subject = Address::generate(&env);
+        let other_subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC_PASSED");
+        let aml = String::from_str(&env, "AML_CLEARED");
+
+        // Each attestation matches two of the three criteria, never all three.
+        client.create_attestation(&issuer, &subject, &aml, &None, &None, &None);
+        client.create_attestation(&other_issuer, &subject, &kyc, &None, &None, &None);
+        client.create_attestation(&issuer, &other_subject, &kyc, &None, &None, &None);
+
+        assert_eq!(client.find_attestation(&issuer, &subject, &kyc), None);
+    }
+
+    #[test]
+    fn test_find_attestation_skips_revoked() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+
+        assert_eq!(client.find_attestation(&issuer, &subject, &claim), None);
+    }
+}
+
+mod revocation_evidence_tests {
+    use super::*;
+
+    #[test]
+    fn test_revocation_evidence_round_trip() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        let uri = String::from_str(&env, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+        client.revoke_attestation_with_evidence(
+            &issuer,
+            &id,
+            &Some(String::from_str(&env, "sanctions match")),
+            &Some(uri.clone()),
+        );
+
+        assert_eq!(client.get_revocation_evidence(&id), Some(uri.clone()));
+        let attestation = client.get_attestation(&id);
+        assert!(attestation.revoked);
+        assert_eq!(attestation.revocation_evidence, Some(uri.clone()));
+
+        let events = env.events().all();
+        let (_, _, data) = events.last().unwrap();
+        let (event_id, _reason, event_uri, _claim_type, _subject): (
+            String,
+            Option<String>,
+            Option<String>,
+            String,
+            Address,
+        ) = soroban_sdk::TryFromVal::try_from_val(&env, &data).unwrap();
+        assert_eq!(event_id, id);
+        assert_eq!(event_uri, Some(uri));
+    }
+
+    #[test]
+    fn test_revocation_evidence_none_without_evidence() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        client.revoke_attestation(&issuer, &id, &None);
+
+        assert_eq!(client.get_revocation_evidence(&id), None);
+    }
+
+    #[test]
+    fn test_revocation_evidence_uri_too_long_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        let long_uri = String::from_str(&env, &"a".repeat(257));
+        assert_eq!(
+            client.try_revoke_attestation_with_evidence(&issuer, &id, &None, &Some(long_uri)),
+            Err(Ok(Error::InvalidEvidenceUri))
+        );
+        assert!(!client.get_attestation(&id).revoked);
+    }
+}
+
+mod ledger_rate_limit_tests {
+    use super::*;
+
+    fn create_for_new_subject(env: &Env, client: &TrustLinkContractClient, issuer: &Address) -> Result<String, Error> {
+        let subject = Address::generate(env);
+        let claim = String::from_str(env, "KYC_PASSED");
+        match client.try_create_attestation(issuer, &subject, &claim, &None, &None, &None) {
+            Ok(Ok(id)) => Ok(id),
+            Err(Ok(err)) => Err(err),
+            _ => panic!("unexpected invocation result"),
+        }
+    }
+
+    #[test]
+    fn test_issuer_blocked_after_max_per_ledger() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        client.set_max_per_ledger(&admin, &3);
+
+        for _ in 0..3 {
+            assert!(create_for_new_subject(&env, &client, &issuer).is_ok());
+        }
+        assert_eq!(
+            create_for_new_subject(&env, &client, &issuer),
+            Err(Error::IssuerRateLimited)
+        );
+    }
+
+    #[test]
+    fn test_issuer_counter_resets_on_next_ledger() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        client.set_max_per_ledger(&admin, &2);
+
+        create_for_new_subject(&env, &client, &issuer).unwrap();
+        create_for_new_subject(&env, &client, &issuer).unwrap();
+        assert_eq!(
+            create_for_new_subject(&env, &client, &issuer),
+            Err(Error::IssuerRateLimited)
+        );
+
+        env.ledger().with_mut(|l| {
+            l.sequence_number += 1;
+            l.timestamp += 5;
+        });
+        assert!(create_for_new_subject(&env, &client, &issuer).is_ok());
+    }
+
+    #[test]
+    fn test_ledger_cap_is_per_issuer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let other = Address::generate(&env);
+        client.register_issuer(&admin, &other);
+        client.set_max_per_ledger(&admin, &1);
+
+        create_for_new_subject(&env, &client, &issuer).unwrap();
+        assert!(create_for_new_subject(&env, &client, &other).is_ok());
+    }
+
+    #[test]
+    fn test_batch_counts_against_ledger_cap() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        client.set_max_per_ledger(&admin, &2);
+
+        let mut subjects = soroban_sdk::Vec::new(&env);
+        for _ in 0..3 {
+            subjects.push_back(Address::generate(&env));
+        }
+        assert_eq!(
+            client.try_create_attestations_batch(&issuer, &subjects, &String::from_str(&env, "KYC_PASSED"), &None),
+            Err(Ok(Error::IssuerRateLimited))
+        );
+    }
+}
+
+mod cohort_expiration_tests {
+    use super::*;
+
+    #[test]
+    fn test_second_attestation_inherits_cohort_expiration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        client.set_cohort_expiration(&admin, &claim, &Some(10_000));
+
+        env.ledger().set_timestamp(1_000);
+        let first = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        env.ledger().set_timestamp(4_000);
+        let second = client.create_attestation(&issuer, &subject, &claim, &Some(999_999), &None, &None);
+
+        assert_eq!(client.get_attestation(&first).expiration, Some(11_000));
+        assert_eq!(client.get_attestation(&second).expiration, Some(11_000));
+    }
+
+    #[test]
+    fn test_cohort_is_per_subject() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let early = Address::generate(&env);
+        let late = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        client.set_cohort_expiration(&admin, &claim, &Some(10_000));
+
+        env.ledger().set_timestamp(1_000);
+        client.create_attestation(&issuer, &early, &claim, &None, &None, &None);
+        env.ledger().set_timestamp(5_000);
+        let late_id = client.create_attestation(&issuer, &late, &claim, &None, &None, &None);
+
+        assert_eq!(client.get_attestation(&late_id).expiration, Some(15_000));
+    }
+
+    #[test]
+    fn test_creation_after_cohort_window_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        client.set_cohort_expiration(&admin, &claim, &Some(1_000));
+
+        env.ledger().set_timestamp(1_000);
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        env.ledger().set_timestamp(2_500);
+
+        assert_eq!(
+            client.try_create_attestation(&issuer, &subject, &claim, &None, &None, &None),
+            Err(Ok(Error::InvalidExpiration))
+        );
+    }
+
+    #[test]
+    fn test_batch_and_request_validate_the_cohort_expiration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        client.set_cohort_expiration(&admin, &claim, &Some(1_000));
+
+        env.ledger().set_timestamp(1_000);
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        env.ledger().set_timestamp(2_500);
+
+        // The caller's expiration is in the future, but the anchored one is not.
+        let subjects = Vec::from_array(&env, [subject.clone()]);
+        assert_eq!(
+            client.try_create_attestations_batch(&issuer, &subjects, &claim, &Some(9_000)),
+            Err(Ok(Error::InvalidExpiration))
+        );
+        let request_id = client.request_attestation(&subject, &issuer, &claim);
+        assert_eq!(
+            client.try_fulfill_request(&issuer, &request_id, &Some(9_000)),
+            Err(Ok(Error::InvalidExpiration))
+        );
+    }
+
+    #[test]
+    fn test_no_cohort_keeps_requested_expiration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().set_timestamp(1_000);
+        let id = client.create_attestation(&issuer, &subject, &claim, &Some(9_000), &None, &None);
+
+        assert_eq!(client.get_attestation(&id).expiration, Some(9_000));
+    }
+}
+
+mod subject_index_at_tests {
+    use super::*;
+
+    #[test]
+    fn test_get_subject_attestation_at_matches_full_enumeration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+
+        // Span more than one chunk of the index.
+        for i in 0..60u64 {
+            env.ledger().set_timestamp(1_000 + i);
+            client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+        }
+
+        let count = client.get_subject_attestation_count(&subject);
+        assert_eq!(count, 60);
+        let all = client.get_subject_attestations(&subject, &0, &count);
+        for i in 0..count {
+            assert_eq!(client.get_subject_attestation_at(&subject, &i), all.get(i));
+        }
+    }
+
+    #[test]
+    fn test_get_subject_attestation_at_out_of_range_is_none() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+
+        assert!(client.get_subject_attestation_at(&subject, &0).is_some());
+        assert_eq!(client.get_subject_attestation_at(&subject, &1), None);
+        assert_eq!(client.get_subject_attestation_at(&Address::generate(&env), &0), None);
+    }
+}
+
+mod strict_claim_tests {
+    use super::*;
+
+    #[test]
+    fn test_removing_issuer_flips_strict_check_only() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        assert!(client.has_valid_claim_strict(&subject, &claim));
+        assert!(client.has_valid_claim(&subject, &claim));
+
+        client.remove_issuer(&admin, &issuer);
+
+        assert!(!client.has_valid_claim_strict(&subject, &claim));
+        assert!(client.has_valid_claim(&subject, &claim));
+    }
+
+    #[test]
+    fn test_strict_check_honours_remaining_issuer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let other = Address::generate(&env);
+        client.register_issuer(&admin, &other);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        client.create_attestation(&other, &subject, &claim, &None, &None, &None);
+
+        client.remove_issuer(&admin, &issuer);
+
+        assert!(client.has_valid_claim_strict(&subject, &claim));
+    }
+}
+
+mod issuer_pause_tests {
+    use super::*;
+
+    #[test]
+    fn test_paused_issuer_blocked_and_resumes_after_unpause() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        client.pause_issuer(&admin, &issuer);
+        assert!(client.is_issuer_paused(&issuer));
+        assert!(client.is_issuer(&issuer));
+        assert_eq!(
+            client.try_create_attestation(&issuer, &subject, &claim, &None, &None, &None),
+            Err(Ok(Error::IssuerPaused))
+        );
+
+        client.unpause_issuer(&admin, &issuer);
+        assert!(!client.is_issuer_paused(&issuer));
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        assert!(client.has_valid_claim(&subject, &claim));
+    }
+
+    #[test]
+    fn test_pausing_one_issuer_leaves_others_unaffected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let other = Address::generate(&env);
+        client.register_issuer(&admin, &other);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        let existing = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        client.pause_issuer(&admin, &issuer);
+
+        client.create_attestation(&other, &subject, &String::from_str(&env, "AML_CLEARED"), &None, &None, &None);
+        assert!(!client.is_paused());
+        assert!(!client.get_attestation(&existing).revoked);
+        assert!(client.has_valid_claim(&subject, &claim));
+    }
+
+    #[test]
+    fn test_pause_unregistered_issuer_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _, client) = setup(&env);
+
+        assert_eq!(
+            client.try_pause_issuer(&admin, &Address::generate(&env)),
+            Err(Ok(Error::NotFound))
+        );
+    }
+}
+
+mod idempotent_revoke_tests {
+    use super::*;
+
+    #[test]
+    fn test_double_revoke_errors_by_default() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+
+        assert!(!client.get_idempotent_revoke());
+        client.revoke_attestation(&issuer, &id, &None);
+        assert_eq!(
+            client.try_revoke_attestation(&issuer, &id, &None),
+            Err(Ok(Error::AlreadyRevoked))
+        );
+    }
+
+    #[test]
+    fn test_double_revoke_succeeds_without_event_when_idempotent() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+
+        client.set_idempotent_revoke(&admin, &true);
+        client.revoke_attestation(&issuer, &id, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+
+        // The retry invocation itself publishes nothing.
+        assert_eq!(env.events().all().len(), 0);
+        assert!(client.get_attestation(&id).revoked);
+        assert_eq!(client.get_global_stats().total_revocations, 1);
+    }
+
+    #[test]
+    fn test_idempotent_revoke_still_rejects_other_issuer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let other = Address::generate(&env);
+        client.register_issuer(&admin, &other);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+
+        client.set_idempotent_revoke(&admin, &true);
+        client.revoke_attestation(&issuer, &id, &None);
+        assert_eq!(
+            client.try_revoke_attestation(&other, &id, &None),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+}
+
+mod initialize_with_issuers_tests {
+    use super::*;
+
+    #[test]
+    fn test_initialize_with_issuers_sets_admin_and_issuers() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, client) = create_test_contract(&env);
+        let admin = Address::generate(&env);
+        let mut issuers = soroban_sdk::Vec::new(&env);
+        for _ in 0..3 {
+            issuers.push_back(Address::generate(&env));
+        }
+
+        client.initialize_with_issuers(&admin, &issuers);
+
+        assert_eq!(client.get_admin(), admin);
+        for issuer in issuers.iter() {
+            assert!(client.is_issuer(&issuer));
+        }
+        assert_eq!(client.get_global_stats().total_issuers, 3);
+    }
+
+    #[test]
+    fn test_initialize_with_issuers_double_init_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, client) = create_test_contract(&env);
+        let admin = Address::generate(&env);
+        let issuers = soroban_sdk::vec![&env, Address::generate(&env)];
+
+        client.initialize_with_issuers(&admin, &issuers);
+
+        assert_eq!(
+            client.try_initialize_with_issuers(&admin, &issuers),
+            Err(Ok(Error::AlreadyInitialized))
+        );
+        assert_eq!(
+            client.try_initialize(&admin, &None),
+            Err(Ok(Error::AlreadyInitialized))
+        );
+    }
+
+    #[test]
+    fn test_initialize_with_too_many_issuers_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, client) = create_test_contract(&env);
+        let admin = Address::generate(&env);
+        let mut issuers = soroban_sdk::Vec::new(&env);
+        for _ in 0..51 {
+            issuers.push_back(Address::generate(&env));
+        }
+
+        assert_eq!(
+            client.try_initialize_with_issuers(&admin, &issuers),
+            Err(Ok(Error::LimitExceeded))
+        );
+    }
+}
+
+mod attestation_parties_tests {
+    use super::*;
+
+    #[test]
+    fn test_get_attestation_parties_matches_created_attestation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+
+        assert_eq!(client.get_attestation_parties(&id), (issuer, subject));
+    }
+
+    #[test]
+    fn test_get_attestation_parties_missing_id() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, _, client) = setup(&env);
+
+        assert_eq!(
+            client.try_get_attestation_parties(&String::from_str(&env, "missing")),
+            Err(Ok(Error::NotFound))
+        );
+    }
+}
+
+mod transfer_all_attestations_tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_all_moves_whole_portfolio_in_pages() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, old_issuer, client) = setup(&env);
+        let new_issuer = Address::generate(&env);
+        client.register_issuer(&admin, &new_issuer);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        let mut ids = soroban_sdk::Vec::new(&env);
+        for _ in 0..5 {
+            let subject = Address::generate(&env);
+            ids.push_back(client.create_attestation(&old_issuer, &subject, &claim, &None, &None, &None));
+        }
+
+        let mut total = 0;
+        loop {
+            let moved = client.transfer_all_attestations(&admin, &old_issuer, &new_issuer, &2);
+            if moved == 0 {
+                break;
+            }
+            total += moved;
+        }
+
+        assert_eq!(total, 5);
+        assert_eq!(client.get_issuer_attestation_count(&old_issuer), 0);
+        assert_eq!(client.get_issuer_attestation_count(&new_issuer), 5);
+        for id in ids.iter() {
+            assert_eq!(client.get_attestation(&id).issuer, new_issuer);
+        }
+    }
+
+    #[test]
+    fn test_successor_can_manage_transferred_attestations() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, old_issuer, client) = setup(&env);
+        let new_issuer = Address::generate(&env);
+        client.register_issuer(&admin, &new_issuer);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&old_issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+
+        assert_eq!(client.transfer_all_attestations(&admin, &old_issuer, &new_issuer, &10), 1);
+
+        let events = env.events().all();
+        let (_, _, data) = events.last().unwrap();
+        let (to, count): (Address, u32) = soroban_sdk::TryFromVal::try_from_val(&env, &data).unwrap();
+        assert_eq!(to, new_issuer);
+        assert_eq!(count, 1);
+
+        assert_eq!(
+            client.try_revoke_attestation(&old_issuer, &id, &None),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.revoke_attestation(&new_issuer, &id, &None);
+        assert!(client.get_attestation(&id).revoked);
+    }
+
+    #[test]
+    fn test_dangling_id_is_dropped_not_fatal() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, old_issuer, client) = setup(&env);
+        let new_issuer = Address::generate(&env);
+        client.register_issuer(&admin, &new_issuer);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        let gone = client.create_attestation(&old_issuer, &Address::generate(&env), &claim, &None, &None, &None);
+        let kept = client.create_attestation(&old_issuer, &Address::generate(&env), &claim, &None, &None, &None);
+        env.as_contract(&client.address, || Storage::remove_attestation(&env, &gone));
+
+        assert_eq!(client.transfer_all_attestations(&admin, &old_issuer, &new_issuer, &10), 2);
+
+        assert_eq!(client.get_issuer_attestation_count(&old_issuer), 0);
+        assert_eq!(client.get_issuer_attestation_count(&new_issuer), 1);
+        assert_eq!(client.get_attestation(&kept).issuer, new_issuer);
+    }
+
+    #[test]
+    fn test_transfer_all_to_unregistered_issuer_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+
+        assert_eq!(
+            client.try_transfer_all_attestations(&admin, &issuer, &Address::generate(&env), &10),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+}
+
+mod has_any_valid_attestation_tests {
+    use super::*;
+
+    #[test]
+    fn test_subject_with_one_valid_claim() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+
+        assert!(client.has_any_valid_attestation(&subject));
+    }
+
+    #[test]
+    fn test_subject_with_only_expired_or_revoked_claims() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+
+        env.ledger().set_timestamp(1_000);
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &Some(2_000), &None, &None);
+        let revoked = client.create_attestation(&issuer, &subject, &String::from_str(&env, "AML_CLEARED"), &None, &None, &None);
+        client.revoke_attestation(&issuer, &revoked, &None);
+        env.ledger().set_timestamp(3_000);
+
+        assert!(!client.has_any_valid_attestation(&subject));
+    }
+
+    #[test]
+    fn test_subject_with_no_attestations() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, _, client) = setup(&env);
+
+        assert!(!client.has_any_valid_attestation(&Address::generate(&env)));
+    }
+}
+
+mod pagination_ordering_tests {
+    use super::*;
+
+    fn create_n(env: &Env, client: &TrustLinkContractClient, issuer: &Address, subject: &Address, n: u64) -> soroban_sdk::Vec<String> {
+        let mut ids = soroban_sdk::Vec::new(env);
+        for i in 0..n {
+            env.ledger().set_timestamp(1_000 + i);
+            ids.push_back(client.create_attestation(issuer, subject, &String::from_str(env, "KYC_PASSED"), &None, &None, &None));
+        }
+        ids
+    }
+
+    #[test]
+    fn test_pages_are_contiguous_and_non_overlapping() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let ids = create_n(&env, &client, &issuer, &subject, 10);
+
+        let mut collected = soroban_sdk::Vec::new(&env);
+        let mut start = 0;
+        loop {
+            let page = client.get_subject_attestations(&subject, &start, &3);
+            if page.is_empty() {
+                break;
+            }
+            collected.append(&page);
+            start += 3;
+        }
+
+        assert_eq!(collected, ids);
+    }
+
+    #[test]
+    fn test_pages_stay_contiguous_after_middle_removal() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let ids = create_n(&env, &client, &issuer, &subject, 7);
+
+        let removed = ids.get(3).unwrap();
+        client.revoke_attestation(&issuer, &removed, &None);
+
+        let mut expected = ids.clone();
+        expected.remove(3);
+
+        let first = client.get_subject_attestations(&subject, &0, &3);
+        let second = client.get_subject_attestations(&subject, &3, &3);
+        let mut collected = first.clone();
+        collected.append(&second);
+
+        assert_eq!(collected, expected);
+        for id in second.iter() {
+            assert!(!first.contains(&id));
+        }
+    }
+
+    /// Regression: removing a middle id must shift later ids, not move the
+    /// last id into the vacated slot.
+    #[test]
+    fn test_index_removal_does_not_swap_remove() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let ids = create_n(&env, &client, &issuer, &subject, 4);
+
+        client.revoke_attestation(&issuer, &ids.get(1).unwrap(), &None);
+
+        let page = client.get_subject_attestations(&subject, &0, &10);
+        assert_eq!(
+            page,
+            soroban_sdk::vec![&env, ids.get(0).unwrap(), ids.get(2).unwrap(), ids.get(3).unwrap()]
+        );
+        assert_eq!(client.get_subject_attestation_at(&subject, &1), ids.get(2));
+    }
+}
+
+mod verify_and_log_tests {
+    use super::*;
+
+    fn last_verification(env: &Env) -> (Address, Address, String, bool) {
+        let events = env.events().all();
+        let (_, topic, data) = events.last().unwrap();
+        let topic0: soroban_sdk::Symbol =
+            soroban_sdk::TryFromVal::try_from_val(env, &topic.get(0).unwrap()).unwrap();
+        assert_eq!(topic0, soroban_sdk::symbol_short!("verified"));
+        let verifier: Address =
+            soroban_sdk::TryFromVal::try_from_val(env, &topic.get(1).unwrap()).unwrap();
+        let (subject, claim_type, result): (Address, String, bool) =
+            soroban_sdk::TryFromVal::try_from_val(env, &data).unwrap();
+        (verifier, subject, claim_type, result)
+    }
+
+    #[test]
+    fn test_verify_and_log_emits_event_for_valid_claim() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let verifier = Address::generate(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        assert!(client.verify_and_log(&verifier, &subject, &claim));
+        assert_eq!(last_verification(&env), (verifier, subject, claim, true));
+    }
+
+    #[test]
+    fn test_verify_and_log_emits_event_for_invalid_claim() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, _, client) = setup(&env);
+        let verifier = Address::generate(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        assert!(!client.verify_and_log(&verifier, &subject, &claim));
+        assert_eq!(last_verification(&env), (verifier, subject, claim, false));
+    }
+}
+
+mod claim_query_tests {
+    use super::*;
+    use crate::types::ClaimQuery;
+
+    fn ty(env: &Env, claim: &str) -> ClaimQuery {
+        ClaimQuery::Type(String::from_str(env, claim))
+    }
+
+    #[test]
+    fn test_nested_and_or_policy() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_LITE"), &None, &None, &None);
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "AGE_OVER_18"), &None, &None, &None);
+
+        // (KYC_PASSED OR KYC_LITE) AND AGE_OVER_18
+        let policy = ClaimQuery::And(soroban_sdk::vec![
+            &env,
+            ClaimQuery::Or(soroban_sdk::vec![&env, ty(&env, "KYC_PASSED"), ty(&env, "KYC_LITE")]),
+            ty(&env, "AGE_OVER_18"),
+        ]);
+        assert!(client.evaluate_claim_query(&subject, &policy));
+
+        // (KYC_PASSED OR KYC_LITE) AND ACCREDITED
+        let stricter = ClaimQuery::And(soroban_sdk::vec![
+            &env,
+            ClaimQuery::Or(soroban_sdk::vec![&env, ty(&env, "KYC_PASSED"), ty(&env, "KYC_LITE")]),
+            ty(&env, "ACCREDITED"),
+        ]);
+        assert!(!client.evaluate_claim_query(&subject, &stricter));
+    }
+
+    #[test]
+    fn test_or_of_ands() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "ACCREDITED"), &None, &None, &None);
+
+        // (KYC_PASSED AND AGE_OVER_18) OR ACCREDITED
+        let policy = ClaimQuery::Or(soroban_sdk::vec![
+            &env,
+            ClaimQuery::And(soroban_sdk::vec![&env, ty(&env, "KYC_PASSED"), ty(&env, "AGE_OVER_18")]),
+            ty(&env, "ACCREDITED"),
+        ]);
+        assert!(client.evaluate_claim_query(&subject, &policy));
+        assert!(!client.evaluate_claim_query(&Address::generate(&env), &policy));
+    }
+
+    #[test]
+    fn test_query_deeper_than_limit_is_false() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+
+        let mut query = ty(&env, "KYC_PASSED");
+        for _ in 0..crate::types::MAX_CLAIM_QUERY_DEPTH - 1 {
+            query = ClaimQuery::And(soroban_sdk::vec![&env, query]);
+        }
+        assert!(client.evaluate_claim_query(&subject, &query));
+
+        let too_deep = ClaimQuery::And(soroban_sdk::vec![&env, query]);
+        assert!(!client.evaluate_claim_query(&subject, &too_deep));
+    }
+
+    #[test]
+    fn test_empty_lists_are_false() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, _, client) = setup(&env);
+        let subject = Address::generate(&env);
+
+        assert!(!client.evaluate_claim_query(&subject, &ClaimQuery::And(soroban_sdk::Vec::new(&env))));
+        assert!(!client.evaluate_claim_query(&subject, &ClaimQuery::Or(soroban_sdk::Vec::new(&env))));
+    }
+}
+
+mod can_create_attestation_tests {
+    use super::*;
+
+    #[test]
+    fn test_can_create_ok_writes_nothing() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        assert_eq!(client.try_can_create_attestation(&issuer, &subject, &claim, &None), Ok(Ok(())));
+        assert_eq!(client.get_subject_attestation_count(&subject), 0);
+        assert_eq!(client.get_global_stats().total_attestations, 0);
+    }
+
+    #[test]
+    fn test_can_create_unregistered_issuer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, _, client) = setup(&env);
+
+        assert_eq!(
+            client.try_can_create_attestation(
+                &Address::generate(&env),
+                &Address::generate(&env),
+                &String::from_str(&env, "KYC_PASSED"),
+                &None
+            ),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_can_create_invalid_claim_type() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+
+        assert_eq!(
+            client.try_can_create_attestation(&issuer, &Address::generate(&env), &String::from_str(&env, ""), &None),
+            Err(Ok(Error::InvalidClaimType))
+        );
+    }
+
+    #[test]
+    fn test_can_create_invalid_expiration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        env.ledger().set_timestamp(1_000);
+
+        assert_eq!(
+            client.try_can_create_attestation(
+                &issuer,
+                &Address::generate(&env),
+                &String::from_str(&env, "KYC_PASSED"),
+                &Some(500)
+            ),
+            Err(Ok(Error::InvalidExpiration))
+        );
+    }
+
+    #[test]
+    fn test_can_create_duplicate() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        assert_eq!(
+            client.try_can_create_attestation(&issuer, &subject, &claim, &None),
+            Err(Ok(Error::DuplicateAttestation))
+        );
+    }
+
+    #[test]
+    fn test_can_create_over_limit() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        client.set_limits(&admin, &1, &100);
+        client.create_attestation(&issuer, &Address::generate(&env), &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+
+        assert_eq!(
+            client.try_can_create_attestation(
+                &issuer,
+                &Address::generate(&env),
+                &String::from_str(&env, "KYC_PASSED"),
+                &None
+            ),
+            Err(Ok(Error::LimitExceeded))
+        );
+    }
+
+    #[test]
+    fn test_can_create_while_paused() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        client.pause(&admin);
+
+        assert_eq!(
+            client.try_can_create_attestation(
+                &issuer,
+                &Address::generate(&env),
+                &String::from_str(&env, "KYC_PASSED"),
+                &None
+            ),
+            Err(Ok(Error::ContractPaused))
+        );
+    }
+}
+
+mod dependency_tests {
+    use super::*;
+
+    #[test]
+    fn test_revoking_parent_invalidates_child_in_deps_check() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC_PASSED");
+        let accredited = String::from_str(&env, "ACCREDITED");
+
+        let parent = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        let child = client.create_dependent_attestation(&issuer, &subject, &accredited, &parent, &None, &None);
+        assert_eq!(client.get_attestation(&child).depends_on, Some(parent.clone()));
+        assert!(client.has_valid_claim_with_deps(&subject, &accredited));
+
+        client.revoke_attestation(&issuer, &parent, &None);
+
+        assert!(!client.has_valid_claim_with_deps(&subject, &accredited));
+        // The lenient check ignores dependencies.
+        assert!(client.has_valid_claim(&subject, &accredited));
+    }
+
+    #[test]
+    fn test_expired_grandparent_invalidates_chain() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+
+        env.ledger().set_timestamp(1_000);
+        let root = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &Some(2_000), &None, &None);
+        let mid = client.create_dependent_attestation(&issuer, &subject, &String::from_str(&env, "AML_CLEARED"), &root, &None, &None);
+        let leaf_claim = String::from_str(&env, "ACCREDITED");
+        client.create_dependent_attestation(&issuer, &subject, &leaf_claim, &mid, &None, &None);
+        assert!(client.has_valid_claim_with_deps(&subject, &leaf_claim));
+
+        env.ledger().set_timestamp(2_000);
+        assert!(!client.has_valid_claim_with_deps(&subject, &leaf_claim));
+    }
+
+    #[test]
+    fn test_parent_must_belong_to_same_subject() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let other = Address::generate(&env);
+        let parent = client.create_attestation(&issuer, &other, &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+
+        assert_eq!(
+            client.try_create_dependent_attestation(&issuer, &subject, &String::from_str(&env, "ACCREDITED"), &parent, &None, &None),
+            Err(Ok(Error::InvalidDependency))
+        );
+        assert_eq!(
+            client.try_create_dependent_attestation(
+                &issuer,
+                &subject,
+                &String::from_str(&env, "ACCREDITED"),
+                &String::from_str(&env, "missing"),
+                &None,
+                &None
+            ),
+            Err(Ok(Error::NotFound))
+        );
+    }
+}
+
+mod issuer_claim_types_tests {
+    use super::*;
+
+    #[test]
+    fn test_get_issuer_claim_types_deduplicates() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let types = ["KYC_PASSED", "AML_CLEARED", "ACCREDITED"];
+        for claim in types.iter() {
+            for _ in 0..2 {
+                client.create_attestation(&issuer, &Address::generate(&env), &String::from_str(&env, claim), &None, &None, &None);
+            }
+        }
+
+        let (claim_types, truncated) = client.get_issuer_claim_types(&issuer, &100);
+
+        assert!(!truncated);
+        assert_eq!(
+            claim_types,
+            soroban_sdk::vec![
+                &env,
+                String::from_str(&env, "KYC_PASSED"),
+                String::from_str(&env, "AML_CLEARED"),
+                String::from_str(&env, "ACCREDITED"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_issuer_claim_types_reports_truncation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        client.create_attestation(&issuer, &Address::generate(&env), &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+        client.create_attestation(&issuer, &Address::generate(&env), &String::from_str(&env, "AML_CLEARED"), &None, &None, &None);
+
+        let (claim_types, truncated) = client.get_issuer_claim_types(&issuer, &1);
+
+        assert!(truncated);
+        assert_eq!(claim_types, soroban_sdk::vec![&env, String::from_str(&env, "KYC_PASSED")]);
+    }
+}
+
+mod created_event_ledger_seq_tests {
+    use super::*;
+
+    #[test]
+    fn test_created_event_includes_ledger_sequence() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().with_mut(|l| {
+            l.sequence_number = 4_242;
+            l.timestamp = 1_000;
+        });
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        let events = env.events().all();
+        let (_, topics, data) = events.last().unwrap();
+        let topic0: soroban_sdk::Symbol =
+            soroban_sdk::TryFromVal::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+        assert_eq!(topic0, soroban_sdk::symbol_short!("created"));
+        let (event_id, event_issuer, event_claim, timestamp, metadata, ledger_seq): (
+            String,
+            Address,
+            String,
+            u64,
+            Option<String>,
+            u32,
+        ) = soroban_sdk::TryFromVal::try_from_val(&env, &data).unwrap();
+
+        assert_eq!(event_id, id);
+        assert_eq!(event_issuer, issuer);
+        assert_eq!(event_claim, claim);
+        assert_eq!(timestamp, 1_000);
+        assert_eq!(metadata, None);
+        assert_eq!(ledger_seq, 4_242);
+    }
+}
+
+mod revoke_by_content_tests {
+    use super::*;
+
+    #[test]
+    fn test_revoke_by_content_revokes_matching_attestation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().set_timestamp(1_000);
+        let target = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        env.ledger().set_timestamp(2_000);
+        let other = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        client.revoke_by_content(&issuer, &subject, &claim, &1_000);
+
+        assert!(client.get_attestation(&target).revoked);
+        assert!(!client.get_attestation(&other).revoked);
+    }
+
+    #[test]
+    fn test_revoke_by_mismatched_content_not_found() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().set_timestamp(1_000);
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        assert_eq!(
+            client.try_revoke_by_content(&issuer, &subject, &claim, &1_001),
+            Err(Ok(Error::NotFound))
+        );
+        assert_eq!(
+            client.try_revoke_by_content(&issuer, &subject, &String::from_str(&env, "AML_CLEARED"), &1_000),
+            Err(Ok(Error::NotFound))
+        );
+        assert!(!client.get_attestation(&id).revoked);
+    }
+}
+
+mod touch_batch_tests {
+    use super::*;
+
+    fn expired_ids(env: &Env) -> soroban_sdk::Vec<String> {
+        let mut ids = soroban_sdk::Vec::new(env);
+        for (_, topics, data) in env.events().all().iter() {
+            let topic0: soroban_sdk::Symbol =
+                soroban_sdk::TryFromVal::try_from_val(env, &topics.get(0).unwrap()).unwrap();
+            if topic0 == soroban_sdk::symbol_short!("expired") {
+                let id: String = soroban_sdk::TryFromVal::try_from_val(env, &data).unwrap();
+                ids.push_back(id);
+            }
+        }
+        ids
+    }
+
+    #[test]
+    fn test_touch_batch_emits_only_newly_expired() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().set_timestamp(1_000);
+        let short = client.create_attestation(&issuer, &Address::generate(&env), &claim, &Some(2_000), &None, &None);
+        let long = client.create_attestation(&issuer, &Address::generate(&env), &claim, &Some(10_000), &None, &None);
+        let permanent = client.create_attestation(&issuer, &Address::generate(&env), &claim, &None, &None, &None);
+        let ids = soroban_sdk::vec![&env, short.clone(), long, permanent];
+
+        let statuses = client.touch_batch(&ids);
+        assert_eq!(
+            statuses,
+            soroban_sdk::vec![&env, AttestationStatus::Valid, AttestationStatus::Valid, AttestationStatus::Valid]
+        );
+        assert!(expired_ids(&env).is_empty());
+
+        env.ledger().set_timestamp(5_000);
+        let statuses = client.touch_batch(&ids);
+        assert_eq!(
+            statuses,
+            soroban_sdk::vec![&env, AttestationStatus::Expired, AttestationStatus::Valid, AttestationStatus::Valid]
+        );
+        assert_eq!(expired_ids(&env), soroban_sdk::vec![&env, short]);
+
+        // A second touch does not re-announce the same expiry.
+        client.touch_batch(&ids);
+        assert!(expired_ids(&env).is_empty());
+    }
+
+    #[test]
+    fn test_touch_batch_too_large() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, _, client) = setup(&env);
+        let mut ids = soroban_sdk::Vec::new(&env);
+        for _ in 0..51 {
+            ids.push_back(String::from_str(&env, "id"));
+        }
+
+        assert_eq!(client.try_touch_batch(&ids), Err(Ok(Error::LimitExceeded)));
+    }
+}
+
+mod fresh_claim_tests {
+    use super::*;
+
+    const NINETY_DAYS: u64 = 90 * 86_400;
+
+    #[test]
+    fn test_old_unexpired_attestation_is_not_fresh() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().set_timestamp(1_000);
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        env.ledger().set_timestamp(1_000 + NINETY_DAYS + 1);
+
+        assert!(client.has_valid_claim(&subject, &claim));
+        assert!(!client.has_fresh_valid_claim(&subject, &claim, &NINETY_DAYS));
+    }
+
+    #[test]
+    fn test_recent_attestation_is_fresh() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().set_timestamp(1_000);
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        env.ledger().set_timestamp(1_000 + NINETY_DAYS + 1);
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        assert!(client.has_fresh_valid_claim(&subject, &claim, &NINETY_DAYS));
+    }
+
+    #[test]
+    fn test_fresh_but_expired_attestation_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        env.ledger().set_timestamp(1_000);
+        client.create_attestation(&issuer, &subject, &claim, &Some(1_100), &None, &None);
+        env.ledger().set_timestamp(1_200);
+
+        assert!(!client.has_fresh_valid_claim(&subject, &claim, &NINETY_DAYS));
+    }
+}
+
+mod pause_flags_tests {
+    use super::*;
+
+    fn flags(create: bool, revoke: bool, register: bool) -> PauseFlags {
+        PauseFlags { create, revoke, register }
+    }
+
+    #[test]
+    fn test_create_paused_revoke_allowed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        client.set_pause_flags(&admin, &flags(true, false, false));
+        assert_eq!(client.get_pause_flags(), flags(true, false, false));
+        assert!(client.is_paused());
+
+        let other = String::from_str(&env, "AML");
+        let result = client.try_create_attestation(&issuer, &subject, &other, &None, &None, &None);
+        assert_eq!(result, Err(Ok(Error::ContractPaused)));
+
+        client.revoke_attestation(&issuer, &id, &None);
+        assert!(client.get_attestation(&id).revoked);
+    }
+
+    #[test]
+    fn test_revoke_paused_create_allowed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        client.set_pause_flags(&admin, &flags(false, true, false));
+
+        let result = client.try_revoke_attestation(&issuer, &id, &None);
+        assert_eq!(result, Err(Ok(Error::ContractPaused)));
+        assert!(!client.get_attestation(&id).revoked);
+
+        let other = String::from_str(&env, "AML");
+        let new_id = client.create_attestation(&issuer, &subject, &other, &None, &None, &None);
+        assert!(client.has_valid_claim(&subject, &other));
+        assert_ne!(new_id, id);
+    }
+
+    #[test]
+    fn test_register_flag_only_blocks_registration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        client.set_pause_flags(&admin, &flags(false, false, true));
+
+        let result = client.try_register_issuer(&admin, &Address::generate(&env));
+        assert_eq!(result, Err(Ok(Error::ContractPaused)));
+    }
+
+    #[test]
+    fn test_pause_and_unpause_toggle_all_flags() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+
+        client.pause(&admin);
+        assert_eq!(client.get_pause_flags(), flags(true, true, true));
+        client.unpause(&admin);
+        assert_eq!(client.get_pause_flags(), flags(false, false, false));
+        assert!(!client.is_paused());
+    }
+
+    #[test]
+    fn test_set_pause_flags_requires_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let result = client.try_set_pause_flags(&issuer, &flags(true, true, true));
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    }
+
+    #[test]
+    fn test_legacy_paused_bool_still_decodes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        env.as_contract(&client.address, || {
+            env.storage().instance().set(&crate::storage::StorageKey::Paused, &true);
+        });
+
+        assert_eq!(client.get_pause_flags(), flags(true, true, true));
+        let claim = String::from_str(&env, "KYC_PASSED");
+        let result = client.try_create_attestation(&issuer, &Address::generate(&env), &claim, &None, &None, &None);
+        assert_eq!(result, Err(Ok(Error::ContractPaused)));
+
+        client.unpause(&admin);
+        assert_eq!(client.get_pause_flags(), flags(false, false, false));
+        client.create_attestation(&issuer, &Address::generate(&env), &claim, &None, &None, &None);
+    }
+}
+
+mod created_between_tests {
+    use super::*;
+
+    #[test]
+    fn test_only_attestations_in_range_are_returned() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let other_issuer = Address::generate(&env);
+        client.register_issuer(&admin, &other_issuer);
+        let subject = Address::generate(&env);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let early = client.create_attestation(&issuer, &subject, &String::from_str(&env, "A"), &None, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        let mid = client.create_attestation(&other_issuer, &subject, &String::from_str(&env, "B"), &None, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 3_000);
+        let late = client.create_attestation(&issuer, &subject, &String::from_str(&env, "C"), &None, &None, &None);
+
+        let ids = client.get_attestations_created_between(&1_500, &3_000, &0, &10);
+        assert_eq!(ids.len(), 2);
+        assert!(ids.contains(&mid));
+        assert!(ids.contains(&late));
+        assert!(!ids.contains(&early));
+    }
+
+    #[test]
+    fn test_inclusive_single_point_range() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "A"), &None, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        let exact = client.create_attestation(&issuer, &subject, &String::from_str(&env, "B"), &None, &None, &None);
+
+        let ids = client.get_attestations_created_between(&2_000, &2_000, &0, &10);
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids.get(0), Some(exact));
+    }
+
+    #[test]
+    fn test_pagination_and_inverted_range() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+
+        for (i, claim) in ["A", "B", "C"].iter().enumerate() {
+            env.ledger().with_mut(|l| l.timestamp = 1_000 + i as u64);
+            client.create_attestation(&issuer, &subject, &String::from_str(&env, claim), &None, &None, &None);
+        }
+
+        assert_eq!(client.get_attestations_created_between(&0, &5_000, &0, &2).len(), 2);
+        assert_eq!(client.get_attestations_created_between(&0, &5_000, &2, &2).len(), 1);
+        assert!(client.get_attestations_created_between(&5_000, &0, &0, &10).is_empty());
+    }
+}
+
+mod claims_bitmap_tests {
+    use super::*;
+
+    #[test]
+    fn test_bitmap_matches_held_claims() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+
+        let kyc = String::from_str(&env, "KYC");
+        let aml = String::from_str(&env, "AML");
+        let accredited = String::from_str(&env, "ACCREDITED");
+        let sanctions = String::from_str(&env, "SANCTIONS");
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        let aml_id = client.create_attestation(&issuer, &subject, &aml, &None, &None, &None);
+        client.create_attestation(&issuer, &subject, &sanctions, &None, &None, &None);
+        client.revoke_attestation(&issuer, &aml_id, &None);
+
+        let types = Vec::from_array(&env, [kyc, aml, accredited, sanctions]);
+        // held: KYC (bit 0) and SANCTIONS (bit 3); AML revoked, ACCREDITED never issued
+        assert_eq!(client.claims_bitmap(&subject, &types), 0b1001);
+    }
+
+    #[test]
+    fn test_empty_list_returns_zero() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        assert_eq!(client.claims_bitmap(&subject, &Vec::new(&env)), 0);
+    }
+
+    #[test]
+    fn test_more_than_64_types_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let mut types = Vec::new(&env);
+        for _ in 0..65 {
+            types.push_back(String::from_str(&env, "KYC"));
+        }
+        assert_eq!(client.try_claims_bitmap(&subject, &types), Err(Ok(Error::LimitExceeded)));
+    }
+}
+
+mod sub_issuer_tests {
+    use super::*;
+
+    #[test]
+    fn test_sub_issuer_creates_on_behalf_of_parent() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, parent, client) = setup(&env);
+        let sub = Address::generate(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        client.add_sub_issuer(&parent, &sub);
+        assert_eq!(client.get_sub_issuer_parent(&sub), Some(parent.clone()));
+
+        let id = client.create_attestation(&sub, &subject, &claim, &None, &None, &None);
+        let attestation = client.get_attestation(&id);
+        assert_eq!(attestation.issuer, sub);
+        assert_eq!(attestation.parent_issuer, Some(parent));
+        assert!(client.has_valid_claim_strict(&subject, &claim));
+    }
+
+    #[test]
+    fn test_direct_issuer_has_no_parent_recorded() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        assert_eq!(client.get_attestation(&id).parent_issuer, None);
+    }
+
+    #[test]
+    fn test_removing_parent_disables_sub_issuer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, parent, client) = setup(&env);
+        let sub = Address::generate(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+
+        client.add_sub_issuer(&parent, &sub);
+        client.create_attestation(&sub, &subject, &claim, &None, &None, &None);
+        client.remove_issuer(&admin, &parent);
+
+        let other = String::from_str(&env, "AML");
+        let result = client.try_create_attestation(&sub, &subject, &other, &None, &None, &None);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+        assert!(!client.has_valid_claim_strict(&subject, &claim));
+    }
+
+    #[test]
+    fn test_unregistered_parent_cannot_add_sub_issuer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        let outsider = Address::generate(&env);
+        let result = client.try_add_sub_issuer(&outsider, &Address::generate(&env));
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    }
+
+    #[test]
+    fn test_removed_sub_issuer_cannot_create() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, parent, client) = setup(&env);
+        let sub = Address::generate(&env);
+        let subject = Address::generate(&env);
+
+        client.add_sub_issuer(&parent, &sub);
+        client.remove_sub_issuer(&parent, &sub);
+        let result = client.try_create_attestation(&sub, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+        assert_eq!(client.try_remove_sub_issuer(&parent, &sub), Err(Ok(Error::NotFound)));
+    }
+}
+
+mod contract_metadata_tests {
+    use super::*;
+
+    #[test]
+    fn test_default_metadata_before_set() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        let metadata = client.get_contract_metadata();
+        assert_eq!(metadata.name, String::from_str(&env, "TrustLink"));
+        assert_eq!(metadata.metadata_version, 0);
+        assert_eq!(metadata.version, client.get_version());
+    }
+
+    #[test]
+    fn test_set_contract_metadata_round_trip() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let name = String::from_str(&env, "Acme KYC Registry");
+        let description = String::from_str(&env, "KYC_PASSED and ACCREDITED claims for Acme users.");
+
+        client.set_contract_metadata(&admin, &name, &description, &3);
+        let metadata = client.get_contract_metadata();
+        assert_eq!(metadata.name, name);
+        assert_eq!(metadata.description, description);
+        assert_eq!(metadata.metadata_version, 3);
+        assert_eq!(metadata.version, client.get_version());
+
+        let config = client.get_config();
+        assert_eq!(config.contract_name, name);
+        assert_eq!(config.contract_description, description);
+    }
+
+    #[test]
+    fn test_oversized_fields_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let ok = String::from_str(&env, "ok");
+        let long_name = String::from_str(&env, &"n".repeat(65));
+        let long_description = String::from_str(&env, &"d".repeat(257));
+
+        assert_eq!(
+            client.try_set_contract_metadata(&admin, &long_name, &ok, &1),
+            Err(Ok(Error::MetadataTooLong))
+        );
+        assert_eq!(
+            client.try_set_contract_metadata(&admin, &ok, &long_description, &1),
+            Err(Ok(Error::MetadataTooLong))
+        );
+    }
+
+    #[test]
+    fn test_non_admin_cannot_set_metadata() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let ok = String::from_str(&env, "ok");
+        assert_eq!(client.try_set_contract_metadata(&issuer, &ok, &ok, &1), Err(Ok(Error::Unauthorized)));
+    }
+}
+
+mod dependency_expiry_clamp_tests {
+    use super::*;
+
+    #[test]
+    fn test_child_without_expiration_expires_with_parent() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let accredited = String::from_str(&env, "ACCREDITED");
+
+        env.ledger().set_timestamp(1_000);
+        let parent = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &Some(2_000), &None, &None);
+        let child = client.create_dependent_attestation(&issuer, &subject, &accredited, &parent, &None, &None);
+        assert_eq!(client.get_attestation_status(&child), AttestationStatus::Valid);
+        assert!(client.has_valid_claim(&subject, &accredited));
+
+        env.ledger().set_timestamp(2_000);
+        assert_eq!(client.get_attestation_status(&child), AttestationStatus::Expired);
+        assert!(!client.has_valid_claim(&subject, &accredited));
+        // The stored record keeps its own (absent) expiration.
+        assert_eq!(client.get_attestation(&child).expiration, None);
+    }
+
+    #[test]
+    fn test_child_with_later_expiration_clamped_to_parent() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let accredited = String::from_str(&env, "ACCREDITED");
+
+        env.ledger().set_timestamp(1_000);
+        let parent = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &Some(2_000), &None, &None);
+        let child = client.create_dependent_attestation(&issuer, &subject, &accredited, &parent, &Some(5_000), &None);
+
+        env.ledger().set_timestamp(3_000);
+        assert_eq!(client.get_attestation_status(&child), AttestationStatus::Expired);
+        assert!(!client.has_valid_claim(&subject, &accredited));
+    }
+
+    #[test]
+    fn test_child_with_earlier_expiration_keeps_its_own() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+
+        env.ledger().set_timestamp(1_000);
+        let parent = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &Some(5_000), &None, &None);
+        let child = client.create_dependent_attestation(&issuer, &subject, &String::from_str(&env, "ACCREDITED"), &parent, &Some(2_000), &None);
+
+        env.ledger().set_timestamp(2_500);
+        assert_eq!(client.get_attestation_status(&child), AttestationStatus::Expired);
+        assert_eq!(client.get_attestation_status(&parent), AttestationStatus::Valid);
+    }
+}
+
+mod explicit_permanence_tests {
+    use super::*;
+
+    #[test]
+    fn test_multisig_proposal_needs_cohort_window_in_strict_mode() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let cosigner = Address::generate(&env);
+        client.register_issuer(&admin, &cosigner);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC");
+        let signers = Vec::from_array(&env, [issuer.clone(), cosigner.clone()]);
+        client.set_enforce_explicit_permanence(&admin, &true);
+
+        assert_eq!(
+            client.try_propose_attestation(&issuer, &subject, &claim, &signers, &2),
+            Err(Ok(Error::ExpirationRequired))
+        );
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.set_cohort_expiration(&admin, &claim, &Some(5_000));
+        let proposal_id = client.propose_attestation(&issuer, &subject, &claim, &signers, &2);
+        client.cosign_attestation(&cosigner, &proposal_id);
+        assert!(client.has_valid_claim(&subject, &claim));
+    }
+
+    #[test]
+    fn test_none_expiration_accepted_when_not_enforced() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        assert!(!client.get_attestation(&id).permanent);
+    }
+
+    #[test]
+    fn test_none_expiration_rejected_in_strict_mode() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC");
+        client.set_enforce_explicit_permanence(&admin, &true);
+        assert!(client.get_enforce_explicit_permanence());
+
+        let result = client.try_create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        assert_eq!(result, Err(Ok(Error::ExpirationRequired)));
+
+        // An explicit expiration is still fine.
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.create_attestation(&issuer, &subject, &claim, &Some(5_000), &None, &None);
+    }
+
+    #[test]
+    fn test_permanent_create_accepted_in_strict_mode() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC");
+        client.set_enforce_explicit_permanence(&admin, &true);
+
+        let id = client.create_permanent_attestation(&issuer, &subject, &claim, &None, &None);
+        let attestation = client.get_attestation(&id);
+        assert!(attestation.permanent);
+        assert_eq!(attestation.expiration, None);
+        assert!(client.has_valid_claim(&subject, &claim));
+    }
+
+    #[test]
+    fn test_batch_none_expiration_rejected_in_strict_mode() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        client.set_enforce_explicit_permanence(&admin, &true);
+        let subjects = Vec::from_array(&env, [Address::generate(&env)]);
+        let result = client.try_create_attestations_batch(&issuer, &subjects, &String::from_str(&env, "KYC"), &None);
+        assert_eq!(result, Err(Ok(Error::ExpirationRequired)));
+    }
+}
+
+mod get_admin_unchecked_tests {
+    use super::*;
+
+    #[test]
+    fn test_returns_none_before_initialize() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TrustLinkContract);
+        let client = TrustLinkContractClient::new(&env, &contract_id);
+        assert_eq!(client.get_admin_unchecked(), None);
+        assert_eq!(client.try_get_admin(), Err(Ok(Error::NotInitialized)));
+    }
+
+    #[test]
+    fn test_returns_admin_after_initialize() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        assert_eq!(client.get_admin_unchecked(), Some(admin.clone()));
+        assert_eq!(client.get_admin(), admin);
+    }
+}
+
+mod category_tests {
+    use super::*;
+
+    #[test]
+    fn test_creation_options_combine_in_one_record() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = env.as_contract(&client.address, || {
+            let options = attestation::CreationOptions {
+                category: Category::Financial,
+                revocable: false,
+                stake: Some(500),
+                score: Some(720),
+                expiration_locked: true,
+                ..attestation::CreationOptions::default()
+            };
+            attestation::create_attestation_internal(
+                &env,
+                issuer.clone(),
+                subject.clone(),
+                String::from_str(&env, "CREDIT_SCORE"),
+                Some(10_000),
+                None,
+                None,
+                options,
+            )
+            .unwrap()
+        });
+
+        let attestation = client.get_attestation(&id);
+        assert_eq!(attestation.category, Category::Financial);
+        assert!(!attestation.revocable);
+        assert_eq!(attestation.stake, Some(500));
+        assert_eq!(attestation.score, Some(720));
+        assert!(attestation.expiration_locked);
+    }
+
+    #[test]
+    fn test_default_category_is_other() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        assert_eq!(client.get_attestation(&id).category, Category::Other);
+    }
+
+    #[test]
+    fn test_filter_by_category() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+
+        let kyc = client.create_attestation_with_category(
+            &issuer, &subject, &String::from_str(&env, "KYC"), &Category::Identity, &None, &None, &None,
+        );
+        let passport = client.create_attestation_with_category(
+            &issuer, &subject, &String::from_str(&env, "PASSPORT"), &Category::Identity, &None, &None, &None,
+        );
+        let credit = client.create_attestation_with_category(
+            &issuer, &subject, &String::from_str(&env, "CREDIT_SCORE"), &Category::Financial, &None, &None, &None,
+        );
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "MISC"), &None, &None, &None);
+
+        let identity = client.get_attestations_by_category(&subject, &Category::Identity, &0, &10);
+        assert_eq!(identity.len(), 2);
+        assert_eq!(identity.get(0), Some(kyc));
+        assert_eq!(identity.get(1), Some(passport));
+
+        let financial = client.get_attestations_by_category(&subject, &Category::Financial, &0, &10);
+        assert_eq!(financial.len(), 1);
+        assert_eq!(financial.get(0), Some(credit));
+
+        assert_eq!(client.get_attestations_by_category(&subject, &Category::Other, &0, &10).len(), 1);
+        assert!(client.get_attestations_by_category(&subject, &Category::Compliance, &0, &10).is_empty());
+        assert_eq!(client.get_attestations_by_category(&subject, &Category::Identity, &1, &10).len(), 1);
+    }
+}
+
+mod subject_updated_at_tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_advances_on_create_and_revoke() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        assert_eq!(client.get_subject_updated_at(&subject), 0);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        assert_eq!(client.get_subject_updated_at(&subject), 1_000);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        client.revoke_attestation(&issuer, &id, &None);
+        assert_eq!(client.get_subject_updated_at(&subject), 2_000);
+    }
+
+    #[test]
+    fn test_other_subjects_changes_do_not_bump() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let other = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC");
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 5_000);
+        let other_id = client.create_attestation(&issuer, &other, &claim, &None, &None, &None);
+        client.revoke_attestation(&issuer, &other_id, &None);
+
+        assert_eq!(client.get_subject_updated_at(&subject), 1_000);
+        assert_eq!(client.get_subject_updated_at(&other), 5_000);
+    }
+}
+
+mod transfer_to_subject_tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_moves_to_new_subject() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let old_subject = Address::generate(&env);
+        let new_subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC_PASSED");
+        let id = client.create_attestation(&issuer, &old_subject, &claim, &None, &None, &None);
+
+        client.transfer_to_subject(&issuer, &id, &new_subject, &false);
+
+        assert!(client.has_valid_claim(&new_subject, &claim));
+        assert!(!client.has_valid_claim(&old_subject, &claim));
+        assert_eq!(client.get_attestation(&id).subject, new_subject);
+        assert_eq!(client.get_subject_attestations(&new_subject, &0, &10).len(), 1);
+        assert!(client.get_subject_attestations(&old_subject, &0, &10).is_empty());
+    }
+
+    #[test]
+    fn test_transfer_emits_event() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let old_subject = Address::generate(&env);
+        let new_subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &old_subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+
+        client.transfer_to_subject(&issuer, &id, &new_subject, &true);
+
+        let (_, _, data) = env.events().all().last().unwrap();
+        let (event_id, event_subject): (String, Address) =
+            soroban_sdk::TryFromVal::try_from_val(&env, &data).unwrap();
+        assert_eq!(event_id, id);
+        assert_eq!(event_subject, new_subject);
+    }
+
+    #[test]
+    fn test_only_original_issuer_can_transfer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let other_issuer = Address::generate(&env);
+        client.register_issuer(&admin, &other_issuer);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+
+        let result = client.try_transfer_to_subject(&other_issuer, &id, &Address::generate(&env), &false);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    }
+
+    #[test]
+    fn test_revoked_attestation_cannot_be_transferred() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+
+        let result = client.try_transfer_to_subject(&issuer, &id, &Address::generate(&env), &false);
+        assert_eq!(result, Err(Ok(Error::AlreadyRevoked)));
+    }
+
+    #[test]
+    fn test_expired_attestation_cannot_be_transferred() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &Some(2_000), &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+
+        let result = client.try_transfer_to_subject(&issuer, &id, &Address::generate(&env), &false);
+        assert_eq!(result, Err(Ok(Error::Expired)));
+    }
+
+    #[test]
+    fn test_paused_or_removed_issuer_cannot_transfer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+
+        client.pause_issuer(&admin, &issuer);
+        let result = client.try_transfer_to_subject(&issuer, &id, &Address::generate(&env), &false);
+        assert_eq!(result, Err(Ok(Error::IssuerPaused)));
+
+        client.remove_issuer(&admin, &issuer);
+        let result = client.try_transfer_to_subject(&issuer, &id, &Address::generate(&env), &false);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    }
+
+    #[test]
+    fn test_consent_flag_controls_new_subject_auth() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC");
+        let first = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        let second = client.create_attestation(&issuer, &subject, &String::from_str(&env, "AML"), &None, &None, &None);
+
+        let consenting = Address::generate(&env);
+        client.transfer_to_subject(&issuer, &first, &consenting, &true);
+        let authorized: std::vec::Vec<Address> = env.auths().into_iter().map(|(address, _)| address).collect();
+        assert!(authorized.contains(&consenting));
+
+        let silent = Address::generate(&env);
+        client.transfer_to_subject(&issuer, &second, &silent, &false);
+        let authorized: std::vec::Vec<Address> = env.auths().into_iter().map(|(address, _)| address).collect();
+        assert!(!authorized.contains(&silent));
+    }
+
+    #[test]
+    fn test_singleton_and_whitelist_apply_to_new_subject() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let claim = String::from_str(&env, "KYC");
+        client.set_singleton_claim_type(&admin, &claim, &true);
+        let holder = Address::generate(&env);
+        let mover = Address::generate(&env);
+        client.create_attestation(&issuer, &holder, &claim, &None, &None, &None);
+        let id = client.create_attestation(&issuer, &mover, &claim, &None, &None, &None);
+
+        let result = client.try_transfer_to_subject(&issuer, &id, &holder, &false);
+        assert_eq!(result, Err(Ok(Error::SingletonViolation)));
+
+        client.enable_whitelist_mode(&issuer);
+        let result = client.try_transfer_to_subject(&issuer, &id, &Address::generate(&env), &false);
+        assert_eq!(result, Err(Ok(Error::SubjectNotWhitelisted)));
+    }
+
+    #[test]
+    fn test_dependent_of_expired_parent_cannot_be_transferred() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let parent = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &Some(2_000), &None, &None);
+        let child = client.create_dependent_attestation(&issuer, &subject, &String::from_str(&env, "AML"), &parent, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+
+        let result = client.try_transfer_to_subject(&issuer, &child, &Address::generate(&env), &false);
+        assert_eq!(result, Err(Ok(Error::Expired)));
+    }
+}
+
+mod admin_revoke_tests {
+    use super::*;
+
+    #[test]
+    fn test_admin_revoke_then_already_revoked() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC");
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        client.admin_revoke(&admin, &id, &Some(String::from_str(&env, "fraud")));
+        assert!(client.get_attestation(&id).revoked);
+        assert!(!client.has_valid_claim(&subject, &claim));
+
+        let events_before = env.events().all().len();
+        let result = client.try_admin_revoke(&admin, &id, &None);
+        assert_eq!(result, Err(Ok(Error::AlreadyRevoked)));
+        assert_eq!(env.events().all().len(), events_before);
+    }
+
+    #[test]
+    fn test_admin_revoke_on_issuer_revoked_attestation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+
+        assert_eq!(client.try_admin_revoke(&admin, &id, &None), Err(Ok(Error::AlreadyRevoked)));
+    }
+
+    #[test]
+    fn test_non_admin_cannot_admin_revoke() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        assert_eq!(client.try_admin_revoke(&issuer, &id, &None), Err(Ok(Error::Unauthorized)));
+    }
+
+    #[test]
+    fn test_amend_revocation_reason() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        client.admin_revoke(&admin, &id, &Some(String::from_str(&env, "fraud")));
+
+        let corrected = Some(String::from_str(&env, "document forgery"));
+        client.amend_revocation_reason(&admin, &id, &corrected);
+        let attestation = client.get_attestation(&id);
+        assert!(attestation.revoked);
+        assert_eq!(attestation.revocation_reason, corrected);
+    }
+
+    #[test]
+    fn test_amend_requires_revoked_attestation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+
+        let result = client.try_amend_revocation_reason(&admin, &id, &Some(String::from_str(&env, "x")));
+        assert_eq!(result, Err(Ok(Error::NotRevoked)));
+    }
+}
+
+mod valid_attestations_tests {
+    use super::*;
+
+    #[test]
+    fn test_only_valid_records_returned() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let valid = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "AML"), &Some(2_000), &None, &None);
+        let revoked = client.create_attestation(&issuer, &subject, &String::from_str(&env, "PEP"), &None, &None, &None);
+        client.revoke_attestation(&issuer, &revoked, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 3_000);
+        let (records, truncated) = client.get_valid_attestations(&subject, &10);
+        assert!(!truncated);
+        assert_eq!(records.len(), 1);
+        let record = records.get(0).unwrap();
+        assert_eq!(record.id, valid);
+        assert_eq!(record.claim_type, String::from_str(&env, "KYC"));
+    }
+
+    #[test]
+    fn test_truncation_flag_when_scan_limited() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        for claim in ["A", "B", "C"] {
+            client.create_attestation(&issuer, &subject, &String::from_str(&env, claim), &None, &None, &None);
+        }
+
+        let (records, truncated) = client.get_valid_attestations(&subject, &2);
+        assert!(truncated);
+        assert_eq!(records.len(), 2);
+
+        let (records, truncated) = client.get_valid_attestations(&subject, &3);
+        assert!(!truncated);
+        assert_eq!(records.len(), 3);
+    }
+}
+
+mod attestation_with_issuer_tests {
+    use super::*;
+
+    #[test]
+    fn test_issuer_flag_before_and_after_removal() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC");
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        let (attestation, authorized) = client.get_attestation_with_issuer(&id);
+        assert_eq!(attestation.id, id);
+        assert!(authorized);
+
+        client.remove_issuer(&admin, &issuer);
+        let (attestation, authorized) = client.get_attestation_with_issuer(&id);
+        assert_eq!(attestation.issuer, issuer);
+        assert!(!authorized);
+        // Lenient validity is unaffected by the issuer's removal.
+        assert!(client.has_valid_claim(&subject, &claim));
+    }
+
+    #[test]
+    fn test_unknown_id_is_not_found() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        let result = client.try_get_attestation_with_issuer(&String::from_str(&env, "missing"));
+        assert_eq!(result, Err(Ok(Error::NotFound)));
+    }
+}
+
+mod claim_type_registry_capacity_tests {
+    use super::*;
+
+    #[test]
+    fn test_full_registry_blocks_then_unregister_frees_slot() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let desc = String::from_str(&env, "desc");
+        client.set_max_claim_types(&admin, &2);
+        assert_eq!(client.get_max_claim_types(), 2);
+
+        let kyc = String::from_str(&env, "KYC");
+        let aml = String::from_str(&env, "AML");
+        let pep = String::from_str(&env, "PEP");
+        client.register_claim_type(&admin, &kyc, &desc);
+        client.register_claim_type(&admin, &aml, &desc);
+
+        assert_eq!(client.try_register_claim_type(&admin, &pep, &desc), Err(Ok(Error::RegistryFull)));
+        // Re-describing an existing type does not need a new slot.
+        client.register_claim_type(&admin, &kyc, &String::from_str(&env, "updated"));
+
+        client.unregister_claim_type(&admin, &aml);
+        assert_eq!(client.get_claim_type_description(&aml), None);
+        client.register_claim_type(&admin, &pep, &desc);
+        assert_eq!(client.list_claim_types(&0, &10).len(), 2);
+    }
+
+    #[test]
+    fn test_unregister_keeps_existing_attestations_valid() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.register_claim_type(&admin, &kyc, &String::from_str(&env, "desc"));
+        client.set_registered_claim_type(&admin, &true);
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+
+        client.unregister_claim_type(&admin, &kyc);
+
+        assert!(client.has_valid_claim(&subject, &kyc));
+        let other = Address::generate(&env);
+        let result = client.try_create_attestation(&issuer, &other, &kyc, &None, &None, &None);
+        assert_eq!(result, Err(Ok(Error::InvalidClaimType)));
+    }
+
+    #[test]
+    fn test_unregister_unknown_type_is_not_found() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let result = client.try_unregister_claim_type(&admin, &String::from_str(&env, "NOPE"));
+        assert_eq!(result, Err(Ok(Error::NotFound)));
+    }
+}
+
+mod valid_claim_as_of_tests {
+    use super::*;
+
+    #[test]
+    fn test_expired_claim_was_valid_earlier() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC");
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.create_attestation(&issuer, &subject, &claim, &Some(2_000), &None, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 5_000);
+        assert!(!client.has_valid_claim(&subject, &claim));
+        assert!(client.has_valid_claim_as_of(&subject, &claim, &1_500));
+        assert!(!client.has_valid_claim_as_of(&subject, &claim, &2_000));
+    }
+
+    #[test]
+    fn test_attestation_created_after_as_of_not_counted() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC");
+
+        env.ledger().with_mut(|l| l.timestamp = 3_000);
+        client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+
+        assert!(!client.has_valid_claim_as_of(&subject, &claim, &2_999));
+        assert!(client.has_valid_claim_as_of(&subject, &claim, &3_000));
+    }
+
+    #[test]
+    fn test_revoked_never_counts() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim = String::from_str(&env, "KYC");
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let id = client.create_attestation(&issuer, &subject, &claim, &None, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        client.revoke_attestation(&issuer, &id, &None);
+
+        assert!(!client.has_valid_claim_as_of(&subject, &claim, &1_500));
+    }
+}
+
+mod issuer_fee_accounting_tests {
+    use super::*;
+
+    #[test]
+    fn test_owed_accumulates_and_resets_on_settle() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let aml = String::from_str(&env, "AML");
+        client.set_claim_type_fee(&admin, &kyc, &100);
+        client.set_claim_type_fee(&admin, &aml, &25);
+        assert_eq!(client.get_claim_type_fee(&kyc), 100);
+
+        client.create_attestation(&issuer, &Address::generate(&env), &kyc, &None, &None, &None);
+        client.create_attestation(&issuer, &Address::generate(&env), &kyc, &None, &None, &None);
+        client.create_attestation(&issuer, &Address::generate(&env), &aml, &None, &None, &None);
+        client.create_attestation(&issuer, &Address::generate(&env), &String::from_str(&env, "FREE"), &None, &None, &None);
+        assert_eq!(client.get_issuer_owed(&issuer), 225);
+
+        assert_eq!(client.settle_issuer_fees(&admin, &issuer), 225);
+        assert_eq!(client.get_issuer_owed(&issuer), 0);
+
+        client.create_attestation(&issuer, &Address::generate(&env), &aml, &None, &None, &None);
+        assert_eq!(client.get_issuer_owed(&issuer), 25);
+    }
+
+    #[test]
+    fn test_batch_creation_accrues_per_subject() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.set_claim_type_fee(&admin, &kyc, &10);
+
+        let subjects = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env), Address::generate(&env)]);
+        client.create_attestations_batch(&issuer, &subjects, &kyc, &None);
+        assert_eq!(client.get_issuer_owed(&issuer), 30);
+    }
+
+    #[test]
+    fn test_owed_tracked_per_issuer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let other = Address::generate(&env);
+        client.register_issuer(&admin, &other);
+        let kyc = String::from_str(&env, "KYC");
+        client.set_claim_type_fee(&admin, &kyc, &7);
+
+        client.create_attestation(&issuer, &Address::generate(&env), &kyc, &None, &None, &None);
+        assert_eq!(client.get_issuer_owed(&issuer), 7);
+        assert_eq!(client.get_issuer_owed(&other), 0);
+    }
+
+    #[test]
+    fn test_negative_fee_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let result = client.try_set_claim_type_fee(&admin, &String::from_str(&env, "KYC"), &-1);
+        assert_eq!(result, Err(Ok(Error::InvalidFee)));
+    }
+}
+
+mod revocation_ack_tests {
+    use super::*;
+
+    #[test]
+    fn test_revoke_then_acknowledge() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+        assert!(!client.is_revocation_acknowledged(&id));
+
+        client.acknowledge_revocation(&subject, &id);
+        assert!(client.is_revocation_acknowledged(&id));
+        // A repeat acknowledgment is harmless.
+        client.acknowledge_revocation(&subject, &id);
+    }
+
+    #[test]
+    fn test_acknowledge_before_revocation_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+
+        assert_eq!(client.try_acknowledge_revocation(&subject, &id), Err(Ok(Error::NotRevoked)));
+        assert!(!client.is_revocation_acknowledged(&id));
+    }
+
+    #[test]
+    fn test_only_subject_can_acknowledge() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+
+        let result = client.try_acknowledge_revocation(&Address::generate(&env), &id);
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    }
+}
+
+mod subject_attestations_since_tests {
+    use super::*;
+
+    #[test]
+    fn test_only_newer_than_cutoff_returned() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "A"), &None, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        let at_cutoff = client.create_attestation(&issuer, &subject, &String::from_str(&env, "B"), &None, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 3_000);
+        let newer = client.create_attestation(&issuer, &subject, &String::from_str(&env, "C"), &None, &None, &None);
+
+        let ids = client.get_subject_attestations_since(&subject, &2_000, &0, &10);
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids.get(0), Some(newer));
+        assert!(!ids.contains(&at_cutoff));
+
+        assert_eq!(client.get_subject_attestations_since(&subject, &0, &0, &10).len(), 3);
+        assert_eq!(client.get_subject_attestations_since(&subject, &0, &1, &1).len(), 1);
+        assert!(client.get_subject_attestations_since(&subject, &3_000, &0, &10).is_empty());
+    }
+}
+
+mod backup_admin_tests {
+    use super::*;
+
+    #[test]
+    fn test_backup_cannot_claim_before_window() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let backup = Address::generate(&env);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.set_backup_admin(&admin, &backup, &500);
+        env.ledger().with_mut(|l| l.timestamp = 1_499);
+
+        assert_eq!(client.try_claim_admin(&backup), Err(Ok(Error::Unauthorized)));
+        assert_eq!(client.get_admin(), admin);
+    }
+
+    #[test]
+    fn test_backup_claims_after_inactivity() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let backup = Address::generate(&env);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.set_backup_admin(&admin, &backup, &500);
+        env.ledger().with_mut(|l| l.timestamp = 1_500);
+
+        client.claim_admin(&backup);
+        assert_eq!(client.get_admin(), backup);
+        assert_eq!(client.get_backup_admin(), None);
+        // The old admin has lost its rights.
+        assert_eq!(client.try_remove_issuer(&admin, &issuer), Err(Ok(Error::Unauthorized)));
+    }
+
+    #[test]
+    fn test_admin_action_resets_timer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let backup = Address::generate(&env);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.set_backup_admin(&admin, &backup, &500);
+        env.ledger().with_mut(|l| l.timestamp = 1_400);
+        client.register_issuer(&admin, &Address::generate(&env));
+        assert_eq!(client.get_backup_admin().unwrap().last_admin_action, 1_400);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_600);
+        assert_eq!(client.try_claim_admin(&backup), Err(Ok(Error::Unauthorized)));
+        env.ledger().with_mut(|l| l.timestamp = 1_900);
+        client.claim_admin(&backup);
+    }
+
+    #[test]
+    fn test_only_designated_backup_can_claim() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        client.set_backup_admin(&admin, &Address::generate(&env), &10);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+
+        assert_eq!(client.try_claim_admin(&Address::generate(&env)), Err(Ok(Error::Unauthorized)));
+    }
+
+    #[test]
+    fn test_claim_drops_pending_admin_transfer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let backup = Address::generate(&env);
+        let nominee = Address::generate(&env);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.set_backup_admin(&admin, &backup, &500);
+        client.propose_admin_transfer(&admin, &nominee);
+        env.ledger().with_mut(|l| l.timestamp = 1_500);
+
+        client.claim_admin(&backup);
+        assert_eq!(client.get_pending_admin_transfer(), None);
+        assert_eq!(client.try_accept_admin_transfer(&nominee), Err(Ok(Error::NotFound)));
+        assert_eq!(client.get_admin(), backup);
+    }
+}
+
+mod check_claims_tests {
+    use super::*;
+
+    #[test]
+    fn test_results_match_query_positions() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let aml = String::from_str(&env, "AML");
+        client.create_attestation(&issuer, &alice, &kyc, &None, &None, &None);
+        client.create_attestation(&issuer, &bob, &aml, &None, &None, &None);
+
+        let queries = Vec::from_array(
+            &env,
+            [
+                (alice.clone(), kyc.clone()),
+                (alice.clone(), aml.clone()),
+                (bob.clone(), kyc.clone()),
+                (bob.clone(), aml.clone()),
+            ],
+        );
+        let results = client.check_claims(&queries);
+        assert_eq!(results, Vec::from_array(&env, [true, false, false, true]));
+    }
+
+    #[test]
+    fn test_too_many_queries_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let mut queries = Vec::new(&env);
+        for _ in 0..51 {
+            queries.push_back((subject.clone(), String::from_str(&env, "KYC")));
+        }
+        assert_eq!(client.try_check_claims(&queries), Err(Ok(Error::LimitExceeded)));
+    }
+}
+
+mod issuer_expiry_tests {
+    use super::*;
+
+    #[test]
+    fn test_expired_issuer_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.set_issuer_expiry(&admin, &issuer, &Some(2_000));
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        assert_eq!(
+            client.try_create_attestation(&issuer, &Address::generate(&env), &kyc, &None, &None, &None),
+            Err(Ok(Error::IssuerExpired))
+        );
+    }
+
+    #[test]
+    fn test_attestation_with_issuer_reports_expired_issuer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.set_issuer_expiry(&admin, &issuer, &Some(2_000));
+        let id = client.create_attestation(&issuer, &Address::generate(&env), &String::from_str(&env, "KYC"), &None, &None, &None);
+        assert!(client.get_attestation_with_issuer(&id).1);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        assert!(!client.get_attestation_with_issuer(&id).1);
+        assert!(env.events().all().is_empty());
+    }
+
+    #[test]
+    fn test_past_expiry_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        assert_eq!(
+            client.try_set_issuer_expiry(&admin, &issuer, &Some(1_000)),
+            Err(Ok(Error::InvalidTimestamp))
+        );
+        client.set_issuer_expiry(&admin, &issuer, &None);
+        assert_eq!(client.get_issuer_expiry(&issuer), None);
+    }
+}
+
+mod attestation_age_tests {
+    use super::*;
+
+    #[test]
+    fn test_age_grows_with_ledger_time() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let id = client.create_attestation(
+            &issuer,
+            &Address::generate(&env),
+            &String::from_str(&env, "KYC"),
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(client.get_attestation_age(&id), 0);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_750);
+        assert_eq!(client.get_attestation_age(&id), 750);
+    }
+
+    #[test]
+    fn test_missing_attestation_not_found() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        assert_eq!(
+            client.try_get_attestation_age(&String::from_str(&env, "missing")),
+            Err(Ok(Error::NotFound))
+        );
+    }
+}
+
+mod issuer_weight_tests {
+    use super::*;
+
+    #[test]
+    fn test_single_high_weight_issuer_passes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.set_issuer_weight(&admin, &issuer, &10);
+        assert!(!client.has_weighted_claim(&subject, &kyc, &10));
+
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        assert!(client.has_weighted_claim(&subject, &kyc, &10));
+        assert!(!client.has_weighted_claim(&subject, &kyc, &11));
+    }
+
+    #[test]
+    fn test_low_weight_issuers_combine() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let second = Address::generate(&env);
+        client.register_issuer(&admin, &second);
+        client.set_issuer_weight(&admin, &issuer, &3);
+        client.set_issuer_weight(&admin, &second, &4);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        assert!(!client.has_weighted_claim(&subject, &kyc, &7));
+        client.create_attestation(&second, &subject, &kyc, &None, &None, &None);
+        assert!(client.has_weighted_claim(&subject, &kyc, &7));
+    }
+
+    #[test]
+    fn test_weight_requires_registered_issuer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        assert_eq!(
+            client.try_set_issuer_weight(&admin, &Address::generate(&env), &5),
+            Err(Ok(Error::NotFound))
+        );
+    }
+}
+
+mod claim_type_description_tests {
+    use super::*;
+
+    #[test]
+    fn test_description_round_trips() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC_PASSED");
+        client.register_claim_type(&admin, &kyc, &String::from_str(&env, "KYC"));
+
+        let description = String::from_str(&env, "Identity verified per FATF guidelines");
+        client.set_claim_type_description(&admin, &kyc, &description);
+        assert_eq!(client.get_claim_type_description(&kyc), Some(description));
+    }
+
+    #[test]
+    fn test_undescribed_type_returns_none() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let unknown = String::from_str(&env, "UNKNOWN");
+        assert_eq!(client.get_claim_type_description(&unknown), None);
+        assert_eq!(
+            client.try_set_claim_type_description(&admin, &unknown, &String::from_str(&env, "x")),
+            Err(Ok(Error::NotFound))
+        );
+    }
+
+    #[test]
+    fn test_description_too_long_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC_PASSED");
+        client.register_claim_type(&admin, &kyc, &String::from_str(&env, "KYC"));
+        let long = String::from_str(&env, &"d".repeat(257));
+        assert_eq!(
+            client.try_set_claim_type_description(&admin, &kyc, &long),
+            Err(Ok(Error::MetadataTooLong))
+        );
+    }
+}
+
+mod dev_reset_tests {
+    use super::*;
+
+    #[test]
+    fn test_reset_clears_state_in_dev_mode() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, client) = create_test_contract(&env);
+        let admin = Address::generate(&env);
+        let issuer = Address::generate(&env);
+        let subject = Address::generate(&env);
+        client.initialize_dev(&admin, &None);
+        client.register_issuer(&admin, &issuer);
+        let kyc = String::from_str(&env, "KYC");
+        let id = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+
+        client.reset(&admin);
+
+        assert!(client.is_dev_mode());
+        assert!(!client.is_issuer(&issuer));
+        assert_eq!(client.try_get_attestation(&id), Err(Ok(Error::NotFound)));
+        assert!(!client.has_valid_claim(&subject, &kyc));
+        assert_eq!(client.get_admin(), admin);
+        assert_eq!(client.get_global_stats().total_attestations, 0);
+    }
+
+    #[test]
+    fn test_reset_rejected_in_production() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        assert!(!client.is_dev_mode());
+        assert_eq!(client.try_reset(&admin), Err(Ok(Error::NotPermitted)));
+        assert!(client.is_issuer(&issuer));
+    }
+
+    #[test]
+    fn test_reset_clears_revoked_and_removed_issuer_records() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_, client) = create_test_contract(&env);
+        let admin = Address::generate(&env);
+        let issuer = Address::generate(&env);
+        let removed = Address::generate(&env);
+        let subject = Address::generate(&env);
+        client.initialize_dev(&admin, &None);
+        client.register_issuer(&admin, &issuer);
+        client.register_issuer(&admin, &removed);
+        client.set_issuer_weight(&admin, &issuer, &5);
+        let kyc = String::from_str(&env, "KYC");
+        let revoked = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        client.revoke_attestation(&issuer, &revoked, &None);
+        let orphan = client.create_attestation(&removed, &subject, &kyc, &None, &None, &None);
+        client.remove_issuer(&admin, &removed);
+
+        client.reset(&admin);
+
+        assert_eq!(client.try_get_attestation(&revoked), Err(Ok(Error::NotFound)));
+        assert_eq!(client.try_get_attestation(&orphan), Err(Ok(Error::NotFound)));
+        assert_eq!(client.get_claim_type_count(&kyc), 0);
+        assert_eq!(client.get_issuer_weight(&issuer), 0);
+        assert_eq!(client.get_issuer_attestations(&removed, &0, &10).len(), 0);
+    }
+}
+
+mod singleton_claim_type_tests {
+    use super::*;
+
+    #[test]
+    fn test_second_singleton_rejected_until_first_revoked() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.set_singleton_claim_type(&admin, &kyc, &true);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let first = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        assert_eq!(
+            client.try_create_attestation(&issuer, &subject, &kyc, &None, &None, &None),
+            Err(Ok(Error::SingletonViolation))
+        );
+
+        client.revoke_attestation(&issuer, &first, &None);
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+    }
+
+    #[test]
+    fn test_singleton_is_scoped_per_issuer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let other = Address::generate(&env);
+        client.register_issuer(&admin, &other);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.set_singleton_claim_type(&admin, &kyc, &true);
+
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        client.create_attestation(&other, &subject, &kyc, &None, &None, &None);
+    }
+
+    #[test]
+    fn test_singleton_enforced_on_delegate_and_request_paths() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let delegate = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.set_singleton_claim_type(&admin, &kyc, &true);
+        client.set_claim_type_fee(&admin, &kyc, &10);
+        client.delegate_claim_type(&issuer, &delegate, &kyc, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.create_attestation_as_delegate(&delegate, &issuer, &subject, &kyc, &None, &None);
+        assert_eq!(client.get_issuer_owed(&issuer), 10);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        assert_eq!(
+            client.try_create_attestation_as_delegate(&delegate, &issuer, &subject, &kyc, &None, &None),
+            Err(Ok(Error::SingletonViolation))
+        );
+        let req_id = client.request_attestation(&subject, &issuer, &kyc);
+        assert_eq!(
+            client.try_fulfill_request(&issuer, &req_id, &None),
+            Err(Ok(Error::SingletonViolation))
+        );
+    }
+}
+
+mod subject_page_tests {
+    use super::*;
+
+    fn subject_with_five(env: &Env) -> (Address, TrustLinkContractClient<'_>) {
+        let (admin, _issuer, client) = setup(env);
+        let subject = Address::generate(env);
+        for _ in 0..5 {
+            let issuer = Address::generate(env);
+            client.register_issuer(&admin, &issuer);
+            client.create_attestation(&issuer, &subject, &String::from_str(env, "KYC"), &None, &None, &None);
+        }
+        (subject, client)
+    }
+
+    #[test]
+    fn test_first_middle_and_last_pages() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (subject, client) = subject_with_five(&env);
+
+        let first = client.get_subject_attestations_page(&subject, &0, &2);
+        assert_eq!((first.ids.len(), first.total, first.has_more), (2, 5, true));
+
+        let middle = client.get_subject_attestations_page(&subject, &2, &2);
+        assert_eq!((middle.ids.len(), middle.total, middle.has_more), (2, 5, true));
+
+        let last = client.get_subject_attestations_page(&subject, &4, &2);
+        assert_eq!((last.ids.len(), last.total, last.has_more), (1, 5, false));
+    }
+
+    #[test]
+    fn test_start_past_end_and_huge_limit() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (subject, client) = subject_with_five(&env);
+
+        let past = client.get_subject_attestations_page(&subject, &10, &2);
+        assert_eq!((past.ids.len(), past.has_more), (0, false));
+
+        let all = client.get_subject_attestations_page(&subject, &1, &u32::MAX);
+        assert_eq!((all.ids.len(), all.has_more), (4, false));
+    }
+}
+
+mod auto_extend_tests {
+    use super::*;
+    use crate::types::AutoExtendConfig;
+
+    /// Returns (subject, attestation id) for a KYC claim expiring at 2_000
+    /// whose claim type auto-extends by 500 within 100 seconds of expiry,
+    /// once per attestation.
+    fn configured(env: &Env, client: &TrustLinkContractClient, admin: &Address, issuer: &Address) -> (Address, String) {
+        let kyc = String::from_str(env, "KYC");
+        client.set_auto_extend(admin, &kyc, &Some(AutoExtendConfig { grace_window: 100, extension: 500, max_extensions: 1 }));
+        client.set_auto_extend_consent(issuer, &kyc, &true);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let subject = Address::generate(env);
+        let id = client.create_attestation(issuer, &subject, &kyc, &Some(2_000), &None, &None);
+        (subject, id)
+    }
+
+    #[test]
+    fn test_verification_in_grace_window_extends() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let (subject, id) = configured(&env, &client, &admin, &issuer);
+        let verifier = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+
+        env.ledger().with_mut(|l| l.timestamp = 1_500);
+        assert!(client.has_valid_claim_authed(&verifier, &subject, &kyc));
+        assert_eq!(client.get_attestation(&id).expiration, Some(2_000));
+
+        env.ledger().with_mut(|l| l.timestamp = 1_950);
+        assert!(client.has_valid_claim_authed(&verifier, &subject, &kyc));
+        assert_eq!(client.get_attestation(&id).expiration, Some(2_500));
+    }
+
+    #[test]
+    fn test_expired_attestation_not_extended() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let (subject, id) = configured(&env, &client, &admin, &issuer);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        assert!(!client.has_valid_claim_authed(&Address::generate(&env), &subject, &String::from_str(&env, "KYC")));
+        assert_eq!(client.get_attestation(&id).expiration, Some(2_000));
+    }
+
+    #[test]
+    fn test_requires_issuer_consent() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let (subject, id) = configured(&env, &client, &admin, &issuer);
+        let kyc = String::from_str(&env, "KYC");
+        client.set_auto_extend_consent(&issuer, &kyc, &false);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_950);
+        assert!(client.has_valid_claim_authed(&Address::generate(&env), &subject, &kyc));
+        assert_eq!(client.get_attestation(&id).expiration, Some(2_000));
+    }
+
+    #[test]
+    fn test_paused_issuer_not_extended() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let (subject, id) = configured(&env, &client, &admin, &issuer);
+        client.pause_issuer(&admin, &issuer);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_950);
+        client.has_valid_claim_authed(&Address::generate(&env), &subject, &String::from_str(&env, "KYC"));
+        assert_eq!(client.get_attestation(&id).expiration, Some(2_000));
+    }
+
+    #[test]
+    fn test_extensions_capped_per_attestation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let (subject, id) = configured(&env, &client, &admin, &issuer);
+        let kyc = String::from_str(&env, "KYC");
+
+        env.ledger().with_mut(|l| l.timestamp = 1_950);
+        assert!(client.has_valid_claim_authed(&Address::generate(&env), &subject, &kyc));
+        assert_eq!(client.get_attestation(&id).expiration, Some(2_500));
+
+        env.ledger().with_mut(|l| l.timestamp = 2_450);
+        assert!(client.has_valid_claim_authed(&Address::generate(&env), &subject, &kyc));
+        assert_eq!(client.get_attestation(&id).expiration, Some(2_500));
+    }
+}
+
+mod empty_claim_type_tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_claim_type_is_false() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        for _ in 0..10 {
+            let issuer = Address::generate(&env);
+            client.register_issuer(&admin, &issuer);
+            client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        }
+
+        env.budget().reset_default();
+        assert!(!client.has_valid_claim(&subject, &String::from_str(&env, "AML")));
+        let full_scan = env.budget().cpu_instruction_cost();
+
+        env.budget().reset_default();
+        assert!(!client.has_valid_claim(&subject, &String::from_str(&env, "")));
+        assert!(env.budget().cpu_instruction_cost() < full_scan);
+    }
+
+    #[test]
+    fn test_authed_variant_rejects_empty_claim_type() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        assert_eq!(
+            client.try_has_valid_claim_authed(&Address::generate(&env), &Address::generate(&env), &String::from_str(&env, "")),
+            Err(Ok(Error::InvalidClaimType))
+        );
+    }
+}
+
+mod score_range_tests {
+    use super::*;
+
+    #[test]
+    fn test_in_range_and_out_of_range() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let rep = String::from_str(&env, "REPUTATION");
+        client.create_attestation_with_score(&issuer, &subject, &rep, &650, &None, &None, &None);
+
+        assert!(client.has_valid_claim_in_range(&subject, &rep, &500, &800));
+        assert!(client.has_valid_claim_in_range(&subject, &rep, &650, &650));
+        assert!(!client.has_valid_claim_in_range(&subject, &rep, &700, &800));
+        assert!(!client.has_valid_claim_in_range(&subject, &rep, &0, &649));
+    }
+
+    #[test]
+    fn test_scoreless_attestation_excluded() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let rep = String::from_str(&env, "REPUTATION");
+        let id = client.create_attestation(&issuer, &subject, &rep, &None, &None, &None);
+
+        assert_eq!(client.get_attestation(&id).score, None);
+        assert!(!client.has_valid_claim_in_range(&subject, &rep, &i128::MIN, &i128::MAX));
+    }
+}
+
+mod compact_subject_index_tests {
+    use super::*;
+    use crate::storage::Storage;
+
+    #[test]
+    fn test_compaction_removes_orphaned_ids() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kept = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        let orphan = client.create_attestation(&issuer, &subject, &String::from_str(&env, "AML"), &None, &None, &None);
+        env.as_contract(&client.address, || Storage::remove_attestation(&env, &orphan));
+
+        assert_eq!(client.compact_subject_index(&subject, &subject), 1);
+        assert_eq!(client.get_subject_attestations(&subject, &0, &10), Vec::from_array(&env, [kept]));
+        assert_eq!(client.get_subject_attestation_count(&subject), 1);
+        assert_eq!(client.compact_subject_index(&admin, &subject), 0);
+    }
+
+    #[test]
+    fn test_compaction_cleans_claim_type_index() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let second = Address::generate(&env);
+        client.register_issuer(&admin, &second);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let kept = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        let orphan = client.create_attestation(&second, &subject, &kyc, &None, &None, &None);
+        env.as_contract(&client.address, || Storage::remove_attestation(&env, &orphan));
+
+        assert_eq!(client.compact_subject_index(&subject, &subject), 1);
+        let by_type = env.as_contract(&client.address, || Storage::get_subject_claim_type_ids(&env, &subject, &kyc));
+        assert_eq!(by_type, Vec::from_array(&env, [kept]));
+    }
+
+    #[test]
+    fn test_other_callers_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        assert_eq!(
+            client.try_compact_subject_index(&issuer, &subject),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+}
+
+mod claim_type_count_tests {
+    use super::*;
+
+    #[test]
+    fn test_count_tracks_create_and_revoke() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let aml = String::from_str(&env, "AML");
+        let first = client.create_attestation(&issuer, &Address::generate(&env), &kyc, &None, &None, &None);
+        client.create_attestation(&issuer, &Address::generate(&env), &kyc, &None, &None, &None);
+        client.create_attestation(&issuer, &Address::generate(&env), &aml, &None, &None, &None);
+        assert_eq!(client.get_claim_type_count(&kyc), 2);
+        assert_eq!(client.get_claim_type_count(&aml), 1);
+
+        client.revoke_attestation(&issuer, &first, &None);
+        assert_eq!(client.get_claim_type_count(&kyc), 1);
+        assert_eq!(client.get_claim_type_count(&aml), 1);
+    }
+
+    #[test]
+    fn test_batch_create_counts_each_subject() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let subjects = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env), Address::generate(&env)]);
+        client.create_attestations_batch(&issuer, &subjects, &kyc, &None);
+        assert_eq!(client.get_claim_type_count(&kyc), 3);
+    }
+}
+
+mod revocable_flag_tests {
+    use super::*;
+
+    #[test]
+    fn test_issuer_cannot_revoke_irrevocable() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_irrevocable_attestation(
+            &issuer,
+            &subject,
+            &String::from_str(&env, "DIPLOMA"),
+            &None,
+            &None,
+            &None,
+        );
+        assert!(!client.get_attestation(&id).revocable);
+        assert_eq!(client.try_revoke_attestation(&issuer, &id, &None), Err(Ok(Error::NotRevocable)));
+        assert!(client.has_valid_claim(&subject, &String::from_str(&env, "DIPLOMA")));
+    }
+
+    #[test]
+    fn test_default_attestation_is_revocable() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let id = client.create_attestation(&issuer, &Address::generate(&env), &String::from_str(&env, "KYC"), &None, &None, &None);
+        assert!(client.get_attestation(&id).revocable);
+        client.revoke_attestation(&issuer, &id, &None);
+    }
+
+    #[test]
+    fn test_admin_override_requires_flag() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let id = client.create_irrevocable_attestation(
+            &issuer,
+            &Address::generate(&env),
+            &String::from_str(&env, "DIPLOMA"),
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(client.try_admin_revoke(&admin, &id, &None), Err(Ok(Error::NotRevocable)));
+        assert!(!client.get_admin_overrides_revocable());
+    }
+
+    #[test]
+    fn test_admin_override_set_at_initialization() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let client = TrustLinkContractClient::new(&env, &env.register_contract(None, TrustLinkContract));
+        let admin = Address::generate(&env);
+        let issuer = Address::generate(&env);
+        client.initialize_admin_override(&admin, &None);
+        client.register_issuer(&admin, &issuer);
+        assert!(client.get_admin_overrides_revocable());
+
+        let id = client.create_irrevocable_attestation(
+            &issuer,
+            &Address::generate(&env),
+            &String::from_str(&env, "DIPLOMA"),
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(client.try_revoke_attestation(&issuer, &id, &None), Err(Ok(Error::NotRevocable)));
+        client.admin_revoke(&admin, &id, &None);
+        assert!(client.get_attestation(&id).revoked);
+    }
+}
+
+mod issuer_attestations_full_tests {
+    use super::*;
+
+    #[test]
+    fn test_records_match_id_page() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        for _ in 0..5 {
+            client.create_attestation(&issuer, &Address::generate(&env), &String::from_str(&env, "KYC"), &None, &None, &None);
+        }
+
+        let ids = client.get_issuer_attestations(&issuer, &1, &3);
+        let records = client.get_issuer_attestations_full(&issuer, &1, &3);
+        assert_eq!(records.len(), 3);
+        for (id, record) in ids.iter().zip(records.iter()) {
+            assert_eq!(client.get_attestation(&id), record);
+        }
+        assert_eq!(client.get_issuer_attestations_full(&issuer, &5, &3).len(), 0);
+    }
+}
+
+mod issuer_key_rotation_tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{Bytes, BytesN};
+
+    fn pubkey(env: &Env, key: &SigningKey) -> BytesN<32> {
+        BytesN::from_array(env, &key.verifying_key().to_bytes())
+    }
+
+    fn sign(env: &Env, key: &SigningKey, payload: &Bytes) -> BytesN<64> {
+        let message: std::vec::Vec<u8> = payload.iter().collect();
+        BytesN::from_array(env, &key.sign(&message).to_bytes())
+    }
+
+    #[test]
+    fn test_rotation_invalidates_old_key() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let old_key = SigningKey::from_bytes(&[1u8; 32]);
+        let new_key = SigningKey::from_bytes(&[2u8; 32]);
+        let kyc = String::from_str(&env, "KYC");
+        client.rotate_issuer_key(&issuer, &pubkey(&env, &old_key));
+
+        let first = Address::generate(&env);
+        let payload = client.signed_attestation_payload(&issuer, &first, &kyc, &None);
+        let id = client.submit_signed_attestation(&issuer, &first, &kyc, &None, &sign(&env, &old_key, &payload));
+
+        client.rotate_issuer_key(&issuer, &pubkey(&env, &new_key));
+        assert_eq!(client.get_issuer_key(&issuer), Some(pubkey(&env, &new_key)));
+
+        let second = Address::generate(&env);
+        let payload = client.signed_attestation_payload(&issuer, &second, &kyc, &None);
+        assert!(client
+            .try_submit_signed_attestation(&issuer, &second, &kyc, &None, &sign(&env, &old_key, &payload))
+            .is_err());
+        client.submit_signed_attestation(&issuer, &second, &kyc, &None, &sign(&env, &new_key, &payload));
+
+        // Attestations created under the old key are unaffected.
+        assert!(client.has_valid_claim(&first, &kyc));
+        assert_eq!(client.get_attestation(&id).issuer, issuer);
+    }
+
+    #[test]
+    fn test_signature_cannot_be_replayed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let key = SigningKey::from_bytes(&[3u8; 32]);
+        client.rotate_issuer_key(&issuer, &pubkey(&env, &key));
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let payload = client.signed_attestation_payload(&issuer, &subject, &kyc, &None);
+        let signature = sign(&env, &key, &payload);
+        client.submit_signed_attestation(&issuer, &subject, &kyc, &None, &signature);
+
+        env.ledger().with_mut(|l| l.timestamp += 10);
+        assert!(client.try_submit_signed_attestation(&issuer, &subject, &kyc, &None, &signature).is_err());
+    }
+
+    #[test]
+    fn test_missing_key_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        assert_eq!(
+            client.try_submit_signed_attestation(
+                &issuer,
+                &Address::generate(&env),
+                &String::from_str(&env, "KYC"),
+                &None,
+                &BytesN::from_array(&env, &[0u8; 64]),
+            ),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+}
+
+mod expiry_histogram_tests {
+    use super::*;
+
+    const DAY: u64 = 86_400;
+
+    #[test]
+    fn test_counts_per_bucket() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let kyc = String::from_str(&env, "KYC");
+        let expirations = [
+            Some(1_000 + DAY / 2),
+            Some(1_000 + 3 * DAY),
+            Some(1_000 + 3 * DAY),
+            Some(1_000 + 10 * DAY),
+            Some(1_000 + 90 * DAY),
+            None,
+        ];
+        for expiration in expirations {
+            client.create_attestation(&issuer, &Address::generate(&env), &kyc, &expiration, &None, &None);
+        }
+
+        let histogram = client.get_issuer_expiry_histogram(&issuer, &100);
+        assert_eq!(
+            histogram,
+            ExpiryHistogram { under_1d: 1, under_7d: 2, under_30d: 1, over_30d: 1, never: 1, truncated: false }
+        );
+
+        // Once the shortest one lapses it drops out; its neighbours shift buckets.
+        env.ledger().with_mut(|l| l.timestamp = 1_000 + 5 * DAY);
+        let later = client.get_issuer_expiry_histogram(&issuer, &100);
+        assert_eq!((later.under_1d, later.under_7d, later.under_30d), (0, 1, 0));
+    }
+
+    #[test]
+    fn test_scan_limit_sets_truncated() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        for _ in 0..3 {
+            client.create_attestation(&issuer, &Address::generate(&env), &String::from_str(&env, "KYC"), &None, &None, &None);
+        }
+        let histogram = client.get_issuer_expiry_histogram(&issuer, &2);
+        assert_eq!((histogram.never, histogram.truncated), (2, true));
+    }
+}
+
+mod revoke_event_payload_tests {
+    use super::*;
+
+    #[test]
+    fn test_revoke_event_carries_claim_type_and_subject() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let id = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        client.revoke_attestation(&issuer, &id, &Some(String::from_str(&env, "expired docs")));
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        let topic0: soroban_sdk::Symbol =
+            soroban_sdk::TryFromVal::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+        assert_eq!(topic0, soroban_sdk::symbol_short!("revoked"));
+        let (event_id, _reason, claim_type, event_subject): (String, Option<String>, String, Address) =
+            soroban_sdk::TryFromVal::try_from_val(&env, &data).unwrap();
+        assert_eq!(event_id, id);
+        assert_eq!(claim_type, kyc);
+        assert_eq!(event_subject, subject);
+    }
+}
+
+mod registry_check_ordering_tests {
+    use super::*;
+
+    #[test]
+    fn test_unregistered_type_rejected_before_duplicate_check() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.register_claim_type(&admin, &kyc, &String::from_str(&env, "desc"));
+        client.set_registered_claim_type(&admin, &true);
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        client.unregister_claim_type(&admin, &kyc);
+
+        // Same issuer, subject, type and timestamp would derive the existing id;
+        // the registry check must fire before the duplicate lookup.
+        let result = client.try_create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        assert_eq!(result, Err(Ok(Error::InvalidClaimType)));
+        assert_eq!(client.get_subject_attestations(&subject, &0, &10).len(), 1);
+    }
+
+    #[test]
+    fn test_delegate_cannot_attest_unregistered_type() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let delegate = Address::generate(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.set_registered_claim_type(&admin, &true);
+        client.delegate_claim_type(&issuer, &delegate, &kyc, &None);
+
+        let result = client.try_create_attestation_as_delegate(&delegate, &issuer, &subject, &kyc, &None, &None);
+        assert_eq!(result, Err(Ok(Error::InvalidClaimType)));
+        assert_eq!(client.get_subject_attestations(&subject, &0, &10).len(), 0);
+    }
+}
+
+mod sweep_expired_tests {
+    use super::*;
+
+    #[test]
+    fn test_sweep_removes_only_long_expired() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let aml = String::from_str(&env, "AML");
+        let expired = client.create_attestation(&issuer, &subject, &kyc, &Some(2_000), &None, &None);
+        let valid = client.create_attestation(&issuer, &subject, &aml, &None, &None, &None);
+
+        // Expired but still inside the buffer: nothing is pruned.
+        env.ledger().with_mut(|l| l.timestamp = 2_000 + crate::constants::SWEEP_BUFFER_SECS - 1);
+        assert_eq!(client.sweep_expired(&10), 0);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000 + crate::constants::SWEEP_BUFFER_SECS);
+        assert_eq!(client.sweep_expired(&10), 1);
+        assert_eq!(client.try_get_attestation(&expired), Err(Ok(Error::NotFound)));
+        assert_eq!(client.get_attestation(&valid).id, valid);
+        let ids = client.get_subject_attestations(&subject, &0, &10);
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids.get(0).unwrap(), valid);
+        assert_eq!(client.get_claim_type_count(&kyc), 0);
+    }
+
+    #[test]
+    fn test_sweep_respects_limit() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC");
+        for _ in 0..3 {
+            client.create_attestation(&issuer, &Address::generate(&env), &kyc, &Some(2_000), &None, &None);
+        }
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000 + crate::constants::SWEEP_BUFFER_SECS);
+        assert_eq!(client.sweep_expired(&2), 2);
+        assert_eq!(client.sweep_expired(&2), 1);
+        assert_eq!(client.sweep_expired(&2), 0);
+    }
+
+    #[test]
+    fn test_sweep_reaches_removed_issuers() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let id = client.create_attestation(&issuer, &Address::generate(&env), &kyc, &Some(2_000), &None, &None);
+        client.remove_issuer(&admin, &issuer);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000 + crate::constants::SWEEP_BUFFER_SECS);
+        assert_eq!(client.sweep_expired(&10), 1);
+        assert_eq!(client.try_get_attestation(&id), Err(Ok(Error::NotFound)));
+    }
+
+    #[test]
+    fn test_sweep_reaches_revoked_attestations() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &Some(2_000), &None, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000 + crate::constants::SWEEP_BUFFER_SECS);
+        assert_eq!(client.sweep_expired(&10), 1);
+        assert_eq!(client.try_get_attestation(&id), Err(Ok(Error::NotFound)));
+        env.as_contract(&client.address, || {
+            assert!(Storage::get_revoked_sweep_ids(&env).is_empty());
+            assert!(Storage::get_subject_revoked(&env, &subject).is_empty());
+        });
+    }
+
+    #[test]
+    fn test_sweep_scan_is_bounded_and_resumes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC");
+        for _ in 0..2 {
+            let mut subjects = Vec::new(&env);
+            for _ in 0..crate::constants::MAX_SWEEP_SCAN / 2 {
+                subjects.push_back(Address::generate(&env));
+            }
+            client.create_attestations_batch(&issuer, &subjects, &kyc, &None);
+        }
+        let expired = client.create_attestation(&issuer, &Address::generate(&env), &kyc, &Some(2_000), &None, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000 + crate::constants::SWEEP_BUFFER_SECS);
+        assert_eq!(client.sweep_expired(&10), 0);
+        assert_eq!(client.sweep_expired(&10), 1);
+        assert_eq!(client.try_get_attestation(&expired), Err(Ok(Error::NotFound)));
+    }
+}
+
+mod trusted_issuer_list_tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_from_listed_issuer_is_valid() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+
+        let trusted = Vec::from_array(&env, [Address::generate(&env), issuer.clone()]);
+        assert!(client.has_valid_claim_from_any(&subject, &kyc, &trusted));
+    }
+
+    #[test]
+    fn test_claim_from_unlisted_issuer_is_ignored() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+
+        let trusted = Vec::from_array(&env, [Address::generate(&env)]);
+        assert!(!client.has_valid_claim_from_any(&subject, &kyc, &trusted));
+        assert!(!client.has_valid_claim_from_any(&subject, &kyc, &Vec::new(&env)));
+    }
+}
+
+mod stake_tests {
+    use super::*;
+
+    #[test]
+    fn test_stake_round_trips() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let id = client.create_staked_attestation(&issuer, &subject, &kyc, &5_000, &None, &None, &None);
+        assert_eq!(client.get_attestation(&id).stake, Some(5_000));
+
+        let plain = client.create_attestation(&issuer, &Address::generate(&env), &kyc, &None, &None, &None);
+        assert_eq!(client.get_attestation(&plain).stake, None);
+    }
+
+    #[test]
+    fn test_negative_stake_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let result = client.try_create_staked_attestation(
+            &issuer,
+            &subject,
+            &String::from_str(&env, "KYC"),
+            &-1,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidStake)));
+        assert_eq!(client.get_subject_attestations(&subject, &0, &10).len(), 0);
+    }
+}
+
+mod superseded_status_tests {
+    use super::*;
+
+    #[test]
+    fn test_superseded_status_differs_from_revoked() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let other = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let old = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        let plain = client.create_attestation(&issuer, &other, &kyc, &None, &None, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        let new_id = client.supersede_attestation(&issuer, &old, &None, &None);
+        client.revoke_attestation(&issuer, &plain, &None);
+
+        assert_eq!(client.get_attestation_status(&old), types::AttestationStatus::Superseded);
+        assert_eq!(client.get_attestation_status(&plain), types::AttestationStatus::Revoked);
+        assert_eq!(client.get_attestation_status(&new_id), types::AttestationStatus::Valid);
+
+        let superseded = client.get_attestation(&old);
+        assert_eq!(superseded.superseded_by, Some(new_id));
+        assert_eq!(superseded.revocation_reason, Some(String::from_str(&env, "Superseded")));
+        assert!(client.has_valid_claim(&subject, &kyc));
+        assert!(!client.has_valid_claim(&other, &kyc));
+    }
+
+    #[test]
+    fn test_superseded_attestation_is_invalid() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let old = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        let new_id = client.supersede_attestation(&issuer, &old, &None, &None);
+        client.revoke_attestation(&issuer, &new_id, &None);
+
+        assert!(!client.has_valid_claim(&subject, &kyc));
+        assert_eq!(client.try_supersede_attestation(&issuer, &old, &None, &None), Err(Ok(Error::AlreadyRevoked)));
+    }
+}
+
+mod supersession_chain_tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_follows_links_in_order() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let first = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        let second = client.supersede_attestation(&issuer, &first, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 3_000);
+        let third = client.supersede_attestation(&issuer, &second, &None, &None);
+
+        let chain = client.get_supersession_chain(&first, &10);
+        assert_eq!(chain, Vec::from_array(&env, [first.clone(), second.clone(), third.clone()]));
+
+        let from_middle = client.get_supersession_chain(&second, &10);
+        assert_eq!(from_middle, Vec::from_array(&env, [second, third]));
+
+        assert_eq!(client.get_supersession_chain(&first, &1).len(), 2);
+        assert_eq!(client.get_supersession_chain(&String::from_str(&env, "missing"), &10).len(), 0);
+    }
+
+    #[test]
+    fn test_chain_stops_on_cycle() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let first = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        let second = client.supersede_attestation(&issuer, &first, &None, &None);
+
+        // Forge a loop back to the start; the walk must terminate.
+        env.as_contract(&client.address, || {
+            use crate::storage::Storage;
+            let mut attestation = Storage::get_attestation(&env, &second).unwrap();
+            attestation.superseded_by = Some(first.clone());
+            Storage::set_attestation(&env, &attestation);
+        });
+        let chain = client.get_supersession_chain(&first, &10);
+        assert_eq!(chain, Vec::from_array(&env, [first, second]));
+    }
+}
+
+mod get_admin_or_tests {
+    use super::*;
+
+    #[test]
+    fn test_returns_fallback_before_initialize() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TrustLinkContract);
+        let client = TrustLinkContractClient::new(&env, &contract_id);
+        let fallback = Address::generate(&env);
+        assert_eq!(client.get_admin_or(&fallback), fallback);
+    }
+
+    #[test]
+    fn test_returns_admin_after_initialize() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        assert_eq!(client.get_admin_or(&Address::generate(&env)), admin);
+    }
+}
+
+mod relative_expiration_tests {
+    use super::*;
+
+    #[test]
+    fn test_expiration_is_ledger_time_plus_duration() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 5_000);
+        let (_admin, issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let id = client.create_attestation_relative(&issuer, &Address::generate(&env), &kyc, &Some(86_400));
+        let attestation = client.get_attestation(&id);
+        assert_eq!(attestation.expiration, Some(attestation.timestamp + 86_400));
+        assert_eq!(attestation.expiration, Some(5_000 + 86_400));
+    }
+
+    #[test]
+    fn test_none_is_permanent() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let id = client.create_attestation_relative(&issuer, &Address::generate(&env), &String::from_str(&env, "KYC"), &None);
+        assert_eq!(client.get_attestation(&id).expiration, None);
+    }
+
+    #[test]
+    fn test_overflowing_duration_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 5_000);
+        let (_admin, issuer, client) = setup(&env);
+        let result = client.try_create_attestation_relative(
+            &issuer,
+            &Address::generate(&env),
+            &String::from_str(&env, "KYC"),
+            &Some(u64::MAX),
+        );
+        assert_eq!(result, Err(Ok(Error::InvalidExpiration)));
+    }
+}
+
+mod both_have_valid_claim_tests {
+    use super::*;
+
+    #[test]
+    fn test_both_valid() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let (a, b) = (Address::generate(&env), Address::generate(&env));
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &a, &kyc, &None, &None, &None);
+        client.create_attestation(&issuer, &b, &kyc, &None, &None, &None);
+        assert!(client.both_have_valid_claim(&a, &b, &kyc));
+    }
+
+    #[test]
+    fn test_one_valid() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let (a, b) = (Address::generate(&env), Address::generate(&env));
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &a, &kyc, &None, &None, &None);
+        assert!(!client.both_have_valid_claim(&a, &b, &kyc));
+        assert!(!client.both_have_valid_claim(&b, &a, &kyc));
+    }
+
+    #[test]
+    fn test_neither_valid() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC");
+        assert!(!client.both_have_valid_claim(&Address::generate(&env), &Address::generate(&env), &kyc));
+    }
+}
+
+mod expiration_lock_tests {
+    use super::*;
+
+    #[test]
+    fn test_locked_expiration_cannot_change() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let id = client.create_locked_attestation(&issuer, &Address::generate(&env), &kyc, &Some(5_000), &None, &None);
+        assert!(client.get_attestation(&id).expiration_locked);
+
+        assert_eq!(client.try_update_expiration(&issuer, &id, &Some(9_000)), Err(Ok(Error::ExpirationLocked)));
+        assert_eq!(client.try_renew_attestation(&issuer, &id, &1_000), Err(Ok(Error::ExpirationLocked)));
+        assert_eq!(client.get_attestation(&id).expiration, Some(5_000));
+    }
+
+    #[test]
+    fn test_unlocked_expiration_can_change() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let id = client.create_attestation(&issuer, &Address::generate(&env), &kyc, &Some(5_000), &None, &None);
+        assert!(!client.get_attestation(&id).expiration_locked);
+
+        client.update_expiration(&issuer, &id, &Some(9_000));
+        assert_eq!(client.get_attestation(&id).expiration, Some(9_000));
+    }
+}
+
+mod remove_issuers_batch_tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_skips_unregistered_and_emits_per_removal() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let second = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        client.register_issuer(&admin, &second);
+
+        let batch = Vec::from_array(&env, [issuer.clone(), stranger.clone(), second.clone()]);
+        assert_eq!(client.remove_issuers_batch(&admin, &batch), 2);
+        assert!(!client.is_issuer(&issuer));
+        assert!(!client.is_issuer(&second));
+
+        let removed_topic = soroban_sdk::symbol_short!("iss_rem");
+        let mut removed: Vec<Address> = Vec::new(&env);
+        for (_, topics, _) in env.events().all().iter() {
+            let topic0: Option<soroban_sdk::Symbol> =
+                soroban_sdk::TryFromVal::try_from_val(&env, &topics.get(0).unwrap()).ok();
+            if topic0 == Some(removed_topic.clone()) {
+                let who: Address = soroban_sdk::TryFromVal::try_from_val(&env, &topics.get(1).unwrap()).unwrap();
+                removed.push_back(who);
+            }
+        }
+        assert_eq!(removed, Vec::from_array(&env, [issuer, second]));
+    }
+
+    #[test]
+    fn test_batch_is_idempotent() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let batch = Vec::from_array(&env, [issuer.clone()]);
+        assert_eq!(client.remove_issuers_batch(&admin, &batch), 1);
+        assert_eq!(client.remove_issuers_batch(&admin, &batch), 0);
+    }
+
+    #[test]
+    fn test_batch_too_large_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let mut batch = Vec::new(&env);
+        for _ in 0..51 {
+            batch.push_back(Address::generate(&env));
+        }
+        assert_eq!(client.try_remove_issuers_batch(&admin, &batch), Err(Ok(Error::LimitExceeded)));
+    }
+}
+
+mod verify_claim_detailed_tests {
+    use super::*;
+    use crate::types::VerifyResult;
+
+    #[test]
+    fn test_valid() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        assert_eq!(client.verify_claim_detailed(&subject, &kyc), VerifyResult::Valid);
+    }
+
+    #[test]
+    fn test_no_attestation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        let result = client.verify_claim_detailed(&Address::generate(&env), &String::from_str(&env, "KYC"));
+        assert_eq!(result, VerifyResult::NoAttestation);
+    }
+
+    #[test]
+    fn test_expired() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &subject, &kyc, &Some(2_000), &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        assert_eq!(client.verify_claim_detailed(&subject, &kyc), VerifyResult::Expired);
+    }
+
+    #[test]
+    fn test_revoked() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let id = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+        assert_eq!(client.verify_claim_detailed(&subject, &kyc), VerifyResult::Revoked);
+    }
+
+    #[test]
+    fn test_frozen() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        client.pause_issuer(&admin, &issuer);
+        assert_eq!(client.verify_claim_detailed(&subject, &kyc), VerifyResult::Frozen);
+    }
+
+    #[test]
+    fn test_revoked_list_keeps_newest_revocations() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let total = crate::constants::MAX_SUBJECT_REVOKED + 1;
+        for i in 0..total {
+            let claim_type = String::from_str(&env, &std::format!("CLAIM_{i}"));
+            let id = client.create_attestation(&issuer, &subject, &claim_type, &None, &None, &None);
+            client.revoke_attestation(&issuer, &id, &None);
+        }
+        let oldest = String::from_str(&env, "CLAIM_0");
+        let newest = String::from_str(&env, &std::format!("CLAIM_{}", total - 1));
+        assert_eq!(client.verify_claim_detailed(&subject, &oldest), VerifyResult::NoAttestation);
+        assert_eq!(client.verify_claim_detailed(&subject, &newest), VerifyResult::Revoked);
+    }
+}
+
+mod revocation_cooldown_tests {
+    use super::*;
+
+    #[test]
+    fn test_recreation_blocked_until_cooldown_elapses() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.set_revocation_cooldown(&admin, &kyc, &500);
+        assert_eq!(client.get_revocation_cooldown(&kyc), 500);
+
+        let id = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 1_100);
+        client.revoke_attestation(&issuer, &id, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_599);
+        let result = client.try_create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        assert_eq!(result, Err(Ok(Error::RevocationCooldown)));
+
+        // Other subjects are unaffected.
+        client.create_attestation(&issuer, &Address::generate(&env), &kyc, &None, &None, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_600);
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        assert!(client.has_valid_claim(&subject, &kyc));
+    }
+
+    #[test]
+    fn test_no_cooldown_by_default() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let id = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_001);
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+    }
+
+    #[test]
+    fn test_supersede_not_blocked_by_cooldown() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.set_revocation_cooldown(&admin, &kyc, &500);
+        let id = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_100);
+        client.supersede_attestation(&issuer, &id, &None, &None);
+        assert!(client.has_valid_claim(&subject, &kyc));
+    }
+}
+
+mod subject_id_range_tests {
+    use super::*;
+
+    fn seed(env: &Env, client: &TrustLinkContractClient, issuer: &Address, subject: &Address) -> Vec<String> {
+        let mut ids = Vec::new(env);
+        for claim in ["A", "B", "C"] {
+            ids.push_back(client.create_attestation(issuer, subject, &String::from_str(env, claim), &None, &None, &None));
+        }
+        ids
+    }
+
+    #[test]
+    fn test_range_inside_index() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let ids = seed(&env, &client, &issuer, &subject);
+        assert_eq!(client.get_subject_attestation_id_range(&subject, &1, &2), ids.slice(1..3));
+    }
+
+    #[test]
+    fn test_range_past_end_is_clamped() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let ids = seed(&env, &client, &issuer, &subject);
+        assert_eq!(client.get_subject_attestation_id_range(&subject, &2, &10), ids.slice(2..3));
+        assert_eq!(client.get_subject_attestation_id_range(&subject, &3, &1).len(), 0);
+        assert_eq!(client.get_subject_attestation_id_range(&subject, &7, &1).len(), 0);
+        assert_eq!(client.get_subject_attestation_id_range(&subject, &1, &u32::MAX), ids.slice(1..3));
+    }
+}
+
+mod issuer_history_tests {
+    use super::*;
+
+    #[test]
+    fn test_authorization_at_past_times() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 100);
+        let (admin, _issuer, client) = setup(&env);
+        let issuer = Address::generate(&env);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.register_issuer(&admin, &issuer);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        client.remove_issuer(&admin, &issuer);
+        env.ledger().with_mut(|l| l.timestamp = 3_000);
+        client.register_issuer(&admin, &issuer);
+
+        assert!(!client.was_issuer_at(&issuer, &999));
+        assert!(client.was_issuer_at(&issuer, &1_000));
+        assert!(client.was_issuer_at(&issuer, &1_999));
+        assert!(!client.was_issuer_at(&issuer, &2_000));
+        assert!(!client.was_issuer_at(&issuer, &2_999));
+        assert!(client.was_issuer_at(&issuer, &3_000));
+        assert!(client.was_issuer_at(&issuer, &u64::MAX));
+        assert!(!client.was_issuer_at(&Address::generate(&env), &3_000));
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let issuer = Address::generate(&env);
+        for i in 0..10u64 {
+            env.ledger().with_mut(|l| l.timestamp = 1_000 * (2 * i + 1));
+            client.register_issuer(&admin, &issuer);
+            env.ledger().with_mut(|l| l.timestamp = 1_000 * (2 * i + 2));
+            client.remove_issuer(&admin, &issuer);
+        }
+        env.as_contract(&client.address, || {
+            use crate::storage::Storage;
+            assert_eq!(Storage::get_issuer_history(&env, &issuer).len(), crate::constants::MAX_ISSUER_HISTORY);
+        });
+        // The last registration window is still answerable.
+        assert!(client.was_issuer_at(&issuer, &19_500));
+        assert!(!client.was_issuer_at(&issuer, &20_000));
+    }
+}
+
+mod admin_revoke_subject_claim_type_tests {
+    use super::*;
+
+    #[test]
+    fn test_revokes_type_across_issuers_only() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let second = Address::generate(&env);
+        client.register_issuer(&admin, &second);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let aml = String::from_str(&env, "AML");
+        let a = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        let b = client.create_attestation(&second, &subject, &kyc, &None, &None, &None);
+        client.create_attestation(&issuer, &subject, &aml, &None, &None, &None);
+
+        assert_eq!(client.admin_revoke_subject_claim_type(&admin, &subject, &kyc, &10), 2);
+        assert!(!client.has_valid_claim(&subject, &kyc));
+        assert!(client.has_valid_claim(&subject, &aml));
+        let reason = Some(String::from_str(&env, "AdminAction"));
+        assert_eq!(client.get_attestation(&a).revocation_reason, reason);
+        assert_eq!(client.get_attestation(&b).revocation_reason, reason);
+    }
+
+    #[test]
+    fn test_respects_limit() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let second = Address::generate(&env);
+        client.register_issuer(&admin, &second);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        client.create_attestation(&second, &subject, &kyc, &None, &None, &None);
+
+        assert_eq!(client.admin_revoke_subject_claim_type(&admin, &subject, &kyc, &1), 1);
+        assert!(client.has_valid_claim(&subject, &kyc));
+    }
+
+    #[test]
+    fn test_non_admin_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let result = client.try_admin_revoke_subject_claim_type(
+            &issuer,
+            &Address::generate(&env),
+            &String::from_str(&env, "KYC"),
+            &10,
+        );
+        assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    }
+}
+
+mod attestation_issuer_tests {
+    use super::*;
+
+    #[test]
+    fn test_returns_issuer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let id = client.create_attestation(&issuer, &Address::generate(&env), &String::from_str(&env, "KYC"), &None, &None, &None);
+        assert_eq!(client.get_attestation_issuer(&id), issuer);
+    }
+
+    #[test]
+    fn test_missing_attestation_not_found() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        assert_eq!(client.try_get_attestation_issuer(&String::from_str(&env, "missing")), Err(Ok(Error::NotFound)));
+    }
+}
+
+mod claim_state_tests {
+    use super::*;
+    use crate::types::ClaimState;
+
+    #[test]
+    fn test_expired_beats_revoked() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &subject, &kyc, &Some(2_000), &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 1_500);
+        let revoked = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        client.revoke_attestation(&issuer, &revoked, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        assert_eq!(client.get_claim_state(&subject, &kyc), ClaimState::Expired);
+    }
+
+    #[test]
+    fn test_active_revoked_and_none() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        assert_eq!(client.get_claim_state(&subject, &kyc), ClaimState::None);
+
+        let id = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        assert_eq!(client.get_claim_state(&subject, &kyc), ClaimState::Active);
+
+        client.revoke_attestation(&issuer, &id, &None);
+        assert_eq!(client.get_claim_state(&subject, &kyc), ClaimState::Revoked);
+    }
+}
+
+mod issuer_registry_freeze_tests {
+    use super::*;
+
+    #[test]
+    fn test_registration_blocked_while_frozen() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let newcomer = Address::generate(&env);
+        client.freeze_issuer_registry(&admin);
+        assert!(client.is_issuer_registry_frozen());
+
+        assert_eq!(client.try_register_issuer(&admin, &newcomer), Err(Ok(Error::RegistryFrozen)));
+        assert_eq!(client.try_remove_issuer(&admin, &issuer), Err(Ok(Error::RegistryFrozen)));
+
+        // Attestation creation keeps working.
+        client.create_attestation(&issuer, &Address::generate(&env), &String::from_str(&env, "KYC"), &None, &None, &None);
+
+        client.unfreeze_issuer_registry(&admin);
+        assert!(!client.is_issuer_registry_frozen());
+        client.register_issuer(&admin, &newcomer);
+        assert!(client.is_issuer(&newcomer));
+    }
+
+    #[test]
+    fn test_non_admin_cannot_freeze() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        assert_eq!(client.try_freeze_issuer_registry(&issuer), Err(Ok(Error::Unauthorized)));
+    }
+
+    #[test]
+    fn test_sub_issuer_changes_blocked_while_frozen() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let existing = Address::generate(&env);
+        client.add_sub_issuer(&issuer, &existing);
+        client.freeze_issuer_registry(&admin);
+
+        assert_eq!(client.try_add_sub_issuer(&issuer, &Address::generate(&env)), Err(Ok(Error::RegistryFrozen)));
+        assert_eq!(client.try_remove_sub_issuer(&issuer, &existing), Err(Ok(Error::RegistryFrozen)));
+        assert_eq!(client.get_sub_issuer_parent(&existing), Some(issuer));
+    }
+}
+
+mod pruned_status_tests {
+    use super::*;
+
+    #[test]
+    fn test_pruned_attestation_reports_not_found() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let id = client.create_attestation(&issuer, &subject, &kyc, &Some(2_000), &None, &None);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000 + crate::constants::SWEEP_BUFFER_SECS);
+        assert_eq!(client.get_attestation_status(&id), types::AttestationStatus::Expired);
+        assert_eq!(client.sweep_expired(&10), 1);
+
+        assert_eq!(client.try_get_attestation_status(&id), Err(Ok(Error::NotFound)));
+        assert_eq!(client.try_get_attestation(&id), Err(Ok(Error::NotFound)));
+        assert_eq!(client.try_get_attestation_issuer(&id), Err(Ok(Error::NotFound)));
+        env.as_contract(&client.address, || {
+            use crate::storage::Storage;
+            assert!(!Storage::has_attestation(&env, &id));
+        });
+        assert!(!client.get_subject_attestations(&subject, &0, &10).contains(&id));
+    }
+}
+
+mod subject_claim_type_index_tests {
+    use super::*;
+    use crate::storage::Storage;
+
+    fn index(env: &Env, client: &TrustLinkContractClient<'_>, subject: &Address, claim_type: &String) -> Vec<String> {
+        env.as_contract(&client.address, || Storage::get_subject_claim_type_ids(env, subject, claim_type))
+    }
+
+    #[test]
+    fn test_index_tracks_creates_and_revokes() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let second = Address::generate(&env);
+        client.register_issuer(&admin, &second);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let aml = String::from_str(&env, "AML");
+
+        let a = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        let b = client.create_attestation(&second, &subject, &kyc, &None, &None, &None);
+        let c = client.create_attestation(&issuer, &subject, &aml, &None, &None, &None);
+        assert_eq!(index(&env, &client, &subject, &kyc), Vec::from_array(&env, [a.clone(), b.clone()]));
+        assert_eq!(index(&env, &client, &subject, &aml), Vec::from_array(&env, [c]));
+
+        client.revoke_attestation(&issuer, &a, &None);
+        assert_eq!(index(&env, &client, &subject, &kyc), Vec::from_array(&env, [b.clone()]));
+        assert!(client.has_valid_claim(&subject, &kyc));
+
+        client.revoke_attestation(&second, &b, &None);
+        assert_eq!(index(&env, &client, &subject, &kyc).len(), 0);
+        assert!(!client.has_valid_claim(&subject, &kyc));
+        assert!(client.has_valid_claim(&subject, &aml));
+    }
+
+    #[test]
+    fn test_batch_creation_is_indexed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subjects = Vec::from_array(&env, [Address::generate(&env), Address::generate(&env)]);
+        let kyc = String::from_str(&env, "KYC");
+        let ids = client.create_attestations_batch(&issuer, &subjects, &kyc, &None);
+        for i in 0..2 {
+            let subject = subjects.get(i).unwrap();
+            assert_eq!(index(&env, &client, &subject, &kyc), Vec::from_array(&env, [ids.get(i).unwrap()]));
+            assert!(client.has_valid_claim(&subject, &kyc));
+        }
+    }
+
+    #[test]
+    fn test_expired_entry_still_reports_invalid() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &subject, &kyc, &Some(2_000), &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        assert_eq!(index(&env, &client, &subject, &kyc).len(), 1);
+        assert!(!client.has_valid_claim(&subject, &kyc));
+    }
+}
+
+mod issuer_total_tests {
+    use super::*;
+
+    #[test]
+    fn test_total_follows_registrations_and_removals() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        assert_eq!(client.get_issuer_total(), 1);
+
+        let (b, c) = (Address::generate(&env), Address::generate(&env));
+        client.register_issuer(&admin, &b);
+        client.register_issuer(&admin, &c);
+        client.register_issuer(&admin, &c);
+        assert_eq!(client.get_issuer_total(), 3);
+
+        client.remove_issuer(&admin, &issuer);
+        assert_eq!(client.get_issuer_total(), 2);
+        client.remove_issuers_batch(&admin, &Vec::from_array(&env, [b, c]));
+        assert_eq!(client.get_issuer_total(), 0);
+        assert_eq!(client.get_issuer_list(&0, &10).len(), 0);
+    }
+}
+
+mod pin_attestation_tests {
+    use super::*;
+
+    #[test]
+    fn test_pinned_attestation_survives_sweep() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let pinned = client.create_attestation(&issuer, &subject, &kyc, &Some(2_000), &None, &None);
+        let other = client.create_attestation(&issuer, &subject, &String::from_str(&env, "AML"), &Some(2_000), &None, &None);
+        client.pin_attestation(&subject, &pinned);
+        assert!(client.get_attestation(&pinned).pinned);
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000 + crate::constants::SWEEP_BUFFER_SECS);
+        assert_eq!(client.sweep_expired(&10), 1);
+        assert_eq!(client.get_attestation(&pinned).id, pinned);
+        assert_eq!(client.try_get_attestation(&other), Err(Ok(Error::NotFound)));
+
+        client.unpin_attestation(&subject, &pinned);
+        assert_eq!(client.sweep_expired(&10), 1);
+        assert_eq!(client.try_get_attestation(&pinned), Err(Ok(Error::NotFound)));
+    }
+
+    #[test]
+    fn test_only_subject_can_pin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        assert_eq!(client.try_pin_attestation(&issuer, &id), Err(Ok(Error::Unauthorized)));
+        assert!(!client.get_attestation(&id).pinned);
+    }
+}
+
+mod has_n_of_claims_tests {
+    use super::*;
+
+    fn types(env: &Env) -> Vec<String> {
+        Vec::from_array(env, [String::from_str(env, "KYC"), String::from_str(env, "AML"), String::from_str(env, "ACCREDITED")])
+    }
+
+    #[test]
+    fn test_two_of_three_satisfied() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let claim_types = types(&env);
+        client.create_attestation(&issuer, &subject, &claim_types.get(0).unwrap(), &None, &None, &None);
+        client.create_attestation(&issuer, &subject, &claim_types.get(2).unwrap(), &None, &None, &None);
+
+        assert!(client.has_n_of_claims(&subject, &claim_types, &2));
+        assert!(!client.has_n_of_claims(&subject, &claim_types, &3));
+    }
+
+    #[test]
+    fn test_duplicates_count_once_and_n_above_len_fails() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+
+        let repeated = Vec::from_array(&env, [kyc.clone(), kyc.clone()]);
+        assert!(!client.has_n_of_claims(&subject, &repeated, &2));
+        assert!(!client.has_n_of_claims(&subject, &types(&env), &4));
+        assert!(client.has_n_of_claims(&subject, &types(&env), &0));
+    }
+}
+
+mod attestation_flat_tests {
+    use super::*;
+
+    #[test]
+    fn test_flat_tuple_matches_struct() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &Some(5_000), &None, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+
+        let attestation = client.get_attestation(&id);
+        let flat = client.get_attestation_flat(&id);
+        assert_eq!(
+            flat,
+            (
+                attestation.issuer,
+                attestation.subject,
+                attestation.claim_type,
+                attestation.timestamp,
+                attestation.expiration,
+                attestation.revoked,
+            )
+        );
+        assert!(flat.5);
+    }
+}
+
+mod are_issuers_tests {
+    use super::*;
+
+    #[test]
+    fn test_results_are_positional() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let second = Address::generate(&env);
+        client.register_issuer(&admin, &second);
+        let addresses = Vec::from_array(&env, [Address::generate(&env), issuer, Address::generate(&env), second]);
+        assert_eq!(client.are_issuers(&addresses), Vec::from_array(&env, [false, true, false, true]));
+    }
+
+    #[test]
+    fn test_too_many_addresses_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        let mut addresses = Vec::new(&env);
+        for _ in 0..51 {
+            addresses.push_back(Address::generate(&env));
+        }
+        assert_eq!(client.try_are_issuers(&addresses), Err(Ok(Error::LimitExceeded)));
+    }
+}
+
+mod sorted_by_expiration_tests {
+    use super::*;
+
+    #[test]
+    fn test_soonest_expiring_first_and_permanent_last() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let permanent = client.create_attestation(&issuer, &subject, &String::from_str(&env, "A"), &None, &None, &None);
+        let late = client.create_attestation(&issuer, &subject, &String::from_str(&env, "B"), &Some(9_000), &None, &None);
+        let soon = client.create_attestation(&issuer, &subject, &String::from_str(&env, "C"), &Some(2_000), &None, &None);
+        let middle = client.create_attestation(&issuer, &subject, &String::from_str(&env, "D"), &Some(5_000), &None, &None);
+
+        assert_eq!(
+            client.get_subject_attestations_sorted(&subject, &10),
+            Vec::from_array(&env, [soon, middle, late, permanent])
+        );
+    }
+
+    #[test]
+    fn test_scan_limit_bounds_considered_entries() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let first = client.create_attestation(&issuer, &subject, &String::from_str(&env, "A"), &Some(9_000), &None, &None);
+        let second = client.create_attestation(&issuer, &subject, &String::from_str(&env, "B"), &Some(5_000), &None, &None);
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "C"), &Some(2_000), &None, &None);
+
+        assert_eq!(client.get_subject_attestations_sorted(&subject, &2), Vec::from_array(&env, [second, first]));
+        assert_eq!(client.get_subject_attestations_sorted(&subject, &0).len(), 0);
+    }
+}
+
+mod attestation_status_or_tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_id_returns_not_found() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        let status = client.get_attestation_status_or(&String::from_str(&env, "no-such-id"));
+        assert_eq!(status, AttestationStatus::NotFound);
+    }
+
+    #[test]
+    fn test_existing_id_returns_its_status() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        assert_eq!(client.get_attestation_status_or(&id), AttestationStatus::Valid);
+
+        client.revoke_attestation(&issuer, &id, &None);
+        assert_eq!(client.get_attestation_status_or(&id), AttestationStatus::Revoked);
+    }
+}
+
+mod operation_timelock_tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_issuer_requires_queue_and_delay() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        client.set_operation_timelock(&admin, &100);
+
+        assert_eq!(client.try_remove_issuer(&admin, &issuer), Err(Ok(Error::TimelockNotReady)));
+
+        let operation = TimelockOperation::RemoveIssuer(issuer.clone());
+        let op_hash = client.hash_operation(&operation);
+        assert_eq!(client.queue_operation(&admin, &op_hash), 1_100);
+        assert_eq!(client.get_queued_operation(&op_hash), Some(1_100));
+
+        env.ledger().with_mut(|l| l.timestamp = 1_099);
+        assert_eq!(client.try_execute_operation(&admin, &operation), Err(Ok(Error::TimelockNotReady)));
+        assert!(client.is_issuer(&issuer));
+
+        env.ledger().with_mut(|l| l.timestamp = 1_100);
+        client.execute_operation(&admin, &operation);
+        assert!(!client.is_issuer(&issuer));
+        assert_eq!(client.get_queued_operation(&op_hash), None);
+        assert_eq!(client.try_execute_operation(&admin, &operation), Err(Ok(Error::NotFound)));
+    }
+
+    #[test]
+    fn test_admin_revoke_through_queue() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None);
+        client.set_operation_timelock(&admin, &50);
+
+        assert_eq!(client.try_admin_revoke(&admin, &id, &None), Err(Ok(Error::TimelockNotReady)));
+
+        let operation = TimelockOperation::AdminRevoke(id.clone(), None);
+        client.queue_operation(&admin, &client.hash_operation(&operation));
+        env.ledger().with_mut(|l| l.timestamp = 1_050);
+        client.execute_operation(&admin, &operation);
+        assert!(client.get_attestation(&id).revoked);
+    }
+
+    #[test]
+    fn test_remove_issuers_batch_through_queue() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        let other = Address::generate(&env);
+        client.register_issuer(&admin, &other);
+        client.set_operation_timelock(&admin, &100);
+        let issuers = Vec::from_array(&env, [issuer.clone(), other.clone()]);
+
+        assert_eq!(client.try_remove_issuers_batch(&admin, &issuers), Err(Ok(Error::TimelockNotReady)));
+
+        let operation = TimelockOperation::RemoveIssuers(issuers);
+        client.queue_operation(&admin, &client.hash_operation(&operation));
+        env.ledger().with_mut(|l| l.timestamp = 1_100);
+        client.execute_operation(&admin, &operation);
+        assert!(!client.is_issuer(&issuer));
+        assert!(!client.is_issuer(&other));
+    }
+
+    #[test]
+    fn test_admin_revoke_subject_claim_type_through_queue() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let id = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        client.set_operation_timelock(&admin, &50);
+
+        assert_eq!(
+            client.try_admin_revoke_subject_claim_type(&admin, &subject, &kyc, &10),
+            Err(Ok(Error::TimelockNotReady))
+        );
+
+        let operation = TimelockOperation::AdminRevokeSubjectClaimType(subject.clone(), kyc.clone(), 10);
+        client.queue_operation(&admin, &client.hash_operation(&operation));
+        env.ledger().with_mut(|l| l.timestamp = 1_050);
+        client.execute_operation(&admin, &operation);
+        assert!(client.get_attestation(&id).revoked);
+        assert!(!client.has_valid_claim(&subject, &kyc));
+    }
+
+    #[test]
+    fn test_unqueued_operation_not_found_and_disabled_timelock_allows_direct_calls() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        let operation = TimelockOperation::RemoveIssuer(issuer.clone());
+        assert_eq!(client.try_execute_operation(&admin, &operation), Err(Ok(Error::NotFound)));
+
+        client.set_operation_timelock(&admin, &100);
+        let disable = TimelockOperation::SetTimelock(0);
+        client.queue_operation(&admin, &client.hash_operation(&disable));
+        env.ledger().with_mut(|l| l.timestamp = 1_100);
+        client.execute_operation(&admin, &disable);
+        assert_eq!(client.get_operation_timelock(), 0);
+        client.remove_issuer(&admin, &issuer);
+        assert!(!client.is_issuer(&issuer));
+    }
+
+    #[test]
+    fn test_lowering_timelock_directly_rejected_raising_allowed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        client.set_operation_timelock(&admin, &100);
+
+        assert_eq!(client.try_set_operation_timelock(&admin, &0), Err(Ok(Error::TimelockNotReady)));
+        assert_eq!(client.try_set_operation_timelock(&admin, &99), Err(Ok(Error::TimelockNotReady)));
+        client.set_operation_timelock(&admin, &200);
+        assert_eq!(client.get_operation_timelock(), 200);
+    }
+
+    #[test]
+    fn test_council_issuer_removal_waits_for_operation_timelock() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        let second_admin = Address::generate(&env);
+        client.add_admin(&admin, &second_admin);
+        client.set_operation_timelock(&admin, &100);
+
+        let proposal_id = client.create_council_proposal(&admin, &CouncilOperation::RemoveIssuer(issuer.clone()));
+        client.approve_council_proposal(&second_admin, &proposal_id);
+        assert_eq!(client.try_execute_council_action(&admin, &proposal_id), Err(Ok(Error::TimelockNotReady)));
+
+        env.ledger().with_mut(|l| l.timestamp = 1_100);
+        client.execute_council_action(&admin, &proposal_id);
+        assert!(!client.is_issuer(&issuer));
+    }
+}
+
+mod resolve_valid_claim_tests {
+    use super::*;
+
+    #[test]
+    fn test_returns_most_recent_valid_id() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        let second_issuer = Address::generate(&env);
+        client.register_issuer(&admin, &second_issuer);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        let newer = client.create_attestation(&second_issuer, &subject, &kyc, &None, &None, &None);
+
+        assert_eq!(client.resolve_valid_claim(&subject, &kyc), Some(newer));
+    }
+
+    #[test]
+    fn test_none_when_only_expired_or_revoked() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        let second_issuer = Address::generate(&env);
+        client.register_issuer(&admin, &second_issuer);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &subject, &kyc, &Some(1_500), &None, &None);
+        let revoked = client.create_attestation(&second_issuer, &subject, &kyc, &None, &None, &None);
+        client.revoke_attestation(&second_issuer, &revoked, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+
+        assert_eq!(client.resolve_valid_claim(&subject, &kyc), None);
+        assert_eq!(client.resolve_valid_claim(&Address::generate(&env), &kyc), None);
+    }
+}
+
+mod renounce_issuer_tests {
+    use super::*;
+
+    #[test]
+    fn test_renounced_issuer_cannot_create_but_keeps_attestations() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+
+        client.renounce_issuer(&issuer);
+
+        assert!(!client.is_issuer(&issuer));
+        assert!(client.has_valid_claim(&subject, &kyc));
+        let other = Address::generate(&env);
+        assert_eq!(
+            client.try_create_attestation(&issuer, &other, &kyc, &None, &None, &None),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_admin_can_reregister_and_non_issuer_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        client.renounce_issuer(&issuer);
+        assert_eq!(client.try_renounce_issuer(&issuer), Err(Ok(Error::Unauthorized)));
+
+        client.register_issuer(&admin, &issuer);
+        assert!(client.is_issuer(&issuer));
+    }
+}
+
+mod claim_type_normalization_tests {
+    use super::*;
+
+    fn setup_normalized(env: &Env) -> (Address, Address, TrustLinkContractClient<'_>) {
+        let contract_id = env.register_contract(None, TrustLinkContract);
+        let client = TrustLinkContractClient::new(env, &contract_id);
+        let admin = Address::generate(env);
+        let issuer = Address::generate(env);
+        client.initialize_normalized(&admin, &None);
+        client.register_issuer(&admin, &issuer);
+        (admin, issuer, client)
+    }
+
+    #[test]
+    fn test_lowercase_create_matches_uppercase_query() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup_normalized(&env);
+        let subject = Address::generate(&env);
+        let id = client.create_attestation(&issuer, &subject, &String::from_str(&env, "kyc_passed"), &None, &None, &None);
+
+        assert!(client.is_normalize_claim_types());
+        assert_eq!(client.get_attestation(&id).claim_type, String::from_str(&env, "KYC_PASSED"));
+        assert!(client.has_valid_claim(&subject, &String::from_str(&env, "KYC_PASSED")));
+        assert!(client.has_valid_claim(&subject, &String::from_str(&env, "Kyc_Passed")));
+        assert_eq!(client.resolve_valid_claim(&subject, &String::from_str(&env, "kyc_passed")), Some(id));
+    }
+
+    #[test]
+    fn test_matching_is_case_sensitive_without_flag() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "kyc_passed"), &None, &None, &None);
+
+        assert!(!client.is_normalize_claim_types());
+        assert!(!client.has_valid_claim(&subject, &String::from_str(&env, "KYC_PASSED")));
+    }
+
+    #[test]
+    fn test_non_ascii_bytes_pass_through() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup_normalized(&env);
+        env.as_contract(&client.address, || {
+            let normalized = Validation::normalize_claim_type(&env, &String::from_str(&env, "kyc_é"));
+            assert_eq!(normalized, String::from_str(&env, "KYC_é"));
+        });
+    }
+
+    #[test]
+    fn test_admin_keys_and_other_entrypoints_are_normalized() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup_normalized(&env);
+        let subject = Address::generate(&env);
+        let delegate = Address::generate(&env);
+        client.set_registered_claim_type(&admin, &true);
+        client.register_claim_type(&admin, &String::from_str(&env, "kyc_passed"), &String::from_str(&env, "KYC"));
+        client.set_singleton_claim_type(&admin, &String::from_str(&env, "kyc_passed"), &true);
+        assert!(client.is_singleton_claim_type(&String::from_str(&env, "KYC_PASSED")));
+
+        client.create_attestation(&issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &None, &None, &None);
+        assert!(client.has_valid_claim_strict(&subject, &String::from_str(&env, "kyc_passed")));
+        assert!(client.has_fresh_valid_claim(&subject, &String::from_str(&env, "Kyc_Passed"), &3_600));
+
+        client.delegate_claim_type(&issuer, &delegate, &String::from_str(&env, "kyc_passed"), &None);
+        assert_eq!(
+            client.try_create_attestation_as_delegate(
+                &delegate, &issuer, &subject, &String::from_str(&env, "KYC_PASSED"), &None, &None,
+            ),
+            Err(Ok(Error::SingletonViolation))
+        );
+    }
+}
+
+mod revocation_subscription_tests {
+    use super::*;
+
+    fn notices(env: &Env) -> soroban_sdk::Vec<(Address, String)> {
+        let mut found = soroban_sdk::Vec::new(env);
+        for (_, topic, data) in env.events().all() {
+            let topic0: Result<soroban_sdk::Symbol, _> = soroban_sdk::TryFromVal::try_from_val(env, &topic.get(0).unwrap());
+            if topic0.ok() == Some(soroban_sdk::symbol_short!("rev_note")) {
+                let subscriber: Address = soroban_sdk::TryFromVal::try_from_val(env, &topic.get(1).unwrap()).unwrap();
+                let (_, _, attestation_id): (Address, String, String) =
+                    soroban_sdk::TryFromVal::try_from_val(env, &data).unwrap();
+                found.push_back((subscriber, attestation_id));
+            }
+        }
+        found
+    }
+
+    #[test]
+    fn test_revocation_notifies_each_subscriber() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        client.subscribe_revocation(&first, &subject, &kyc);
+        client.subscribe_revocation(&second, &subject, &kyc);
+        client.subscribe_revocation(&second, &subject, &kyc);
+        assert_eq!(client.get_revocation_subscribers(&subject, &kyc).len(), 2);
+
+        let id = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+
+        assert_eq!(notices(&env), Vec::from_array(&env, [(first, id.clone()), (second, id)]));
+    }
+
+    #[test]
+    fn test_other_claim_types_and_unsubscribed_not_notified() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let subscriber = Address::generate(&env);
+        client.subscribe_revocation(&subscriber, &subject, &kyc);
+
+        let other = client.create_attestation(&issuer, &subject, &String::from_str(&env, "AML"), &None, &None, &None);
+        client.revoke_attestation(&issuer, &other, &None);
+        assert_eq!(notices(&env).len(), 0);
+
+        client.unsubscribe_revocation(&subscriber, &subject, &kyc);
+        let id = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        client.revoke_attestation(&issuer, &id, &None);
+        assert_eq!(notices(&env).len(), 0);
+    }
+
+    #[test]
+    fn test_subscription_requires_subject_consent() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let subscriber = Address::generate(&env);
+        client.subscribe_revocation(&subscriber, &subject, &String::from_str(&env, "KYC"));
+
+        let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(address, _)| address).collect();
+        assert!(signers.contains(&subscriber));
+        assert!(signers.contains(&subject));
+    }
+
+    #[test]
+    fn test_supersession_notifies_subscribers() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let subscriber = Address::generate(&env);
+        client.subscribe_revocation(&subscriber, &subject, &kyc);
+
+        let id = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        client.supersede_attestation(&issuer, &id, &None, &None);
+        assert_eq!(notices(&env), Vec::from_array(&env, [(subscriber, id)]));
+    }
+
+    #[test]
+    fn test_subscriber_list_is_bounded() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, _issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        for _ in 0..crate::constants::MAX_REVOCATION_SUBSCRIBERS {
+            client.subscribe_revocation(&Address::generate(&env), &subject, &kyc);
+        }
+        assert_eq!(
+            client.try_subscribe_revocation(&Address::generate(&env), &subject, &kyc),
+            Err(Ok(Error::LimitExceeded))
+        );
+    }
+}
+
+mod claim_type_id_derivation_tests {
+    use super::*;
+
+    #[test]
+    fn test_import_rejects_delimiter_in_claim_type() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        assert_eq!(
+            client.try_import_attestation(&admin, &issuer, &subject, &String::from_str(&env, "KYC|PASSED"), &500, &None),
+            Err(Ok(Error::InvalidClaimType))
+        );
+    }
+
+    #[test]
+    fn test_bridge_rejects_delimiter_in_claim_type() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, _issuer, client) = setup(&env);
+        let bridge = Address::generate(&env);
+        client.register_bridge(&admin, &bridge);
+        let subject = Address::generate(&env);
+        assert_eq!(
+            client.try_bridge_attestation(
+                &bridge,
+                &subject,
+                &String::from_str(&env, "KYC:ETH"),
+                &String::from_str(&env, "ethereum"),
+                &String::from_str(&env, "0xabc"),
+            ),
+            Err(Ok(Error::InvalidClaimType))
+        );
+    }
+}
+
+mod rebuild_subject_index_tests {
+    use super::*;
+
+    #[test]
+    fn test_correct_candidates_reconstruct_index() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let first = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        let second = client.create_attestation(&issuer, &subject, &String::from_str(&env, "AML"), &None, &None, &None);
+
+        // Simulate a diverged index.
+        env.as_contract(&client.address, || {
+            use crate::storage::Storage;
+            Storage::set_subject_attestations(&env, &subject, &Vec::new(&env));
+            Storage::set_valid_attestations(&env, &subject, &Vec::new(&env));
+            Storage::set_subject_claim_type_ids(&env, &subject, &kyc, &Vec::new(&env));
+        });
+        assert!(!client.has_valid_claim(&subject, &kyc));
+
+        let candidates = Vec::from_array(&env, [first.clone(), second.clone(), first.clone()]);
+        assert_eq!(client.rebuild_subject_index(&admin, &subject, &candidates), 2);
+        assert_eq!(client.get_subject_attestations(&subject, &0, &10), Vec::from_array(&env, [first, second]));
+        assert!(client.has_valid_claim(&subject, &kyc));
+    }
+
+    #[test]
+    fn test_legacy_subject_verifies_and_is_backfilled() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let id = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+
+        // A subject indexed before the per-claim-type list: valid index only.
+        env.as_contract(&client.address, || {
+            use crate::storage::Storage;
+            Storage::set_subject_claim_type_ids(&env, &subject, &kyc, &Vec::new(&env));
+        });
+
+        assert!(client.has_valid_claim(&subject, &kyc));
+        assert!(!client.has_valid_claim(&subject, &String::from_str(&env, "AML")));
+        env.as_contract(&client.address, || {
+            use crate::storage::Storage;
+            assert_eq!(Storage::get_subject_claim_type_ids(&env, &subject, &kyc), Vec::from_array(&env, [id]));
+        });
+    }
+
+    #[test]
+    fn test_mismatched_revoked_and_unknown_ids_excluded() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let other = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        let own = client.create_attestation(&issuer, &subject, &kyc, &None, &None, &None);
+        let foreign = client.create_attestation(&issuer, &other, &kyc, &None, &None, &None);
+        let revoked = client.create_attestation(&issuer, &subject, &String::from_str(&env, "AML"), &None, &None, &None);
+        client.revoke_attestation(&issuer, &revoked, &None);
+
+        let candidates = Vec::from_array(&env, [foreign, own.clone(), revoked, String::from_str(&env, "missing")]);
+        assert_eq!(client.rebuild_subject_index(&admin, &subject, &candidates), 1);
+        assert_eq!(client.get_subject_attestations(&subject, &0, &10), Vec::from_array(&env, [own]));
+    }
+
+    #[test]
+    fn test_non_admin_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        assert_eq!(
+            client.try_rebuild_subject_index(&issuer, &subject, &Vec::new(&env)),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+}
+
+mod verify_claim_historical_tests {
+    use super::*;
+
+    fn setup_at(env: &Env) -> (Address, Address, TrustLinkContractClient<'_>) {
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        setup(env)
+    }
+
+    #[test]
+    fn test_all_conditions_hold() {
+        let env = Env::default();
+        let (admin, issuer, client) = setup_at(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        client.create_attestation(&issuer, &subject, &kyc, &Some(5_000), &None, &None);
+
+        // Later removal of the issuer does not affect the audit result.
+        env.ledger().with_mut(|l| l.timestamp = 2_500);
+        client.remove_issuer(&admin, &issuer);
+        assert!(client.verify_claim_historical(&subject, &kyc, &3_000));
+    }
+
+    #[test]
+    fn test_fails_when_expired_at_as_of() {
+        let env = Env::default();
+        let (_admin, issuer, client) = setup_at(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        client.create_attestation(&issuer, &subject, &kyc, &Some(5_000), &None, &None);
+
+        assert!(!client.verify_claim_historical(&subject, &kyc, &5_000));
+        assert!(!client.verify_claim_historical(&subject, &kyc, &1_500));
+    }
+
+    #[test]
+    fn test_fails_when_issuer_not_authorized_at_creation() {
+        let env = Env::default();
+        let (admin, issuer, client) = setup_at(&env);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        client.import_attestation(&admin, &issuer, &subject, &kyc, &500, &None);
+
+        assert!(client.has_valid_claim_as_of(&subject, &kyc, &600));
+        assert!(!client.verify_claim_historical(&subject, &kyc, &600));
+    }
+}
+
+mod sub_issuer_parent_tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_records_parent_issuer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (_admin, issuer, client) = setup(&env);
+        let sub_issuer = Address::generate(&env);
+        client.add_sub_issuer(&issuer, &sub_issuer);
+        let subject = Address::generate(&env);
+        let ids = client.create_attestations_batch(
+            &sub_issuer,
+            &Vec::from_array(&env, [subject]),
+            &String::from_str(&env, "KYC"),
+            &None,
+        );
+        assert_eq!(client.get_attestation(&ids.get(0).unwrap()).parent_issuer, Some(issuer));
+    }
+
+    #[test]
+    fn test_sub_issuer_blocked_when_parent_expired() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (admin, issuer, client) = setup(&env);
+        let sub_issuer = Address::generate(&env);
+        client.add_sub_issuer(&issuer, &sub_issuer);
+        client.set_issuer_expiry(&admin, &issuer, &Some(2_000));
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        let subject = Address::generate(&env);
+        assert_eq!(
+            client.try_create_attestation(&sub_issuer, &subject, &String::from_str(&env, "KYC"), &None, &None, &None),
+            Err(Ok(Error::IssuerExpired))
+        );
+    }
+
+    #[test]
+    fn test_historical_verification_counts_sub_issuer_via_parent() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let (_admin, issuer, client) = setup(&env);
+        let sub_issuer = Address::generate(&env);
+        client.add_sub_issuer(&issuer, &sub_issuer);
+        let subject = Address::generate(&env);
+        let kyc = String::from_str(&env, "KYC");
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        client.create_attestation(&sub_issuer, &subject, &kyc, &None, &None, &None);
+
+        assert!(client.verify_claim_historical(&subject, &kyc, &3_000));
+    }
+}
+
+mod migrate_tests {
+    use super::*;
+    use crate::types::LegacyAttestation;
+
+    #[test]
+    fn test_legacy_record_is_rewritten_once() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (admin, issuer, client) = setup(&env);
+        let subject = Address::generate(&env);
+        let current = client.create_attestation(&issuer, &subject, &String::from_str(&env, "AML"), &None, &None, &None);
+        let id = String::from_str(&env, "legacy-1");
+        let legacy = LegacyAttestation {
+            id: id.clone(),
+            issuer: issuer.clone(),
+            subject: subject.clone(),
+            claim_type: String::from_str(&env, "KYC"),
+            timestamp: 0,
+            expiration: None,
+            revoked: false,
+            metadata: None,
+            jurisdiction: None,
+            valid_from: None,
+            origin: AttestationOrigin::Native,
+            source_chain: None,
+            source_tx: None,
+            tags: None,
+            revocation_reason: None,
+            deleted: false,
+        };
+        env.as_contract(&client.address, || {
+            env.storage().persistent().set(&crate::storage::StorageKey::Attestation(id.clone()), &legacy);
+        });
+
+        let ids = soroban_sdk::vec![&env, id.clone(), current, String::from_str(&env, "missing")];
+        assert_eq!(client.migrate(&admin, &ids), 1);
+        let migrated = client.get_attestation(&id);
+        assert_eq!(migrated.claim_type, String::from_str(&env, "KYC"));
+        assert!(!migrated.permanent);
+        assert_eq!(migrated.category, Category::Other);
+        assert!(migrated.revocable);
+        assert!(!migrated.expiration_locked);
+        assert!(!migrated.pinned);
+
+        // Already in the current layout: nothing left to rewrite.
+        assert_eq!(client.migrate(&admin, &ids), 0);
+        assert_eq!(client.try_migrate(&issuer, &ids), Err(Ok(Error::Unauthorized)));
+    }
+}
@@ -1,6 +1,6 @@
 //! Shared data types for TrustLink.
 
-use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, Env, String, Vec};
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Vec};
 
 pub use crate::errors::Error;
 
@@ -111,8 +111,11 @@ pub struct ContractConfig {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ContractMetadata {
     pub name: String,
+    /// Contract code version, always read from the live `Version` entry.
     pub version: String,
     pub description: String,
+    /// Admin-supplied revision number of this self-description (0 until set).
+    pub metadata_version: u32,
 }
 
 /// Metadata about a registered issuer.
@@ -142,6 +145,44 @@ pub struct GlobalStats {
     pub total_issuers: u64,
 }
 
+/// One page of attestation ids plus what a client needs to render paging.
+///
+/// `total` counts the whole index, including entries of deleted records that
+/// are filtered out of `ids`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Page {
+    pub ids: Vec<String>,
+    pub total: u32,
+    pub has_more: bool,
+}
+
+/// Opt-in renewal of a claim type when it is verified close to expiry.
+///
+/// A successful `has_valid_claim_authed` within `grace_window` seconds of
+/// the attestation's expiration pushes the expiration out by `extension`,
+/// at most `max_extensions` times per attestation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoExtendConfig {
+    pub grace_window: u64,
+    pub extension: u64,
+    pub max_extensions: u32,
+}
+
+/// Counts of an issuer's valid attestations by time remaining until expiry.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ExpiryHistogram {
+    pub under_1d: u32,
+    pub under_7d: u32,
+    pub under_30d: u32,
+    pub over_30d: u32,
+    pub never: u32,
+    /// `true` when the issuer has more attestations than were scanned.
+    pub truncated: bool,
+}
+
 /// Health status for monitoring.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -173,6 +214,18 @@ pub struct RateLimitConfig {
     pub min_issuance_interval: u64,
 }
 
+/// Granular pause switches. Each flag halts one family of write operations.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PauseFlags {
+    /// Blocks attestation creation, import, bridging, renewal and amendment.
+    pub create: bool,
+    /// Blocks single and batch revocation.
+    pub revoke: bool,
+    /// Blocks issuer registration.
+    pub register: bool,
+}
+
 /// Contract configuration.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -187,6 +240,15 @@ pub struct ContractConfig {
     /// `None` or a 64-character lowercase hexadecimal string (SHA-256 hash).
     /// Enables enforcement of GDPR data-minimisation at the contract level.
     pub metadata_hash_only: bool,
+    /// When `true`, revoking an already-revoked attestation owned by the same
+    /// issuer succeeds silently instead of returning `AlreadyRevoked`.
+    pub idempotent_revoke: bool,
+    /// When `true`, native attestations without an expiration must be created
+    /// through `create_permanent_attestation`; otherwise `ExpirationRequired`.
+    pub enforce_explicit_permanence: bool,
+    /// When `true`, `admin_revoke` may revoke attestations created as
+    /// non-revocable; issuers never can.
+    pub admin_overrides_revocable: bool,
 }
 
 #[contracttype]
@@ -213,6 +275,29 @@ pub enum CouncilOperation {
     PauseContract,
 }
 
+/// Destructive admin operations that, while the operation timelock is set,
+/// must be queued by hash and run through `execute_operation`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TimelockOperation {
+    RemoveIssuer(Address),
+    /// Attestation id and optional reason, as for `admin_revoke`.
+    AdminRevoke(String, Option<String>),
+    /// Issuers to remove, as for `remove_issuers_batch`.
+    RemoveIssuers(Vec<Address>),
+    /// Subject, claim type and limit, as for `admin_revoke_subject_claim_type`.
+    AdminRevokeSubjectClaimType(Address, String, u32),
+    /// Lower (or disable, with `0`) the operation timelock itself.
+    SetTimelock(u64),
+}
+
+impl TimelockOperation {
+    /// SHA-256 of the operation's XDR encoding; the value passed to `queue_operation`.
+    pub fn hash(&self, env: &Env) -> BytesN<32> {
+        BytesN::from_array(env, &env.crypto().sha256(&self.clone().to_xdr(env)).to_array())
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CouncilProposal {
@@ -227,6 +312,35 @@ pub struct CouncilProposal {
     pub quorum_reached_at: Option<u64>,
 }
 
+/// Maximum parent-chain length followed by `has_valid_claim_with_deps`.
+pub const MAX_DEPENDENCY_DEPTH: u32 = 5;
+
+/// Maximum nesting depth accepted by `evaluate_claim_query`.
+pub const MAX_CLAIM_QUERY_DEPTH: u32 = 5;
+
+/// A composable claim policy, e.g. `(KYC_PASSED OR KYC_LITE) AND AGE_OVER_18`.
+///
+/// Empty `And`/`Or` lists evaluate to `false`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClaimQuery {
+    Type(String),
+    And(Vec<ClaimQuery>),
+    Or(Vec<ClaimQuery>),
+}
+
+/// Coarse, machine-readable classification of an attestation, independent of
+/// claim-type naming conventions.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Category {
+    Identity,
+    Financial,
+    Compliance,
+    Reputation,
+    Other,
+}
+
 /// Describes how an attestation entered the system.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -255,9 +369,91 @@ pub struct Attestation {
     pub source_tx: Option<String>,
     pub tags: Option<Vec<String>>,
     pub revocation_reason: Option<String>,
+    /// URI of the justification documents attached at revocation, if any.
+    pub revocation_evidence: Option<String>,
+    /// Id of a parent attestation (same subject) this credential derives from.
+    pub depends_on: Option<String>,
+    /// Parent issuer on whose behalf a sub-issuer created this attestation.
+    pub parent_issuer: Option<Address>,
+    /// `true` when the issuer deliberately minted a non-expiring claim.
+    pub permanent: bool,
+    /// Coarse classification chosen by the issuer; `Other` unless set at creation.
+    pub category: Category,
+    /// Optional numeric value (KYC score, risk rating, reputation points).
+    pub score: Option<i128>,
+    /// `false` for credentials the issuer can never revoke (e.g. a diploma).
+    pub revocable: bool,
+    /// Collateral the issuer reports backing this claim (accounting only).
+    pub stake: Option<i128>,
+    /// Id of the attestation that replaced this one via `supersede_attestation`.
+    pub superseded_by: Option<String>,
+    /// `true` when the expiration was fixed at issuance and can never change.
+    pub expiration_locked: bool,
+    /// Set by the subject to keep the record from being pruned.
+    pub pinned: bool,
     pub deleted: bool,
 }
 
+/// `Attestation` layout written before the fields from `revocation_evidence`
+/// onward existed. Soroban decodes a struct only when every field is present,
+/// so such records must be rewritten by `migrate` before they can be read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyAttestation {
+    pub id: String,
+    pub issuer: Address,
+    pub subject: Address,
+    pub claim_type: String,
+    pub timestamp: u64,
+    pub expiration: Option<u64>,
+    pub revoked: bool,
+    pub metadata: Option<String>,
+    pub jurisdiction: Option<String>,
+    pub valid_from: Option<u64>,
+    pub origin: AttestationOrigin,
+    pub source_chain: Option<String>,
+    pub source_tx: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub revocation_reason: Option<String>,
+    pub deleted: bool,
+}
+
+impl From<LegacyAttestation> for Attestation {
+    /// New fields take the values a fresh default attestation would have:
+    /// not permanent, `Category::Other`, revocable, unlocked and unpinned.
+    fn from(legacy: LegacyAttestation) -> Self {
+        Attestation {
+            id: legacy.id,
+            issuer: legacy.issuer,
+            subject: legacy.subject,
+            claim_type: legacy.claim_type,
+            timestamp: legacy.timestamp,
+            expiration: legacy.expiration,
+            revoked: legacy.revoked,
+            metadata: legacy.metadata,
+            jurisdiction: legacy.jurisdiction,
+            valid_from: legacy.valid_from,
+            origin: legacy.origin,
+            source_chain: legacy.source_chain,
+            source_tx: legacy.source_tx,
+            tags: legacy.tags,
+            revocation_reason: legacy.revocation_reason,
+            revocation_evidence: None,
+            depends_on: None,
+            parent_issuer: None,
+            permanent: false,
+            category: Category::Other,
+            score: None,
+            revocable: true,
+            stake: None,
+            superseded_by: None,
+            expiration_locked: false,
+            pinned: false,
+            deleted: legacy.deleted,
+        }
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AttestationStatus {
@@ -265,6 +461,36 @@ pub enum AttestationStatus {
     Expired,
     Revoked,
     Pending,
+    /// Revoked because the issuer replaced it with a newer attestation.
+    Superseded,
+    /// No such attestation; only returned by `get_attestation_status_or`.
+    NotFound,
+}
+
+/// Outcome of `verify_claim_detailed`, explaining why a claim check passed
+/// or failed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VerifyResult {
+    Valid,
+    /// The subject has no attestation of the claim type at all.
+    NoAttestation,
+    Expired,
+    Revoked,
+    /// A matching attestation is otherwise valid but its issuer is paused.
+    Frozen,
+}
+
+/// Most favourable state among a subject's attestations of one claim type,
+/// as returned by `get_claim_state`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClaimState {
+    Active,
+    Expired,
+    Revoked,
+    /// The subject has never held the claim type (or its records were deleted).
+    None,
 }
 
 /// The action recorded in an audit log entry.
@@ -358,6 +584,17 @@ pub struct PendingAdminTransfer {
     pub new_admin: Address,
 }
 
+/// Dead-man's-switch configuration: `backup_admin` may take over once no
+/// admin action has happened for `inactivity_window` seconds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BackupAdmin {
+    pub backup_admin: Address,
+    pub inactivity_window: u64,
+    /// Ledger timestamp of the most recent admin action.
+    pub last_admin_action: u64,
+}
+
 /// Admin council: ordered list of admin addresses.
 pub type AdminCouncil = Vec<Address>;
 
@@ -428,6 +665,10 @@ impl Attestation {
         String::from_bytes(env, &hex)
     }
 
+    /// SHA-256 over the XDR encoding of each field in turn. Every field is
+    /// encoded as a self-delimiting `ScVal` (type tag, then a length prefix
+    /// for strings), so shifting bytes across a field boundary always changes
+    /// the payload and no delimiter character inside a field can collide.
     pub fn generate_id(
         env: &Env,
         issuer: &Address,
@@ -443,6 +684,9 @@ impl Attestation {
         Self::hash_payload(env, &payload)
     }
 
+    /// Same construction as [`Attestation::generate_id`]; the length prefix
+    /// keeps `(source_chain, source_tx)` splits such as `("ab", "c")` and
+    /// `("a", "bc")` distinct.
     pub fn generate_bridge_id(
         env: &Env,
         bridge: &Address,
@@ -462,6 +706,8 @@ impl Attestation {
         Self::hash_payload(env, &payload)
     }
 
+    /// Status from this record alone. Query paths use `query::effective_status`,
+    /// which additionally clamps expiry to the `depends_on` chain.
     pub fn get_status(&self, current_time: u64) -> AttestationStatus {
         if let Some(valid_from) = self.valid_from {
             if current_time < valid_from {
@@ -469,12 +715,19 @@ impl Attestation {
             }
         }
         if self.revoked {
+            if self.superseded_by.is_some() {
+                return AttestationStatus::Superseded;
+            }
             return AttestationStatus::Revoked;
         }
-        if let Some(expiration) = self.expiration {
-            if current_time >= expiration {
+        match self.expiration {
+            // A zero expiration is rejected at creation; treat any stored one
+            // as expired rather than letting a zero ledger time read it as valid.
+            Some(0) => return AttestationStatus::Expired,
+            Some(expiration) if current_time >= expiration => {
                 return AttestationStatus::Expired;
             }
+            _ => {}
         }
         AttestationStatus::Valid
     }
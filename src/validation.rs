@@ -24,6 +24,9 @@ pub struct Validation;
 impl Validation {
     /// Assert that `caller` is in the admin council.
     ///
+    /// Read-only: mutating entrypoints call [`Storage::record_admin_action`]
+    /// themselves to reset the backup-admin inactivity timer.
+    ///
     /// # Errors
     /// - [`Error::NotInitialized`] — council not initialized.
     /// - [`Error::Unauthorized`] — `caller` not in council.
@@ -43,13 +46,46 @@ impl Validation {
         Ok(())
     }
 
-    /// Assert that `caller` is a registered issuer.
+    /// Assert that `caller` is a registered, unpaused issuer, or a sub-issuer
+    /// whose parent is.
     ///
     /// # Errors
-    /// - [`Error::Unauthorized`] — `caller` is not in the issuer registry.
+    /// - [`Error::Unauthorized`] — `caller` is neither in the issuer registry nor
+    ///   a sub-issuer of a registered parent.
+    /// - [`Error::IssuerPaused`] — `caller` or its parent has been paused by an admin.
+    /// - [`Error::IssuerExpired`] — the authorization of `caller` or its parent has lapsed.
     pub fn require_issuer(env: &Env, caller: &Address) -> Result<(), Error> {
         if !Storage::is_issuer(env, caller) {
-            return Err(Error::Unauthorized);
+            // Sub-issuers act only while their parent is still registered.
+            match Storage::get_sub_issuer_parent(env, caller) {
+                Some(parent) if Storage::is_issuer(env, &parent) => {
+                    if Storage::is_issuer_paused(env, &parent) {
+                        return Err(Error::IssuerPaused);
+                    }
+                    Self::require_issuer_not_expired(env, &parent)?;
+                }
+                _ => return Err(Error::Unauthorized),
+            }
+        }
+        if Storage::is_issuer_paused(env, caller) {
+            return Err(Error::IssuerPaused);
+        }
+        Self::require_issuer_not_expired(env, caller)
+    }
+
+    /// Whether `issuer` would pass [`Validation::require_issuer`]. For
+    /// read-only queries that report issuer standing.
+    pub fn is_issuer_active(env: &Env, issuer: &Address) -> bool {
+        Self::require_issuer(env, issuer).is_ok()
+    }
+
+    // No event is emitted on rejection: events from a failed invocation are
+    // rolled back and would never reach an indexer.
+    fn require_issuer_not_expired(env: &Env, issuer: &Address) -> Result<(), Error> {
+        if let Some(expires_at) = Storage::get_issuer_expiry(env, issuer) {
+            if env.ledger().timestamp() >= expires_at {
+                return Err(Error::IssuerExpired);
+            }
         }
         Ok(())
     }
@@ -79,17 +115,62 @@ impl Validation {
         Err(Error::Unauthorized)
     }
 
-    /// Assert that the contract is not currently paused.
+    /// Assert that attestation creation is not paused.
+    ///
+    /// # Errors
+    /// - [`Error::ContractPaused`] — the admin has set the `create` pause flag.
+    pub fn require_create_not_paused(env: &Env) -> Result<(), Error> {
+        if Storage::get_pause_flags(env).create {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+
+    /// Assert that revocation is not paused.
     ///
     /// # Errors
-    /// - [`Error::ContractPaused`] — the contract has been paused by the admin.
-    pub fn require_not_paused(env: &Env) -> Result<(), Error> {
-        if Storage::is_paused(env) {
+    /// - [`Error::ContractPaused`] — the admin has set the `revoke` pause flag.
+    pub fn require_revoke_not_paused(env: &Env) -> Result<(), Error> {
+        if Storage::get_pause_flags(env).revoke {
             return Err(Error::ContractPaused);
         }
         Ok(())
     }
 
+    /// Assert that issuer registration is not paused.
+    ///
+    /// # Errors
+    /// - [`Error::ContractPaused`] — the admin has set the `register` pause flag.
+    pub fn require_register_not_paused(env: &Env) -> Result<(), Error> {
+        if Storage::get_pause_flags(env).register {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+
+    /// Assert that the issuer set may be changed.
+    ///
+    /// # Errors
+    /// - [`Error::RegistryFrozen`] — an admin has frozen the issuer registry.
+    pub fn require_registry_not_frozen(env: &Env) -> Result<(), Error> {
+        if Storage::is_issuer_registry_frozen(env) {
+            return Err(Error::RegistryFrozen);
+        }
+        Ok(())
+    }
+
+    /// Assert that destructive admin operations may run directly.
+    ///
+    /// # Errors
+    /// - [`Error::TimelockNotReady`] — the operation timelock is set, so the
+    ///   operation must go through `queue_operation` / `execute_operation`.
+    pub fn require_no_timelock(env: &Env) -> Result<(), Error> {
+        if Storage::get_operation_timelock(env) > 0 {
+            return Err(Error::TimelockNotReady);
+        }
+        Ok(())
+    }
+
     /// Validate a `claim_type` string.
     ///
     /// # Rules
@@ -119,6 +200,28 @@ impl Validation {
         Ok(())
     }
 
+    /// Case-fold `claim_type` when the contract was initialized with
+    /// `normalize_claim_types`; otherwise return it unchanged.
+    ///
+    /// # Rules
+    /// - ASCII letters are uppercased; every other byte, including each byte
+    ///   of a non-ASCII UTF-8 sequence, passes through unchanged. Such strings
+    ///   fail [`Validation::validate_claim_type`] on creation anyway, so they
+    ///   can never match on verification.
+    /// - Strings that are empty or longer than 64 bytes pass through unchanged
+    ///   for the same reason.
+    pub fn normalize_claim_type(env: &Env, claim_type: &String) -> String {
+        let len = claim_type.len();
+        if len == 0 || len > 64 || !Storage::is_normalize_claim_types(env) {
+            return claim_type.clone();
+        }
+        let mut buf = [0u8; 64];
+        let slice = &mut buf[..len as usize];
+        claim_type.copy_into_slice(slice);
+        slice.make_ascii_uppercase();
+        String::from_bytes(env, slice)
+    }
+
     /// Validate optional metadata string.
     ///
     /// # Rules
@@ -135,6 +238,49 @@ impl Validation {
         Ok(())
     }
 
+    /// Validate the admin-supplied contract self-description.
+    ///
+    /// # Rules
+    /// - `name` maximum 64 characters.
+    /// - `description` maximum 256 characters.
+    ///
+    /// # Errors
+    /// - [`Error::MetadataTooLong`] — either field exceeds its limit.
+    pub fn validate_contract_metadata(name: &String, description: &String) -> Result<(), Error> {
+        if name.len() > 64 || description.len() > 256 {
+            return Err(Error::MetadataTooLong);
+        }
+        Ok(())
+    }
+
+    /// Validate a claim type's human-readable description (at most 256 characters).
+    ///
+    /// # Errors
+    /// - [`Error::MetadataTooLong`] — `description` exceeds 256 characters.
+    pub fn validate_claim_type_description(description: &String) -> Result<(), Error> {
+        if description.len() > 256 {
+            return Err(Error::MetadataTooLong);
+        }
+        Ok(())
+    }
+
+    /// Validate an optional revocation evidence URI.
+    ///
+    /// # Rules
+    /// - Must be non-empty when provided.
+    /// - Maximum 256 characters.
+    ///
+    /// # Errors
+    /// - [`Error::InvalidEvidenceUri`] — URI is empty or exceeds 256 characters.
+    pub fn validate_evidence_uri(evidence_uri: &Option<String>) -> Result<(), Error> {
+        if let Some(uri) = evidence_uri {
+            if uri.is_empty() || uri.len() > 256 {
+                return Err(Error::InvalidEvidenceUri);
+            }
+        }
+        Ok(())
+    }
+
     /// When `metadata_hash_only` mode is enabled in `ContractConfig`, enforce
     /// that the metadata value is a 64-character lowercase hexadecimal string
     /// (a SHA-256 hash). `None` is always accepted.
@@ -165,6 +311,9 @@ impl Validation {
 
     /// Check if a claim type is registered when required by contract config.
     ///
+    /// Creation paths call this before deriving the attestation id, so an
+    /// unknown type is rejected without a duplicate lookup or any write.
+    ///
     /// # Errors
     /// - [`Error::InvalidClaimType`] — claim type is not registered and contract requires registration.
     pub fn require_registered_claim_type(env: &Env, claim_type: &String) -> Result<(), Error> {
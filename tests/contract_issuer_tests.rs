@@ -0,0 +1,91 @@
+//! Tests for contract-address issuers.
+//!
+//! Acceptance criteria:
+//!   - A contract registered as an issuer can create an attestation through a
+//!     cross-contract call, authorized by its own invocation.
+//!   - The stored attestation records the contract address as the issuer.
+//!   - A contract that is not registered is still rejected.
+
+#![cfg(test)]
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+use trustlink::{TrustLinkContract, TrustLinkContractClient};
+
+// ---------------------------------------------------------------------------
+// Issuer contract
+// ---------------------------------------------------------------------------
+
+/// Minimal contract that issues attestations in its own name, standing in for
+/// a DAO or other on-chain issuer.
+#[contract]
+pub struct IssuerContract;
+
+#[contractimpl]
+impl IssuerContract {
+    pub fn issue(env: Env, trustlink: Address, subject: Address, claim_type: String) -> String {
+        TrustLinkContractClient::new(&env, &trustlink).create_attestation(
+            &env.current_contract_address(),
+            &subject,
+            &claim_type,
+            &None,
+            &None,
+            &None,
+        )
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helper
+// ---------------------------------------------------------------------------
+
+fn setup(env: &Env) -> (Address, TrustLinkContractClient<'_>) {
+    let id = env.register_contract(None, TrustLinkContract);
+    let client = TrustLinkContractClient::new(env, &id);
+    let admin = Address::generate(env);
+    client.initialize(&admin, &None);
+    (admin, client)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_contract_issuer_creates_attestation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (admin, client) = setup(&env);
+
+    let issuer_id = env.register_contract(None, IssuerContract);
+    let issuer = IssuerContractClient::new(&env, &issuer_id);
+    client.register_issuer(&admin, &issuer_id);
+
+    // Drop mocked auths: the issuer contract is the direct invoker of
+    // TrustLink, so its `require_auth` is satisfied without any signature.
+    env.set_auths(&[]);
+
+    let subject = Address::generate(&env);
+    let claim_type = String::from_str(&env, "KYC_PASSED");
+    let id = issuer.issue(&client.address, &subject, &claim_type);
+
+    let attestation = client.get_attestation(&id);
+    assert_eq!(attestation.issuer, issuer_id);
+    assert_eq!(attestation.subject, subject);
+    assert!(client.has_valid_claim(&subject, &claim_type));
+}
+
+#[test]
+fn test_unregistered_contract_issuer_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (_admin, client) = setup(&env);
+
+    let issuer_id = env.register_contract(None, IssuerContract);
+    let issuer = IssuerContractClient::new(&env, &issuer_id);
+    env.set_auths(&[]);
+
+    let subject = Address::generate(&env);
+    let claim_type = String::from_str(&env, "KYC_PASSED");
+    assert!(issuer.try_issue(&client.address, &subject, &claim_type).is_err());
+    assert!(!client.has_valid_claim(&subject, &claim_type));
+}
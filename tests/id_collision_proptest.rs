@@ -1,9 +1,10 @@
 // Property-based fuzz tests for attestation ID collision resistance (#320)
 //
-// Verifies three invariants using proptest:
+// Verifies four invariants using proptest:
 //   1. Different (issuer, subject, claim_type, timestamp) tuples → different IDs
 //   2. Same inputs → same ID (determinism)
 //   3. IDs are always exactly 64 lowercase hex characters
+//   4. Moving bytes across a string-field boundary → different IDs
 
 #![cfg(test)]
 
@@ -113,3 +114,49 @@ proptest! {
         );
     }
 }
+
+// ---------------------------------------------------------------------------
+// 4. Field boundaries — each field is length-prefixed, so re-splitting the
+//    same bytes between adjacent string fields changes the ID
+// ---------------------------------------------------------------------------
+
+fn gen_bridge_id(env: &Env, bridge: &Address, subject: &Address, chain: &str, tx: &str) -> String {
+    Attestation::generate_bridge_id(
+        env,
+        bridge,
+        subject,
+        &String::from_str(env, "KYC_PASSED"),
+        &String::from_str(env, chain),
+        &String::from_str(env, tx),
+        1_700_000_000,
+    )
+}
+
+#[test]
+fn test_shifted_bridge_source_split_produces_different_id() {
+    let env = Env::default();
+    let bridge = Address::generate(&env);
+    let subject = Address::generate(&env);
+    let id_a = gen_bridge_id(&env, &bridge, &subject, "ab", "c");
+    let id_b = gen_bridge_id(&env, &bridge, &subject, "a", "bc");
+    assert_ne!(to_std(&env, &id_a), to_std(&env, &id_b));
+}
+
+proptest! {
+    #[test]
+    fn prop_resplit_source_reference_produces_different_id(
+        joined in "[a-z0-9|:]{2,32}",
+        split_a in 1usize..32,
+        split_b in 1usize..32,
+    ) {
+        let split_a = split_a.min(joined.len() - 1);
+        let split_b = split_b.min(joined.len() - 1);
+        prop_assume!(split_a != split_b);
+        let env = Env::default();
+        let bridge  = Address::generate(&env);
+        let subject = Address::generate(&env);
+        let id_a = gen_bridge_id(&env, &bridge, &subject, &joined[..split_a], &joined[split_a..]);
+        let id_b = gen_bridge_id(&env, &bridge, &subject, &joined[..split_b], &joined[split_b..]);
+        prop_assert_ne!(to_std(&env, &id_a), to_std(&env, &id_b));
+    }
+}